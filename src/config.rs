@@ -2,6 +2,14 @@
 //!
 //! Provides a builder pattern for configuring the object store.
 
+use crate::error::{ObjectStoreError, Result};
+
+/// Default for [`StoreConfig::max_columns`]
+pub const DEFAULT_MAX_COLUMNS: usize = 1000;
+
+/// Default for [`StoreConfig::max_indexes`]
+pub const DEFAULT_MAX_INDEXES: usize = 100;
+
 /// Configuration for auto-managed columns
 #[derive(Debug, Clone)]
 pub struct AutoColumns {
@@ -23,6 +31,81 @@ impl Default for AutoColumns {
     }
 }
 
+/// How reads treat stored enum values missing from the column's current values
+///
+/// Rows written before an enum value was removed from the schema can still
+/// hold it; writes would reject such a value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnknownEnumValues {
+    /// Return the stored value unchanged
+    #[default]
+    PassThrough,
+    /// Treat the value as NULL (the property is omitted)
+    Null,
+    /// Fail the read with a database error
+    Error,
+}
+
+/// UUID version used for instance ids generated by the store
+///
+/// Ids are generated client-side and stored as strings either way.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IdGenerator {
+    /// Random UUIDv4
+    #[default]
+    UuidV4,
+    /// Time-ordered UUIDv7; ids generated by one process sort in creation
+    /// order, which keeps primary key inserts local in the index
+    UuidV7,
+}
+
+impl IdGenerator {
+    /// Generate a new id
+    pub fn generate(&self) -> String {
+        match self {
+            IdGenerator::UuidV4 => uuid::Uuid::new_v4().to_string(),
+            IdGenerator::UuidV7 => uuid::Uuid::now_v7().to_string(),
+        }
+    }
+}
+
+/// Where NULL values are placed when sorting
+///
+/// PostgreSQL's own default treats NULL as larger than any value: last in
+/// ascending and first in descending order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NullsOrdering {
+    /// NULLs before all other values (`NULLS FIRST`)
+    First,
+    /// NULLs after all other values (`NULLS LAST`)
+    Last,
+}
+
+impl NullsOrdering {
+    /// SQL keyword form, e.g. `NULLS FIRST`
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            NullsOrdering::First => "NULLS FIRST",
+            NullsOrdering::Last => "NULLS LAST",
+        }
+    }
+}
+
+/// What instance inserts do when the data table has drifted from its schema
+///
+/// The check compares the table's columns with the schema metadata before
+/// each single-instance insert, costing one catalog query per insert.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DriftCheck {
+    /// Don't check; an insert into a drifted table fails with the raw SQL error
+    #[default]
+    Off,
+    /// Fail the insert with a conflict error naming the missing columns
+    Error,
+    /// Re-add missing columns (or the missing table) before inserting
+    Repair,
+}
+
 /// Configuration for the object store
 #[derive(Debug, Clone)]
 pub struct StoreConfig {
@@ -32,8 +115,30 @@ pub struct StoreConfig {
     pub metadata_table: String,
     /// Whether to use soft delete (deleted column) or hard delete
     pub soft_delete: bool,
+    /// Soft delete for schemas in the metadata table, if different from `soft_delete`
+    pub metadata_soft_delete: Option<bool>,
+    /// Whether soft-deleting a schema also soft-deletes its instances
+    pub cascade_schema_delete: bool,
+    /// Whether new schemas get the default `created_at` index
+    pub default_index: bool,
     /// Auto-managed columns configuration
     pub auto_columns: AutoColumns,
+    /// Whether new tables list the auto-managed columns after the user columns
+    pub auto_columns_last: bool,
+    /// Whether `unique` columns get a named unique index instead of a constraint
+    pub unique_as_index: bool,
+    /// Read-time handling of stored enum values not in the column's enum set
+    pub unknown_enum_values: UnknownEnumValues,
+    /// UUID version for generated instance ids
+    pub id_generator: IdGenerator,
+    /// Maximum number of user columns per schema
+    pub max_columns: usize,
+    /// Maximum number of declared indexes per schema
+    pub max_indexes: usize,
+    /// NULL placement for sort keys that don't specify one (`None`: PostgreSQL's default)
+    pub nulls_ordering: Option<NullsOrdering>,
+    /// Drift check performed before single-instance inserts
+    pub drift_check: DriftCheck,
 }
 
 impl StoreConfig {
@@ -41,6 +146,55 @@ impl StoreConfig {
     pub fn builder(database_url: impl Into<String>) -> StoreConfigBuilder {
         StoreConfigBuilder::new(database_url)
     }
+
+    /// Whether deleting a schema soft-deletes its metadata row
+    ///
+    /// Follows [`metadata_soft_delete`](Self::metadata_soft_delete) when set,
+    /// and [`soft_delete`](Self::soft_delete) otherwise.
+    pub fn soft_delete_schemas(&self) -> bool {
+        self.metadata_soft_delete.unwrap_or(self.soft_delete)
+    }
+
+    /// Names of the columns the store manages on every data table
+    ///
+    /// Includes the enabled auto columns and the `deleted` flag when soft delete is on.
+    pub fn managed_column_names(&self) -> Vec<&'static str> {
+        let mut names = Vec::new();
+        if self.auto_columns.id {
+            names.push("id");
+        }
+        if self.auto_columns.created_at {
+            names.push("created_at");
+        }
+        if self.auto_columns.updated_at {
+            names.push("updated_at");
+        }
+        if self.soft_delete {
+            names.push("deleted");
+        }
+        names
+    }
+
+    /// Check a schema definition's size against the configured limits
+    ///
+    /// Fails with [`ObjectStoreError::Validation`] when `columns` exceeds
+    /// [`max_columns`](Self::max_columns) or `indexes` exceeds
+    /// [`max_indexes`](Self::max_indexes).
+    pub fn check_schema_limits(&self, columns: usize, indexes: usize) -> Result<()> {
+        if columns > self.max_columns {
+            return Err(ObjectStoreError::validation(format!(
+                "Schema has {} columns, more than the limit of {}",
+                columns, self.max_columns
+            )));
+        }
+        if indexes > self.max_indexes {
+            return Err(ObjectStoreError::validation(format!(
+                "Schema has {} indexes, more than the limit of {}",
+                indexes, self.max_indexes
+            )));
+        }
+        Ok(())
+    }
 }
 
 /// Builder for StoreConfig
@@ -49,7 +203,18 @@ pub struct StoreConfigBuilder {
     database_url: String,
     metadata_table: String,
     soft_delete: bool,
+    metadata_soft_delete: Option<bool>,
+    cascade_schema_delete: bool,
+    default_index: bool,
     auto_columns: AutoColumns,
+    auto_columns_last: bool,
+    unique_as_index: bool,
+    unknown_enum_values: UnknownEnumValues,
+    id_generator: IdGenerator,
+    max_columns: usize,
+    max_indexes: usize,
+    nulls_ordering: Option<NullsOrdering>,
+    drift_check: DriftCheck,
 }
 
 impl StoreConfigBuilder {
@@ -59,7 +224,18 @@ impl StoreConfigBuilder {
             database_url: database_url.into(),
             metadata_table: "__schema".to_string(),
             soft_delete: true,
+            metadata_soft_delete: None,
+            cascade_schema_delete: false,
+            default_index: true,
             auto_columns: AutoColumns::default(),
+            auto_columns_last: false,
+            unique_as_index: false,
+            unknown_enum_values: UnknownEnumValues::PassThrough,
+            id_generator: IdGenerator::UuidV4,
+            max_columns: DEFAULT_MAX_COLUMNS,
+            max_indexes: DEFAULT_MAX_INDEXES,
+            nulls_ordering: None,
+            drift_check: DriftCheck::Off,
         }
     }
 
@@ -75,6 +251,36 @@ impl StoreConfigBuilder {
         self
     }
 
+    /// Enable or disable soft delete for schemas only (default: same as `soft_delete`)
+    ///
+    /// Controls the metadata table's `deleted` column and whether
+    /// [`delete_schema`](crate::ObjectStore::delete_schema) keeps or drops the
+    /// table, independently of instance soft delete.
+    pub fn metadata_soft_delete(mut self, enabled: bool) -> Self {
+        self.metadata_soft_delete = Some(enabled);
+        self
+    }
+
+    /// Soft-delete a schema's instances along with the schema (default: false)
+    ///
+    /// When disabled, the rows are left untouched in the data table. Either way,
+    /// instance operations on a soft-deleted schema fail with `SchemaDeleted`.
+    /// Requires soft delete for both schemas and instances; otherwise deleting a
+    /// schema either drops its table or has no `deleted` column to set.
+    pub fn cascade_schema_delete(mut self, enabled: bool) -> Self {
+        self.cascade_schema_delete = enabled;
+        self
+    }
+
+    /// Create the default `created_at` index on new schemas (default: true)
+    ///
+    /// Individual schemas can override this with
+    /// [`CreateSchemaRequest::with_default_index`](crate::CreateSchemaRequest::with_default_index).
+    pub fn default_index(mut self, enabled: bool) -> Self {
+        self.default_index = enabled;
+        self
+    }
+
     /// Enable or disable auto-generated `id` column (default: true)
     pub fn auto_id(mut self, enabled: bool) -> Self {
         self.auto_columns.id = enabled;
@@ -93,6 +299,73 @@ impl StoreConfigBuilder {
         self
     }
 
+    /// Place auto-managed columns after the user columns in new tables (default: false)
+    ///
+    /// By default `id` comes first and the timestamps and `deleted` flag last.
+    /// This only changes the physical order seen by `SELECT *`; `id` stays the
+    /// primary key and the store always selects columns by name.
+    pub fn auto_columns_last(mut self, enabled: bool) -> Self {
+        self.auto_columns_last = enabled;
+        self
+    }
+
+    /// Realize `unique` columns as named unique indexes (default: false)
+    ///
+    /// Instead of an inline UNIQUE constraint, each unique column gets a
+    /// `CREATE UNIQUE INDEX` named `uq_<table>_<column>`, which
+    /// [`update_schema`](crate::ObjectStore::update_schema) drops again when
+    /// `unique` is turned off.
+    pub fn unique_as_index(mut self, enabled: bool) -> Self {
+        self.unique_as_index = enabled;
+        self
+    }
+
+    /// How reads handle stored enum values that are no longer in the column's
+    /// enum set (default: [`UnknownEnumValues::PassThrough`])
+    pub fn unknown_enum_values(mut self, mode: UnknownEnumValues) -> Self {
+        self.unknown_enum_values = mode;
+        self
+    }
+
+    /// UUID version for generated instance ids (default: [`IdGenerator::UuidV4`])
+    pub fn id_generator(mut self, generator: IdGenerator) -> Self {
+        self.id_generator = generator;
+        self
+    }
+
+    /// Maximum number of user columns a schema may define (default: 1000)
+    ///
+    /// PostgreSQL caps a table at 1600 columns; a lower limit also keeps the
+    /// bulk-insert parameter math well away from degenerate row sizes.
+    pub fn max_columns(mut self, max: usize) -> Self {
+        self.max_columns = max;
+        self
+    }
+
+    /// Maximum number of declared indexes a schema may define (default: 100)
+    pub fn max_indexes(mut self, max: usize) -> Self {
+        self.max_indexes = max;
+        self
+    }
+
+    /// Default NULL placement for sorted queries (default: PostgreSQL's own)
+    ///
+    /// Applies to every sort key whose order doesn't carry its own
+    /// `nulls first` / `nulls last` suffix.
+    pub fn nulls_ordering(mut self, ordering: NullsOrdering) -> Self {
+        self.nulls_ordering = Some(ordering);
+        self
+    }
+
+    /// Check the data table for drift before inserting (default: [`DriftCheck::Off`])
+    ///
+    /// Catches columns dropped out of band, which would otherwise surface as
+    /// a raw SQL error from the INSERT.
+    pub fn drift_check(mut self, mode: DriftCheck) -> Self {
+        self.drift_check = mode;
+        self
+    }
+
     /// Disable the auto-generated `id` column
     pub fn without_id(mut self) -> Self {
         self.auto_columns.id = false;
@@ -127,7 +400,18 @@ impl StoreConfigBuilder {
             database_url: self.database_url,
             metadata_table: self.metadata_table,
             soft_delete: self.soft_delete,
+            metadata_soft_delete: self.metadata_soft_delete,
+            cascade_schema_delete: self.cascade_schema_delete,
+            default_index: self.default_index,
             auto_columns: self.auto_columns,
+            auto_columns_last: self.auto_columns_last,
+            unique_as_index: self.unique_as_index,
+            unknown_enum_values: self.unknown_enum_values,
+            id_generator: self.id_generator,
+            max_columns: self.max_columns,
+            max_indexes: self.max_indexes,
+            nulls_ordering: self.nulls_ordering,
+            drift_check: self.drift_check,
         }
     }
 }
@@ -226,6 +510,48 @@ mod tests {
         assert!(config.soft_delete);
     }
 
+    #[test]
+    fn test_cascade_schema_delete_disabled_by_default() {
+        let config = StoreConfig::builder("postgres://localhost/test").build();
+        assert!(!config.cascade_schema_delete);
+    }
+
+    #[test]
+    fn test_cascade_schema_delete_enabled() {
+        let config = StoreConfig::builder("postgres://localhost/test")
+            .cascade_schema_delete(true)
+            .build();
+
+        assert!(config.cascade_schema_delete);
+    }
+
+    #[test]
+    fn test_metadata_soft_delete_follows_soft_delete_by_default() {
+        let config = StoreConfig::builder("postgres://localhost/test")
+            .soft_delete(false)
+            .build();
+        assert_eq!(config.metadata_soft_delete, None);
+        assert!(!config.soft_delete_schemas());
+
+        let config = StoreConfig::builder("postgres://localhost/test")
+            .soft_delete(false)
+            .metadata_soft_delete(true)
+            .build();
+        assert!(!config.soft_delete);
+        assert!(config.soft_delete_schemas());
+    }
+
+    #[test]
+    fn test_default_index_toggle() {
+        let config = StoreConfig::builder("postgres://localhost/test").build();
+        assert!(config.default_index);
+
+        let config = StoreConfig::builder("postgres://localhost/test")
+            .default_index(false)
+            .build();
+        assert!(!config.default_index);
+    }
+
     // =========================================================================
     // Auto Columns Configuration Tests
     // =========================================================================
@@ -301,6 +627,82 @@ mod tests {
         assert!(!config.auto_columns.updated_at);
     }
 
+    #[test]
+    fn test_managed_column_names_default() {
+        let config = StoreConfig::builder("postgres://localhost/test").build();
+        assert_eq!(
+            config.managed_column_names(),
+            vec!["id", "created_at", "updated_at", "deleted"]
+        );
+    }
+
+    #[test]
+    fn test_managed_column_names_minimal() {
+        let config = StoreConfig::builder("postgres://localhost/test")
+            .without_auto_columns()
+            .soft_delete(false)
+            .build();
+        assert!(config.managed_column_names().is_empty());
+    }
+
+    #[test]
+    fn test_unknown_enum_values() {
+        let config = StoreConfig::builder("postgres://localhost/test").build();
+        assert_eq!(config.unknown_enum_values, UnknownEnumValues::PassThrough);
+
+        let config = StoreConfig::builder("postgres://localhost/test")
+            .unknown_enum_values(UnknownEnumValues::Error)
+            .build();
+        assert_eq!(config.unknown_enum_values, UnknownEnumValues::Error);
+    }
+
+    #[test]
+    fn test_drift_check() {
+        let config = StoreConfig::builder("postgres://localhost/test").build();
+        assert_eq!(config.drift_check, DriftCheck::Off);
+
+        let config = StoreConfig::builder("postgres://localhost/test")
+            .drift_check(DriftCheck::Repair)
+            .build();
+        assert_eq!(config.drift_check, DriftCheck::Repair);
+    }
+
+    #[test]
+    fn test_id_generator() {
+        let config = StoreConfig::builder("postgres://localhost/test").build();
+        assert_eq!(config.id_generator, IdGenerator::UuidV4);
+        let id = config.id_generator.generate();
+        assert_eq!(uuid::Uuid::parse_str(&id).unwrap().get_version_num(), 4);
+
+        let config = StoreConfig::builder("postgres://localhost/test")
+            .id_generator(IdGenerator::UuidV7)
+            .build();
+        let ids: Vec<String> = (0..1000).map(|_| config.id_generator.generate()).collect();
+        assert_eq!(uuid::Uuid::parse_str(&ids[0]).unwrap().get_version_num(), 7);
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_schema_limits() {
+        let config = StoreConfig::builder("postgres://localhost/test").build();
+        assert_eq!(config.max_columns, DEFAULT_MAX_COLUMNS);
+        assert_eq!(config.max_indexes, DEFAULT_MAX_INDEXES);
+
+        let config = StoreConfig::builder("postgres://localhost/test")
+            .max_columns(3)
+            .max_indexes(1)
+            .build();
+        assert!(config.check_schema_limits(3, 1).is_ok());
+
+        let err = config.check_schema_limits(4, 1).unwrap_err();
+        assert!(matches!(err, ObjectStoreError::Validation(_)));
+        assert!(err.to_string().contains("4 columns"));
+
+        let err = config.check_schema_limits(3, 2).unwrap_err();
+        assert!(matches!(err, ObjectStoreError::Validation(_)));
+        assert!(err.to_string().contains("2 indexes"));
+    }
+
     // =========================================================================
     // Chained Builder Tests
     // =========================================================================
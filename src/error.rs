@@ -1,5 +1,8 @@
 //! Error types for Object Store operations
 
+use std::sync::LazyLock;
+
+use regex::Regex;
 use thiserror::Error;
 
 /// Errors that can occur during object store operations
@@ -8,19 +11,37 @@ pub enum ObjectStoreError {
     #[error("Validation error: {0}")]
     Validation(String),
 
+    #[error("Validation error: {message}")]
+    InstanceValidation {
+        /// Position of the offending instance in the submitted batch
+        instance_index: Option<usize>,
+        /// Column that failed validation, if the failure is column-specific
+        column: Option<String>,
+        /// Human-readable description of the failure
+        message: String,
+    },
+
     #[error("Schema not found: {0}")]
     SchemaNotFound(String),
 
+    #[error("Schema is deleted: {0}")]
+    SchemaDeleted(String),
+
     #[error("Instance not found: {0}")]
     InstanceNotFound(String),
 
+    #[error("Instance is deleted: {0}")]
+    InstanceDeleted(String),
+
     #[error("Conflict: {0}")]
     Conflict(String),
 
     #[error("Database error: {0}")]
     Database(String),
 
-    #[error("SQL error: {0}")]
+    /// Error reported by sqlx; PostgreSQL errors also show the constraint,
+    /// detail (with row values redacted), and hint
+    #[error("SQL error: {}", describe_sql_error(.0))]
     Sql(#[from] sqlx::Error),
 
     #[error("Invalid condition: {0}")]
@@ -41,14 +62,38 @@ impl ObjectStoreError {
         Self::Validation(msg.into())
     }
 
+    /// Validation error for an instance within a bulk operation
+    ///
+    /// Carries the instance index and failing column so callers can map the
+    /// error back to their input rows without parsing the message.
+    pub fn instance_validation(
+        instance_index: usize,
+        column: Option<&str>,
+        msg: impl Into<String>,
+    ) -> Self {
+        Self::InstanceValidation {
+            instance_index: Some(instance_index),
+            column: column.map(str::to_string),
+            message: msg.into(),
+        }
+    }
+
     pub fn schema_not_found(msg: impl Into<String>) -> Self {
         Self::SchemaNotFound(msg.into())
     }
 
+    pub fn schema_deleted(msg: impl Into<String>) -> Self {
+        Self::SchemaDeleted(msg.into())
+    }
+
     pub fn instance_not_found(msg: impl Into<String>) -> Self {
         Self::InstanceNotFound(msg.into())
     }
 
+    pub fn instance_deleted(msg: impl Into<String>) -> Self {
+        Self::InstanceDeleted(msg.into())
+    }
+
     pub fn conflict(msg: impl Into<String>) -> Self {
         Self::Conflict(msg.into())
     }
@@ -60,6 +105,80 @@ impl ObjectStoreError {
     pub fn bulk_operation(msg: impl Into<String>) -> Self {
         Self::BulkOperation(msg.into())
     }
+
+    /// PostgreSQL error returned by the database, if this wraps one
+    pub fn pg_error(&self) -> Option<&sqlx::postgres::PgDatabaseError> {
+        match self {
+            Self::Sql(sqlx::Error::Database(db_err)) => db_err.try_downcast_ref(),
+            _ => None,
+        }
+    }
+
+    /// Name of the constraint that was violated, if any
+    pub fn constraint(&self) -> Option<&str> {
+        self.pg_error().and_then(|e| e.constraint())
+    }
+}
+
+/// Render a sqlx error, appending PostgreSQL's constraint, detail, and hint
+fn describe_sql_error(err: &sqlx::Error) -> String {
+    let mut message = err.to_string();
+    let Some(pg_err) = err
+        .as_database_error()
+        .and_then(|e| e.try_downcast_ref::<sqlx::postgres::PgDatabaseError>())
+    else {
+        return message;
+    };
+
+    if let Some(constraint) = pg_err.constraint() {
+        message.push_str(&format!("; constraint: {}", constraint));
+    }
+    if let Some(detail) = pg_err.detail() {
+        message.push_str(&format!("; detail: {}", redact_detail(detail)));
+    }
+    if let Some(hint) = pg_err.hint() {
+        message.push_str(&format!("; hint: {}", hint));
+    }
+    message
+}
+
+/// Row values echoed in a PostgreSQL error detail, e.g. `=(a@example.com)`
+static DETAIL_VALUES: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"=\(.*\)").unwrap());
+
+/// Strip row values from a PostgreSQL error detail
+///
+/// Details such as `Key (email)=(a@example.com) already exists.` echo the
+/// offending values; only the column list is kept.
+fn redact_detail(detail: &str) -> String {
+    if detail.starts_with("Failing row contains") {
+        return "Failing row contains (redacted).".to_string();
+    }
+    DETAIL_VALUES.replace(detail, "=(redacted)").into_owned()
 }
 
 pub type Result<T> = std::result::Result<T, ObjectStoreError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_detail() {
+        assert_eq!(
+            redact_detail("Key (email)=(a@example.com) already exists."),
+            "Key (email)=(redacted) already exists."
+        );
+        assert_eq!(
+            redact_detail("Key (customer_id)=(c-1) is not present in table \"customers\"."),
+            "Key (customer_id)=(redacted) is not present in table \"customers\"."
+        );
+        assert_eq!(
+            redact_detail("Failing row contains (1, secret, null)."),
+            "Failing row contains (redacted)."
+        );
+        assert_eq!(
+            redact_detail("Table has dependent objects."),
+            "Table has dependent objects."
+        );
+    }
+}
@@ -2,8 +2,11 @@
 //!
 //! Includes Instance, CreateInstanceRequest, Condition, FilterRequest.
 
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+
+use crate::error::{ObjectStoreError, Result};
 
 /// Instance data stored in dynamic tables
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,6 +56,65 @@ impl Instance {
         self.schema_name = Some(schema_name.into());
         self
     }
+
+    /// `created_at` parsed as a UTC timestamp
+    ///
+    /// Returns `None` when the auto column is disabled (empty string) or the
+    /// value is not valid RFC 3339.
+    pub fn created_at_utc(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        parse_rfc3339(&self.created_at)
+    }
+
+    /// `updated_at` parsed as a UTC timestamp
+    ///
+    /// Returns `None` when the auto column is disabled (empty string) or the
+    /// value is not valid RFC 3339.
+    pub fn updated_at_utc(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        parse_rfc3339(&self.updated_at)
+    }
+
+    /// Version token for conditional requests (HTTP `ETag` / `If-Match`)
+    ///
+    /// Derived from `updated_at` and the properties, so it changes whenever the
+    /// instance does. Pass it to
+    /// [`ObjectStore::update_instance_if_match`](crate::ObjectStore::update_instance_if_match).
+    pub fn etag(&self) -> String {
+        // FNV-1a (64-bit): stable across processes, unlike the std hasher
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let properties = self.properties.to_string();
+        for byte in self
+            .updated_at
+            .bytes()
+            .chain(std::iter::once(0))
+            .chain(properties.bytes())
+        {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+        format!("{:016x}", hash)
+    }
+
+    /// Instance as a single flat JSON object
+    ///
+    /// Merges `properties` with `id`, `createdAt`, and `updatedAt` at the top
+    /// level. On a key collision the instance fields win and the colliding
+    /// property is left out, so the result never depends on property names.
+    pub fn to_flat_json(&self) -> serde_json::Value {
+        let mut flat = match &self.properties {
+            serde_json::Value::Object(properties) => properties.clone(),
+            _ => serde_json::Map::new(),
+        };
+        flat.insert("id".to_string(), self.id.clone().into());
+        flat.insert("createdAt".to_string(), self.created_at.clone().into());
+        flat.insert("updatedAt".to_string(), self.updated_at.clone().into());
+        serde_json::Value::Object(flat)
+    }
+}
+
+fn parse_rfc3339(value: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|dt| dt.with_timezone(&chrono::Utc))
 }
 
 /// Request to create a new instance
@@ -102,6 +164,110 @@ impl UpdateInstanceRequest {
     }
 }
 
+/// Outcome of a best-effort bulk insert
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BulkCreateResult {
+    /// IDs of the created instances, in input order
+    pub inserted: Vec<String>,
+    /// Input index and error message for each instance that was not created
+    pub failed: Vec<(usize, String)>,
+}
+
+/// One page of results together with the total across all pages
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Page<T> {
+    /// Items on this page
+    pub items: Vec<T>,
+    /// Total number of matching items
+    pub total: i64,
+    /// Requested page size
+    #[serde(rename = "pageSize")]
+    pub page_size: i64,
+    /// Number of pages needed to cover `total` at `page_size`
+    #[serde(rename = "pageCount")]
+    pub page_count: i64,
+    /// Whether items remain after this page
+    #[serde(rename = "hasMore")]
+    pub has_more: bool,
+}
+
+impl<T> Page<T> {
+    /// Build a page from its items, the total count, the page size, and the
+    /// offset the page starts at
+    pub fn new(items: Vec<T>, total: i64, page_size: i64, offset: i64) -> Self {
+        let page_count = if page_size > 0 {
+            (total + page_size - 1) / page_size
+        } else {
+            0
+        };
+        let has_more = offset + (items.len() as i64) < total;
+        Self {
+            items,
+            total,
+            page_size,
+            page_count,
+            has_more,
+        }
+    }
+}
+
+/// Position in a keyset-paginated listing
+///
+/// Returned by [`ObjectStore::query_instances_after`](crate::ObjectStore::query_instances_after)
+/// and pointing just past the last row of a page, identified by its
+/// `(created_at, id)`. Clients should treat the [`encode`](Self::encode)d
+/// string as opaque.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cursor {
+    pub(crate) created_at: chrono::DateTime<chrono::Utc>,
+    pub(crate) id: String,
+}
+
+impl Cursor {
+    pub(crate) fn new(created_at: chrono::DateTime<chrono::Utc>, id: impl Into<String>) -> Self {
+        Self {
+            created_at,
+            id: id.into(),
+        }
+    }
+
+    /// `created_at` as RFC3339 with the microsecond precision PostgreSQL stores
+    pub(crate) fn created_at_rfc3339(&self) -> String {
+        self.created_at
+            .to_rfc3339_opts(chrono::SecondsFormat::Micros, true)
+    }
+
+    /// Encode as a URL-safe base64 string
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(format!("{}|{}", self.created_at_rfc3339(), self.id))
+    }
+
+    /// Decode a string produced by [`encode`](Self::encode)
+    ///
+    /// Fails with [`ObjectStoreError::Validation`] if it isn't a valid cursor.
+    pub fn decode(encoded: &str) -> Result<Self> {
+        let invalid = || ObjectStoreError::validation(format!("Invalid cursor '{}'", encoded));
+
+        let bytes = URL_SAFE_NO_PAD.decode(encoded).map_err(|_| invalid())?;
+        let payload = String::from_utf8(bytes).map_err(|_| invalid())?;
+        let (created_at, id) = payload.split_once('|').ok_or_else(invalid)?;
+        let created_at = chrono::DateTime::parse_from_rfc3339(created_at)
+            .map_err(|_| invalid())?
+            .with_timezone(&chrono::Utc);
+        if id.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(Self::new(created_at, id))
+    }
+}
+
+impl std::fmt::Display for Cursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
+
 // ============================================================================
 // Condition-based Filtering
 // ============================================================================
@@ -112,6 +278,7 @@ impl UpdateInstanceRequest {
 /// - Logical: AND, OR, NOT
 /// - Comparison: EQ, NE, GT, LT, GTE, LTE
 /// - Collection: IN, NOT_IN, CONTAINS
+/// - Text: STARTS_WITH, ENDS_WITH, CONTAINS_ANY
 /// - Null checks: IS_EMPTY, IS_NOT_EMPTY, IS_DEFINED
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Condition {
@@ -161,6 +328,29 @@ impl Condition {
         Self::new("LTE", vec![serde_json::json!(field.into()), value.into()])
     }
 
+    /// Create an inclusive range condition (`low <= field <= high`)
+    ///
+    /// Bounds are compared using the column's type, so timestamp columns accept
+    /// date strings such as `"2024-01-01"`.
+    pub fn between(
+        field: impl Into<String>,
+        low: impl Into<serde_json::Value>,
+        high: impl Into<serde_json::Value>,
+    ) -> Self {
+        Self::new(
+            "BETWEEN",
+            vec![serde_json::json!(field.into()), low.into(), high.into()],
+        )
+    }
+
+    /// Reference another field as the value of a comparison
+    ///
+    /// For example, `Condition::lt("start_date", Condition::field_ref("end_date"))`
+    /// compares the two columns directly.
+    pub fn field_ref(field: impl Into<String>) -> serde_json::Value {
+        serde_json::json!({ "field": field.into() })
+    }
+
     /// Create an IN condition
     pub fn r#in(field: impl Into<String>, values: Vec<serde_json::Value>) -> Self {
         Self::new(
@@ -169,6 +359,20 @@ impl Condition {
         )
     }
 
+    /// Create an IN condition on the auto-managed `id` column
+    pub fn id_in<I, S>(ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::r#in(
+            "id",
+            ids.into_iter()
+                .map(|id| serde_json::Value::String(id.into()))
+                .collect(),
+        )
+    }
+
     /// Create a NOT IN condition
     pub fn not_in(field: impl Into<String>, values: Vec<serde_json::Value>) -> Self {
         Self::new(
@@ -188,6 +392,86 @@ impl Condition {
         )
     }
 
+    /// Create a JSON_EQ condition on a value inside a Json column
+    ///
+    /// `path` is dotted (`"address.city"`); each segment may only contain
+    /// letters, digits, `_` and `-`.
+    pub fn json_eq(
+        field: impl Into<String>,
+        path: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        Self::new(
+            "JSON_EQ",
+            vec![
+                serde_json::json!(field.into()),
+                serde_json::json!(path.into()),
+                value.into(),
+            ],
+        )
+    }
+
+    /// Create a CONTAINS_ANY condition (case-insensitive match of any substring)
+    ///
+    /// `%` and `_` in the substrings match literally.
+    pub fn contains_any<I, S>(field: impl Into<String>, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::new(
+            "CONTAINS_ANY",
+            vec![
+                serde_json::json!(field.into()),
+                serde_json::Value::Array(
+                    values
+                        .into_iter()
+                        .map(|value| serde_json::Value::String(value.into()))
+                        .collect(),
+                ),
+            ],
+        )
+    }
+
+    /// Create a STARTS_WITH condition (prefix match, e.g. for autocomplete)
+    ///
+    /// `%` and `_` in the value match literally.
+    pub fn starts_with(field: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::new(
+            "STARTS_WITH",
+            vec![
+                serde_json::json!(field.into()),
+                serde_json::json!(value.into()),
+            ],
+        )
+    }
+
+    /// Create an ENDS_WITH condition (suffix match)
+    ///
+    /// `%` and `_` in the value match literally.
+    pub fn ends_with(field: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::new(
+            "ENDS_WITH",
+            vec![
+                serde_json::json!(field.into()),
+                serde_json::json!(value.into()),
+            ],
+        )
+    }
+
+    /// Create a NOT_CONTAINS condition (excludes substring matches)
+    ///
+    /// `%` and `_` in the value match literally.
+    pub fn not_contains(field: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::new(
+            "NOT_CONTAINS",
+            vec![
+                serde_json::json!(field.into()),
+                serde_json::json!(value.into()),
+            ],
+        )
+    }
+
     /// Create an IS_EMPTY condition
     pub fn is_empty(field: impl Into<String>) -> Self {
         Self::new("IS_EMPTY", vec![serde_json::json!(field.into())])
@@ -248,7 +532,7 @@ pub struct FilterRequest {
     /// Number of results to skip
     #[serde(default = "default_offset")]
     pub offset: i64,
-    /// Maximum number of results to return
+    /// Maximum number of results to return (0 returns only the total count)
     #[serde(default = "default_limit")]
     pub limit: i64,
     /// Filter condition
@@ -260,6 +544,41 @@ pub struct FilterRequest {
     /// Sort order for each field (e.g., ["desc", "asc"])
     #[serde(rename = "sortOrder", skip_serializing_if = "Option::is_none")]
     pub sort_order: Option<Vec<String>>,
+    /// Derived fields computed in the SELECT and returned under their alias
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub computed: Vec<ComputedField>,
+    /// The sort keys already identify rows uniquely, so the implicit `id`
+    /// tiebreaker that keeps pagination stable can be left out
+    #[serde(
+        rename = "uniqueSort",
+        default,
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    pub unique_sort: bool,
+}
+
+/// A derived field computed from an arithmetic expression over columns
+///
+/// The expression may reference schema columns (and the auto columns), numeric
+/// literals, `+ - * / %`, and parentheses; see
+/// [`validate_computed_expression`](crate::sql::expression::validate_computed_expression).
+/// The result appears in each instance's `properties` under `alias`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ComputedField {
+    /// Property name for the result (a lowercase identifier)
+    pub alias: String,
+    /// Arithmetic expression, e.g. `price * 1.2`
+    pub expression: String,
+}
+
+impl ComputedField {
+    /// Create a computed field
+    pub fn new(alias: impl Into<String>, expression: impl Into<String>) -> Self {
+        Self {
+            alias: alias.into(),
+            expression: expression.into(),
+        }
+    }
 }
 
 impl Default for FilterRequest {
@@ -270,6 +589,8 @@ impl Default for FilterRequest {
             condition: None,
             sort_by: None,
             sort_order: None,
+            computed: Vec::new(),
+            unique_sort: false,
         }
     }
 }
@@ -299,6 +620,22 @@ impl FilterRequest {
         self.sort_order = Some(sort_order);
         self
     }
+
+    /// Add a computed field, returned in `properties` under `alias`
+    pub fn with_computed(
+        mut self,
+        alias: impl Into<String>,
+        expression: impl Into<String>,
+    ) -> Self {
+        self.computed.push(ComputedField::new(alias, expression));
+        self
+    }
+
+    /// Declare that the sort keys are unique, skipping the `id` tiebreaker
+    pub fn with_unique_sort(mut self) -> Self {
+        self.unique_sort = true;
+        self
+    }
 }
 
 /// Simple filter using key-value pairs (for convenience)
@@ -306,9 +643,21 @@ impl FilterRequest {
 pub struct SimpleFilter {
     /// Schema name to query
     pub schema_name: String,
-    /// Key-value filters (all must match)
-    #[serde(default)]
-    pub filters: HashMap<String, serde_json::Value>,
+    /// Key-value filters (all must match), kept in insertion order
+    ///
+    /// Serialized as a JSON object; document order is preserved on deserialization.
+    #[serde(default, with = "ordered_filters")]
+    pub filters: Vec<(String, serde_json::Value)>,
+    /// Key-to-values filters matching any of the listed values (`IN`)
+    ///
+    /// Serialized as a JSON object of arrays, in insertion order.
+    #[serde(
+        default,
+        rename = "anyFilters",
+        with = "ordered_filters",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub any_filters: Vec<(String, Vec<serde_json::Value>)>,
     /// Maximum number of results
     #[serde(default = "default_simple_limit")]
     pub limit: i32,
@@ -321,20 +670,90 @@ fn default_simple_limit() -> i32 {
     100
 }
 
+/// Serde helpers mapping an ordered list of filters to and from a JSON object
+mod ordered_filters {
+    use serde::de::{MapAccess, Visitor};
+    use serde::ser::SerializeMap;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    pub fn serialize<S: Serializer, V: Serialize>(
+        filters: &[(String, V)],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(filters.len()))?;
+        for (key, value) in filters {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, V: Deserialize<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<(String, V)>, D::Error> {
+        struct FiltersVisitor<V>(PhantomData<V>);
+
+        impl<'de, V: Deserialize<'de>> Visitor<'de> for FiltersVisitor<V> {
+            type Value = Vec<(String, V)>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an object of column filters")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+                let mut filters: Self::Value = Vec::with_capacity(access.size_hint().unwrap_or(0));
+                while let Some((key, value)) = access.next_entry::<String, V>()? {
+                    // Later duplicates win, matching `SimpleFilter::filter`
+                    match filters.iter_mut().find(|(k, _)| *k == key) {
+                        Some(existing) => existing.1 = value,
+                        None => filters.push((key, value)),
+                    }
+                }
+                Ok(filters)
+            }
+        }
+
+        deserializer.deserialize_map(FiltersVisitor(PhantomData))
+    }
+}
+
 impl SimpleFilter {
     /// Create a new simple filter for a schema
     pub fn new(schema_name: impl Into<String>) -> Self {
         Self {
             schema_name: schema_name.into(),
-            filters: HashMap::new(),
+            filters: Vec::new(),
+            any_filters: Vec::new(),
             limit: 100,
             offset: 0,
         }
     }
 
     /// Add a filter condition
+    ///
+    /// Filters are applied in insertion order; re-adding a key replaces its value in place.
     pub fn filter(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
-        self.filters.insert(key.into(), value.into());
+        let key = key.into();
+        let value = value.into();
+        self.any_filters.retain(|(k, _)| *k != key);
+        match self.filters.iter_mut().find(|(k, _)| *k == key) {
+            Some(existing) => existing.1 = value,
+            None => self.filters.push((key, value)),
+        }
+        self
+    }
+
+    /// Add a filter matching any of the given values (`IN`)
+    ///
+    /// Replaces any earlier `filter` or `filter_any` on the same key.
+    pub fn filter_any(mut self, key: impl Into<String>, values: Vec<serde_json::Value>) -> Self {
+        let key = key.into();
+        self.filters.retain(|(k, _)| *k != key);
+        match self.any_filters.iter_mut().find(|(k, _)| *k == key) {
+            Some(existing) => existing.1 = values,
+            None => self.any_filters.push((key, values)),
+        }
         self
     }
 
@@ -358,35 +777,24 @@ impl SimpleFilter {
     }
 
     /// Convert simple filter to FilterRequest with condition
+    ///
+    /// Equality filters come first, then `filter_any` filters, all combined with AND.
     pub fn to_filter_request(&self) -> FilterRequest {
-        let condition = if self.filters.is_empty() {
-            None
-        } else {
-            let conditions: Vec<serde_json::Value> = self
-                .filters
+        let mut conditions: Vec<Condition> = self
+            .filters
+            .iter()
+            .map(|(key, value)| Condition::eq(key.as_str(), value.clone()))
+            .collect();
+        conditions.extend(
+            self.any_filters
                 .iter()
-                .map(|(key, value)| {
-                    serde_json::json!({
-                        "op": "EQ",
-                        "arguments": [key, value]
-                    })
-                })
-                .collect();
-
-            if conditions.len() == 1 {
-                Some(Condition {
-                    op: "EQ".to_string(),
-                    arguments: Some(vec![
-                        serde_json::json!(self.filters.keys().next().unwrap()),
-                        self.filters.values().next().unwrap().clone(),
-                    ]),
-                })
-            } else {
-                Some(Condition {
-                    op: "AND".to_string(),
-                    arguments: Some(conditions),
-                })
-            }
+                .map(|(key, values)| Condition::r#in(key.as_str(), values.clone())),
+        );
+
+        let condition = match conditions.len() {
+            0 => None,
+            1 => conditions.pop(),
+            _ => Some(Condition::and(conditions)),
         };
 
         FilterRequest {
@@ -395,6 +803,8 @@ impl SimpleFilter {
             condition,
             sort_by: None,
             sort_order: None,
+            computed: Vec::new(),
+            unique_sort: false,
         }
     }
 }
@@ -403,6 +813,40 @@ impl SimpleFilter {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_cursor_round_trip() {
+        let created_at = chrono::DateTime::parse_from_rfc3339("2024-05-01T12:30:45.123456Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let cursor = Cursor::new(created_at, "0190a1b2-c3d4|x");
+
+        let encoded = cursor.encode();
+        assert!(
+            encoded
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        );
+        assert_eq!(cursor.to_string(), encoded);
+        assert_eq!(Cursor::decode(&encoded).unwrap(), cursor);
+        assert_eq!(cursor.created_at_rfc3339(), "2024-05-01T12:30:45.123456Z");
+    }
+
+    #[test]
+    fn test_cursor_decode_rejects_garbage() {
+        for encoded in [
+            "",
+            "not base64!",
+            &URL_SAFE_NO_PAD.encode("no separator"),
+            &URL_SAFE_NO_PAD.encode("yesterday|abc"),
+            &URL_SAFE_NO_PAD.encode("2024-05-01T12:30:45Z|"),
+        ] {
+            assert!(matches!(
+                Cursor::decode(encoded),
+                Err(ObjectStoreError::Validation(_))
+            ));
+        }
+    }
+
     #[test]
     fn test_instance_builder() {
         let instance = Instance::new("inst-123", serde_json::json!({"name": "Test"}))
@@ -414,6 +858,86 @@ mod tests {
         assert_eq!(instance.schema_id, Some("schema-456".to_string()));
     }
 
+    #[test]
+    fn test_instance_etag_tracks_changes() {
+        let instance = Instance::new("inst-1", serde_json::json!({"name": "Test", "qty": 1}));
+        let etag = instance.etag();
+
+        assert_eq!(etag.len(), 16);
+        assert_eq!(etag, instance.clone().with_schema_name("products").etag());
+
+        let mut changed = instance.clone();
+        changed.properties["qty"] = serde_json::json!(2);
+        assert_ne!(changed.etag(), etag);
+
+        let mut touched = instance.clone();
+        touched.updated_at = "2030-01-01T00:00:00+00:00".to_string();
+        assert_ne!(touched.etag(), etag);
+    }
+
+    #[test]
+    fn test_instance_timestamp_accessors() {
+        let mut instance = Instance::new("inst-1", serde_json::json!({}));
+        instance.created_at = "2024-03-01T12:30:00+02:00".to_string();
+        instance.updated_at = String::new();
+
+        let created = instance.created_at_utc().unwrap();
+        assert_eq!(created.to_rfc3339(), "2024-03-01T10:30:00+00:00");
+        assert!(instance.updated_at_utc().is_none());
+
+        instance.updated_at = "not a timestamp".to_string();
+        assert!(instance.updated_at_utc().is_none());
+    }
+
+    #[test]
+    fn test_instance_timestamps_serialize_unchanged() {
+        let instance = Instance::new("inst-1", serde_json::json!({}));
+        let json = serde_json::to_value(&instance).unwrap();
+
+        assert_eq!(json["createdAt"], instance.created_at);
+        assert_eq!(
+            instance.created_at_utc().unwrap().to_rfc3339(),
+            instance.created_at
+        );
+
+        let round_trip: Instance = serde_json::from_value(json).unwrap();
+        assert_eq!(round_trip.updated_at_utc(), instance.updated_at_utc());
+    }
+
+    #[test]
+    fn test_instance_to_flat_json() {
+        let mut instance = Instance::new("inst-1", serde_json::json!({"name": "Widget", "qty": 3}))
+            .with_schema_name("Products");
+        instance.created_at = "2024-01-01T00:00:00+00:00".to_string();
+        instance.updated_at = "2024-01-02T00:00:00+00:00".to_string();
+
+        assert_eq!(
+            instance.to_flat_json(),
+            serde_json::json!({
+                "id": "inst-1",
+                "createdAt": "2024-01-01T00:00:00+00:00",
+                "updatedAt": "2024-01-02T00:00:00+00:00",
+                "name": "Widget",
+                "qty": 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_instance_to_flat_json_collisions() {
+        let mut instance = Instance::new(
+            "inst-1",
+            serde_json::json!({"id": "shadow", "updatedAt": "yesterday", "note": "kept"}),
+        );
+        instance.updated_at = "2024-01-02T00:00:00+00:00".to_string();
+
+        let flat = instance.to_flat_json();
+        assert_eq!(flat["id"], "inst-1");
+        assert_eq!(flat["updatedAt"], "2024-01-02T00:00:00+00:00");
+        assert_eq!(flat["note"], "kept");
+        assert_eq!(flat.as_object().unwrap().len(), 4);
+    }
+
     #[test]
     fn test_condition_builders() {
         let cond = Condition::eq("status", "active");
@@ -426,6 +950,16 @@ mod tests {
         assert_eq!(cond.op, "AND");
     }
 
+    #[test]
+    fn test_condition_id_in() {
+        let cond = Condition::id_in(["a", "b"]);
+        assert_eq!(cond.op, "IN");
+        assert_eq!(
+            cond.arguments,
+            Some(vec![serde_json::json!("id"), serde_json::json!(["a", "b"])])
+        );
+    }
+
     #[test]
     fn test_simple_filter() {
         let filter = SimpleFilter::new("products")
@@ -445,6 +979,94 @@ mod tests {
         assert_eq!(request.condition.unwrap().op, "AND");
     }
 
+    #[test]
+    fn test_simple_filter_insertion_order() {
+        let filter = SimpleFilter::new("products")
+            .filter("status", "active")
+            .filter("category", "electronics");
+
+        let condition = filter.to_filter_request().condition.unwrap();
+        let arguments = condition.arguments.unwrap();
+        assert_eq!(
+            arguments,
+            vec![
+                serde_json::json!({"op": "EQ", "arguments": ["status", "active"]}),
+                serde_json::json!({"op": "EQ", "arguments": ["category", "electronics"]}),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_simple_filter_replaces_duplicate_key() {
+        let filter = SimpleFilter::new("products")
+            .filter("status", "active")
+            .filter("category", "electronics")
+            .filter("status", "archived");
+
+        assert_eq!(
+            filter.filters,
+            vec![
+                ("status".to_string(), serde_json::json!("archived")),
+                ("category".to_string(), serde_json::json!("electronics")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_simple_filter_any_uses_in() {
+        let filter = SimpleFilter::new("products")
+            .filter("category", "electronics")
+            .filter_any(
+                "status",
+                vec![serde_json::json!("active"), serde_json::json!("pending")],
+            );
+
+        let condition = filter.to_filter_request().condition.unwrap();
+        assert_eq!(condition.op, "AND");
+        assert_eq!(
+            condition.arguments.unwrap(),
+            vec![
+                serde_json::json!({"op": "EQ", "arguments": ["category", "electronics"]}),
+                serde_json::json!({"op": "IN", "arguments": ["status", ["active", "pending"]]}),
+            ]
+        );
+
+        let only_any = SimpleFilter::new("products")
+            .filter("status", "archived")
+            .filter_any("status", vec![serde_json::json!("active")]);
+        assert!(only_any.filters.is_empty());
+        assert_eq!(only_any.to_filter_request().condition.unwrap().op, "IN");
+    }
+
+    #[test]
+    fn test_simple_filter_serde_round_trip() {
+        let json = r#"{"schema_name": "products", "filters": {"b": 1, "a": 2}}"#;
+        let filter: SimpleFilter = serde_json::from_str(json).unwrap();
+        assert_eq!(filter.filters[0].0, "b");
+        assert_eq!(filter.filters[1].0, "a");
+
+        let value = serde_json::to_value(&filter).unwrap();
+        assert_eq!(value["filters"], serde_json::json!({"b": 1, "a": 2}));
+    }
+
+    #[test]
+    fn test_page_metadata() {
+        let page = Page::new(vec![1, 2, 3], 3, 10, 0);
+        assert_eq!(page.page_count, 1);
+        assert!(!page.has_more);
+
+        let page = Page::new(vec![1, 2], 5, 2, 0);
+        assert_eq!(page.page_count, 3);
+        assert!(page.has_more);
+
+        let last = Page::new(vec![5], 5, 2, 4);
+        assert!(!last.has_more);
+
+        let empty: Page<i32> = Page::new(Vec::new(), 0, 10, 0);
+        assert_eq!(empty.page_count, 0);
+        assert!(!empty.has_more);
+    }
+
     #[test]
     fn test_filter_request_builder() {
         let request = FilterRequest::new()
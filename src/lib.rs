@@ -95,16 +95,27 @@ pub mod store;
 pub mod types;
 
 // Re-export main types for convenience
-pub use config::{AutoColumns, StoreConfig, StoreConfigBuilder};
+pub use config::{
+    AutoColumns, DriftCheck, IdGenerator, NullsOrdering, StoreConfig, StoreConfigBuilder,
+    UnknownEnumValues,
+};
 pub use error::{ObjectStoreError, Result};
 pub use instance::{
-    Condition, CreateInstanceRequest, FilterRequest, Instance, SimpleFilter, UpdateInstanceRequest,
+    BulkCreateResult, ComputedField, Condition, CreateInstanceRequest, Cursor, FilterRequest,
+    Instance, Page, SimpleFilter, UpdateInstanceRequest,
+};
+pub use schema::{
+    ColumnDescription, ColumnTypeMismatch, CreateSchemaRequest, FieldUpdate, Schema,
+    SchemaDescription, SchemaDrift, SchemaUpdatePreview, UpdateSchemaRequest, to_json_schema,
 };
-pub use schema::{CreateSchemaRequest, Schema, UpdateSchemaRequest};
-pub use store::ObjectStore;
-pub use types::{ColumnDefinition, ColumnType, IndexDefinition};
+pub use store::{ObjectStore, UpsertStream};
+pub use types::{ColumnDefinition, ColumnType, IndexDefinition, Normalization};
 
 // Re-export SQL utilities for advanced users
-pub use sql::condition::{build_condition_clause, build_order_by_clause};
+pub use sql::condition::{
+    SqlParam, build_condition_clause, build_order_by_clause, build_order_by_clause_with_nulls,
+    build_stable_order_by_clause, validate_condition,
+};
 pub use sql::ddl::DdlGenerator;
+pub use sql::query::{BuiltQuery, QueryBuilder};
 pub use sql::sanitize::{quote_identifier, validate_identifier};
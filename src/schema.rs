@@ -1,10 +1,17 @@
 //! Schema-related types for Object Store
 //!
-//! Includes Schema, CreateSchemaRequest, UpdateSchemaRequest.
+//! Includes Schema, SchemaDescription, CreateSchemaRequest, UpdateSchemaRequest, FieldUpdate.
+
+use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use crate::types::{ColumnDefinition, IndexDefinition};
+use crate::config::StoreConfig;
+use crate::error::{ObjectStoreError, Result};
+use crate::sql::ddl::validate_storage_params;
+use crate::sql::expression::{validate_default_value, validate_predicate};
+use crate::sql::sanitize::validate_identifier;
+use crate::types::{ColumnDefinition, ColumnType, IndexDefinition};
 
 /// Schema metadata stored in the `__schema` table
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,6 +36,23 @@ pub struct Schema {
     /// Optional index definitions
     #[serde(skip_serializing_if = "Option::is_none")]
     pub indexes: Option<Vec<IndexDefinition>>,
+    /// Whether the default `created_at` index was created for the table
+    #[serde(rename = "defaultIndex", default = "default_true")]
+    pub default_index: bool,
+    /// Table storage parameters the table was created with (e.g. `fillfactor`)
+    #[serde(
+        rename = "storageParams",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub storage_params: Option<HashMap<String, String>>,
+    /// Tablespace holding the table and its indexes, if not the default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tablespace: Option<String>,
+}
+
+fn default_true() -> bool {
+    true
 }
 
 impl Schema {
@@ -49,6 +73,9 @@ impl Schema {
             table_name: table_name.into(),
             columns,
             indexes: None,
+            default_index: true,
+            storage_params: None,
+            tablespace: None,
         }
     }
 
@@ -63,10 +90,195 @@ impl Schema {
         self.indexes = Some(indexes);
         self
     }
+
+    /// Build an introspection view of this schema's columns
+    pub fn describe(&self) -> SchemaDescription {
+        let indexes = self.indexes.as_deref().unwrap_or_default();
+        let columns = self
+            .columns
+            .iter()
+            .map(|col| ColumnDescription {
+                name: col.name.clone(),
+                column_type: col.column_type.clone(),
+                sql_type: col.column_type.to_sql_type(&col.name),
+                nullable: col.nullable,
+                unique: col.unique,
+                default_value: col.default_value.clone(),
+                references: col.references.clone(),
+                indexed: indexes.iter().any(|idx| idx.columns.contains(&col.name)),
+            })
+            .collect();
+
+        SchemaDescription {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            description: self.description.clone(),
+            table_name: self.table_name.clone(),
+            columns,
+        }
+    }
 }
 
-/// Request to create a new schema
+/// JSON Schema (draft 2020-12) document for a schema's instances
+///
+/// Describes the flat shape of [`Instance::to_flat_json`](crate::Instance::to_flat_json):
+/// one property per column plus `id`, `createdAt`, and `updatedAt`, which are
+/// marked `readOnly`. Nullable columns also accept `null`, and `required` lists
+/// the non-nullable columns without a default, so the document also works for
+/// request bodies of OpenAPI 3.1 APIs. JSON columns accept any value.
+pub fn to_json_schema(schema: &Schema) -> serde_json::Value {
+    let mut properties = serde_json::Map::new();
+    for col in &schema.columns {
+        properties.insert(col.name.clone(), column_json_schema(col));
+    }
+    properties.insert(
+        "id".to_string(),
+        serde_json::json!({ "type": "string", "readOnly": true }),
+    );
+    for name in ["createdAt", "updatedAt"] {
+        properties.insert(
+            name.to_string(),
+            serde_json::json!({ "type": "string", "format": "date-time", "readOnly": true }),
+        );
+    }
+
+    let required: Vec<&str> = schema
+        .columns
+        .iter()
+        .filter(|col| !col.nullable && col.default_value.is_none())
+        .map(|col| col.name.as_str())
+        .collect();
+
+    let mut document = serde_json::json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": schema.name,
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    });
+    if let Some(description) = &schema.description {
+        document["description"] = description.clone().into();
+    }
+    document
+}
+
+fn column_json_schema(col: &ColumnDefinition) -> serde_json::Value {
+    let (json_type, mut property) = match &col.column_type {
+        ColumnType::String => ("string", serde_json::json!({})),
+        ColumnType::Integer => ("integer", serde_json::json!({ "format": "int64" })),
+        ColumnType::Decimal { .. } => ("number", serde_json::json!({})),
+        ColumnType::Boolean => ("boolean", serde_json::json!({})),
+        ColumnType::Timestamp { .. } => ("string", serde_json::json!({ "format": "date-time" })),
+        ColumnType::Json => return serde_json::json!({}),
+        ColumnType::Enum { values } => {
+            let mut allowed: Vec<serde_json::Value> =
+                values.iter().cloned().map(Into::into).collect();
+            if col.nullable {
+                allowed.push(serde_json::Value::Null);
+            }
+            ("string", serde_json::json!({ "enum": allowed }))
+        }
+        #[cfg(feature = "postgis")]
+        ColumnType::Point => ("object", serde_json::json!({})),
+    };
+
+    property["type"] = if col.nullable {
+        serde_json::json!([json_type, "null"])
+    } else {
+        json_type.into()
+    };
+    property
+}
+
+/// Read-only introspection view of a schema, with resolved column details
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaDescription {
+    /// Schema identifier
+    pub id: String,
+    /// Human-readable name of the schema
+    pub name: String,
+    /// Optional description
+    pub description: Option<String>,
+    /// Database table name for instances of this schema
+    #[serde(rename = "tableName")]
+    pub table_name: String,
+    /// Column details in declaration order
+    pub columns: Vec<ColumnDescription>,
+}
+
+/// Resolved details of a single schema column
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColumnDescription {
+    /// Column name
+    pub name: String,
+    /// Declared column type
+    #[serde(flatten)]
+    pub column_type: ColumnType,
+    /// PostgreSQL type the column is created with
+    #[serde(rename = "sqlType")]
+    pub sql_type: String,
+    /// Whether the column allows NULL values
+    pub nullable: bool,
+    /// Whether the column has a UNIQUE constraint
+    pub unique: bool,
+    /// Default value (SQL expression)
+    #[serde(rename = "default", skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<String>,
+    /// Referenced table, if the column is a foreign key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub references: Option<String>,
+    /// Whether the column is part of any declared index
+    pub indexed: bool,
+}
+
+/// Differences between a schema's metadata and its physical data table, from
+/// [`ObjectStore::schema_drift`](crate::ObjectStore::schema_drift)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SchemaDrift {
+    /// Columns the metadata (or the auto-managed columns) expect but the table lacks
+    #[serde(rename = "missingColumns")]
+    pub missing_columns: Vec<String>,
+    /// Table columns the metadata doesn't know about
+    #[serde(rename = "unexpectedColumns")]
+    pub unexpected_columns: Vec<String>,
+    /// Schema columns whose table type differs from the declared type
+    #[serde(rename = "typeMismatches")]
+    pub type_mismatches: Vec<ColumnTypeMismatch>,
+}
+
+impl SchemaDrift {
+    /// Whether the table matches the metadata
+    pub fn is_empty(&self) -> bool {
+        self.missing_columns.is_empty()
+            && self.unexpected_columns.is_empty()
+            && self.type_mismatches.is_empty()
+    }
+}
+
+/// A schema column whose type in the table differs from the metadata
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnTypeMismatch {
+    /// Column name
+    pub column: String,
+    /// PostgreSQL type the metadata declares
+    pub expected: String,
+    /// PostgreSQL type the table has
+    pub actual: String,
+}
+
+/// What [`ObjectStore::update_schema`](crate::ObjectStore::update_schema) would
+/// do for a request, from
+/// [`ObjectStore::preview_update_schema`](crate::ObjectStore::preview_update_schema)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SchemaUpdatePreview {
+    /// ALTER TABLE statements, in execution order
+    pub statements: Vec<String>,
+    /// Changes that lose data or may fail on the stored rows
+    pub warnings: Vec<String>,
+}
+
+/// Request to create a new schema
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CreateSchemaRequest {
     /// Human-readable name of the schema
     pub name: String,
@@ -80,6 +292,28 @@ pub struct CreateSchemaRequest {
     /// Optional index definitions
     #[serde(skip_serializing_if = "Option::is_none")]
     pub indexes: Option<Vec<IndexDefinition>>,
+    /// Optional partial-index predicate for the default `created_at` index
+    /// (e.g. `"status = 'active'"`); may only reference the schema's columns
+    #[serde(
+        rename = "defaultIndexPredicate",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub default_index_predicate: Option<String>,
+    /// Whether to create the default `created_at` index, overriding
+    /// [`StoreConfig::default_index`](crate::StoreConfig::default_index)
+    #[serde(rename = "createDefaultIndex", skip_serializing_if = "Option::is_none")]
+    pub create_default_index: Option<bool>,
+    /// Table storage parameters emitted as `WITH (...)`, limited to
+    /// `fillfactor` and `autovacuum_*`
+    #[serde(
+        rename = "storageParams",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub storage_params: Option<HashMap<String, String>>,
+    /// Tablespace for the table and its indexes (defaults to the database's)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tablespace: Option<String>,
 }
 
 impl CreateSchemaRequest {
@@ -95,6 +329,10 @@ impl CreateSchemaRequest {
             table_name: table_name.into(),
             columns,
             indexes: None,
+            default_index_predicate: None,
+            create_default_index: None,
+            storage_params: None,
+            tablespace: None,
         }
     }
 
@@ -109,6 +347,177 @@ impl CreateSchemaRequest {
         self.indexes = Some(indexes);
         self
     }
+
+    /// Restrict the default index to rows matching a predicate
+    pub fn with_default_index_predicate(mut self, predicate: impl Into<String>) -> Self {
+        self.default_index_predicate = Some(predicate.into());
+        self
+    }
+
+    /// Create or skip the default `created_at` index for this schema
+    pub fn with_default_index(mut self, enabled: bool) -> Self {
+        self.create_default_index = Some(enabled);
+        self
+    }
+
+    /// Set a table storage parameter (e.g. `fillfactor` for HOT updates)
+    pub fn with_storage_param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.storage_params
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), value.into());
+        self
+    }
+
+    /// Create the table and its indexes in a tablespace
+    pub fn with_tablespace(mut self, tablespace: impl Into<String>) -> Self {
+        self.tablespace = Some(tablespace.into());
+        self
+    }
+
+    /// Check the request without a database connection
+    ///
+    /// Runs every check [`ObjectStore::create_schema`](crate::ObjectStore::create_schema)
+    /// makes before it touches the database: size limits, table and column
+    /// identifiers (including collisions with the auto-managed columns),
+    /// duplicate columns, column defaults (against the column type), enum
+    /// values, index columns, the default index options, storage parameters,
+    /// and the tablespace name. Whether the name or table is already taken can
+    /// only be checked by `create_schema`.
+    pub fn validate(&self, config: &StoreConfig) -> Result<()> {
+        config.check_schema_limits(
+            self.columns.len(),
+            self.indexes.as_ref().map_or(0, Vec::len),
+        )?;
+
+        validate_identifier(&self.table_name, &[]).map_err(ObjectStoreError::validation)?;
+
+        let managed_columns = config.managed_column_names();
+        let mut known_columns = managed_columns.clone();
+        for col in &self.columns {
+            validate_identifier(&col.name, &managed_columns)
+                .map_err(ObjectStoreError::validation)?;
+            if known_columns.contains(&col.name.as_str()) {
+                return Err(ObjectStoreError::validation(format!(
+                    "Column '{}' is defined more than once",
+                    col.name
+                )));
+            }
+            known_columns.push(&col.name);
+
+            if let Some(default) = &col.default_value {
+                validate_default_value(default, &col.column_type).map_err(|e| {
+                    ObjectStoreError::validation(format!("Column '{}': {}", col.name, e))
+                })?;
+            }
+
+            if let ColumnType::Enum { values } = &col.column_type {
+                if values.is_empty() {
+                    return Err(ObjectStoreError::validation(format!(
+                        "Enum column '{}' must allow at least one value",
+                        col.name
+                    )));
+                }
+                if let Some((i, value)) = values
+                    .iter()
+                    .enumerate()
+                    .find(|(i, value)| values[..*i].contains(value))
+                {
+                    return Err(ObjectStoreError::validation(format!(
+                        "Enum column '{}' lists value '{}' more than once (at position {})",
+                        col.name, value, i
+                    )));
+                }
+            }
+        }
+
+        for index in self.indexes.iter().flatten() {
+            if index.columns.is_empty() {
+                return Err(ObjectStoreError::validation(format!(
+                    "Index '{}' must cover at least one column",
+                    index.name
+                )));
+            }
+            if let Some(column) = index
+                .columns
+                .iter()
+                .find(|column| !known_columns.contains(&column.as_str()))
+            {
+                return Err(ObjectStoreError::validation(format!(
+                    "Index '{}' references unknown column '{}'",
+                    index.name, column
+                )));
+            }
+        }
+
+        let default_index = self.create_default_index.unwrap_or(config.default_index);
+        if !default_index && self.default_index_predicate.is_some() {
+            return Err(ObjectStoreError::validation(
+                "A default index predicate requires the default index",
+            ));
+        }
+
+        if let Some(params) = &self.storage_params {
+            validate_storage_params(params).map_err(ObjectStoreError::validation)?;
+        }
+
+        if let Some(tablespace) = &self.tablespace {
+            validate_identifier(tablespace, &[]).map_err(ObjectStoreError::validation)?;
+        }
+
+        if let Some(predicate) = &self.default_index_predicate {
+            validate_predicate(predicate, &known_columns).map_err(|e| {
+                ObjectStoreError::validation(format!("Invalid default index predicate: {}", e))
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Tri-state update for a nullable field
+///
+/// In JSON, an absent field is `Unchanged`, `null` is `Clear`, and any other
+/// value is `Set`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum FieldUpdate<T> {
+    /// Leave the stored value as is
+    #[default]
+    Unchanged,
+    /// Replace the stored value
+    Set(T),
+    /// Reset the stored value to NULL
+    Clear,
+}
+
+impl<T> FieldUpdate<T> {
+    /// Whether this update leaves the field untouched
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, FieldUpdate::Unchanged)
+    }
+}
+
+impl<T: Serialize> Serialize for FieldUpdate<T> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            FieldUpdate::Set(value) => value.serialize(serializer),
+            FieldUpdate::Unchanged | FieldUpdate::Clear => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for FieldUpdate<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        // Only reached when the field is present; absent fields use `Default`
+        Ok(match Option::<T>::deserialize(deserializer)? {
+            Some(value) => FieldUpdate::Set(value),
+            None => FieldUpdate::Clear,
+        })
+    }
 }
 
 /// Request to update an existing schema
@@ -116,8 +525,9 @@ impl CreateSchemaRequest {
 pub struct UpdateSchemaRequest {
     /// New name (optional)
     pub name: Option<String>,
-    /// New description (optional)
-    pub description: Option<String>,
+    /// Description update (unchanged, set, or cleared)
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_unchanged")]
+    pub description: FieldUpdate<String>,
     /// New column definitions (optional)
     pub columns: Option<Vec<ColumnDefinition>>,
     /// New index definitions (optional)
@@ -138,7 +548,13 @@ impl UpdateSchemaRequest {
 
     /// Set new description
     pub fn with_description(mut self, description: impl Into<String>) -> Self {
-        self.description = Some(description.into());
+        self.description = FieldUpdate::Set(description.into());
+        self
+    }
+
+    /// Clear the description
+    pub fn clear_description(mut self) -> Self {
+        self.description = FieldUpdate::Clear;
         self
     }
 
@@ -181,6 +597,107 @@ mod tests {
         assert!(schema.indexes.is_some());
     }
 
+    #[test]
+    fn test_to_json_schema() {
+        let schema = Schema::new(
+            "schema-123",
+            "Orders",
+            "orders",
+            vec![
+                ColumnDefinition::new("code", ColumnType::String).not_null(),
+                ColumnDefinition::new("note", ColumnType::String),
+                ColumnDefinition::new("quantity", ColumnType::Integer)
+                    .not_null()
+                    .default("1"),
+                ColumnDefinition::new("total", ColumnType::decimal(10, 2)).not_null(),
+                ColumnDefinition::new("paid", ColumnType::Boolean),
+                ColumnDefinition::new("placed_at", ColumnType::timestamp()).not_null(),
+                ColumnDefinition::new("shipped_at", ColumnType::naive_timestamp()),
+                ColumnDefinition::new("payload", ColumnType::Json).not_null(),
+                ColumnDefinition::new(
+                    "status",
+                    ColumnType::Enum {
+                        values: vec!["open".to_string(), "closed".to_string()],
+                    },
+                ),
+            ],
+        )
+        .with_description("Customer orders");
+
+        let document = to_json_schema(&schema);
+        assert_eq!(
+            document,
+            serde_json::json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "title": "Orders",
+                "description": "Customer orders",
+                "type": "object",
+                "properties": {
+                    "code": { "type": "string" },
+                    "note": { "type": ["string", "null"] },
+                    "quantity": { "type": "integer", "format": "int64" },
+                    "total": { "type": "number" },
+                    "paid": { "type": ["boolean", "null"] },
+                    "placed_at": { "type": "string", "format": "date-time" },
+                    "shipped_at": { "type": ["string", "null"], "format": "date-time" },
+                    "payload": {},
+                    "status": {
+                        "type": ["string", "null"],
+                        "enum": ["open", "closed", null]
+                    },
+                    "id": { "type": "string", "readOnly": true },
+                    "createdAt": { "type": "string", "format": "date-time", "readOnly": true },
+                    "updatedAt": { "type": "string", "format": "date-time", "readOnly": true }
+                },
+                "required": ["code", "total", "placed_at", "payload"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_schema_describe() {
+        let schema = Schema::new(
+            "schema-123",
+            "Orders",
+            "orders",
+            vec![
+                ColumnDefinition::new("code", ColumnType::String)
+                    .unique()
+                    .not_null(),
+                ColumnDefinition::new("total", ColumnType::decimal(10, 2)).default("0"),
+                ColumnDefinition::new("customer_id", ColumnType::String).references("customers"),
+                ColumnDefinition::new("notes", ColumnType::String),
+            ],
+        )
+        .with_indexes(vec![IndexDefinition::new(
+            "customer_total_idx",
+            vec!["customer_id".to_string(), "total".to_string()],
+        )]);
+
+        let description = schema.describe();
+        assert_eq!(description.name, "Orders");
+        assert_eq!(description.columns.len(), 4);
+
+        let code = &description.columns[0];
+        assert_eq!(code.sql_type, "TEXT");
+        assert!(!code.nullable);
+        assert!(code.unique);
+        assert!(!code.indexed);
+
+        let total = &description.columns[1];
+        assert_eq!(total.sql_type, "NUMERIC(10,2)");
+        assert_eq!(total.default_value, Some("0".to_string()));
+        assert!(total.indexed);
+
+        let customer = &description.columns[2];
+        assert_eq!(customer.references, Some("customers".to_string()));
+        assert!(customer.indexed);
+
+        let notes = &description.columns[3];
+        assert!(notes.nullable);
+        assert!(!notes.indexed);
+    }
+
     #[test]
     fn test_create_schema_request_builder() {
         let request = CreateSchemaRequest::new(
@@ -193,6 +710,146 @@ mod tests {
         assert_eq!(request.name, "Products");
         assert_eq!(request.table_name, "products");
         assert_eq!(request.description, Some("Product catalog".to_string()));
+        assert!(request.default_index_predicate.is_none());
+    }
+
+    #[test]
+    fn test_create_schema_request_default_index_predicate() {
+        let request = CreateSchemaRequest::new(
+            "Orders",
+            "orders",
+            vec![ColumnDefinition::new("status", ColumnType::String)],
+        )
+        .with_default_index_predicate("status = 'open'");
+
+        assert_eq!(
+            request.default_index_predicate,
+            Some("status = 'open'".to_string())
+        );
+
+        let json = serde_json::to_string(&request).unwrap();
+        assert!(json.contains("\"defaultIndexPredicate\""));
+    }
+
+    fn products_request() -> CreateSchemaRequest {
+        CreateSchemaRequest::new(
+            "Products",
+            "products",
+            vec![
+                ColumnDefinition::new("sku", ColumnType::String).unique(),
+                ColumnDefinition::new(
+                    "status",
+                    ColumnType::Enum {
+                        values: vec!["active".to_string(), "archived".to_string()],
+                    },
+                ),
+            ],
+        )
+        .with_indexes(vec![IndexDefinition::new(
+            "status_created_idx",
+            vec!["status".to_string(), "created_at".to_string()],
+        )])
+        .with_default_index_predicate("status = 'active'")
+        .with_storage_param("fillfactor", "90")
+    }
+
+    fn validation_error(request: &CreateSchemaRequest, config: &StoreConfig) -> String {
+        match request.validate(config) {
+            Err(ObjectStoreError::Validation(msg)) => msg,
+            other => panic!("Expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_create_schema_request_validate() {
+        let config = StoreConfig::builder("postgres://localhost/test").build();
+        assert!(products_request().validate(&config).is_ok());
+
+        // Size limits
+        let limited = StoreConfig::builder("postgres://localhost/test")
+            .max_columns(1)
+            .build();
+        assert!(validation_error(&products_request(), &limited).contains("columns"));
+
+        // Table identifiers
+        let mut request = products_request();
+        request.table_name = "select".to_string();
+        assert!(validation_error(&request, &config).contains("reserved keyword"));
+
+        let mut request = products_request();
+        request.table_name = "1products".to_string();
+        assert!(validation_error(&request, &config).contains("Must start with"));
+
+        // Column identifiers, auto-column collisions and duplicates
+        let mut request = products_request();
+        request.columns[0].name = "Sku".to_string();
+        assert!(validation_error(&request, &config).contains("'Sku' is invalid"));
+
+        let mut request = products_request();
+        request.columns[0].name = "created_at".to_string();
+        assert!(validation_error(&request, &config).contains("is reserved"));
+
+        let mut request = products_request();
+        request
+            .columns
+            .push(ColumnDefinition::new("sku", ColumnType::Integer));
+        assert!(validation_error(&request, &config).contains("more than once"));
+
+        // `deleted` is only managed with soft delete on
+        let mut request = products_request();
+        request
+            .columns
+            .push(ColumnDefinition::new("deleted", ColumnType::Boolean));
+        assert!(validation_error(&request, &config).contains("is reserved"));
+        let hard_delete = StoreConfig::builder("postgres://localhost/test")
+            .soft_delete(false)
+            .build();
+        assert!(request.validate(&hard_delete).is_ok());
+
+        // Column defaults must suit the column type
+        let mut request = products_request();
+        request.columns[0].default_value = Some("NOW()".to_string());
+        assert!(validation_error(&request, &config).contains("not allowed"));
+
+        let mut request = products_request();
+        request.columns[0].default_value = Some("'x'); DROP TABLE users;--".to_string());
+        assert!(request.validate(&config).is_err());
+
+        // Enum values
+        let mut request = products_request();
+        request.columns[1].column_type = ColumnType::Enum { values: vec![] };
+        assert!(validation_error(&request, &config).contains("at least one value"));
+
+        let mut request = products_request();
+        request.columns[1].column_type = ColumnType::Enum {
+            values: vec!["active".to_string(), "active".to_string()],
+        };
+        assert!(validation_error(&request, &config).contains("'active' more than once"));
+
+        // Index columns
+        let request = products_request().with_indexes(vec![IndexDefinition::new(
+            "price_idx",
+            vec!["price".to_string()],
+        )]);
+        assert!(validation_error(&request, &config).contains("unknown column 'price'"));
+
+        let request =
+            products_request().with_indexes(vec![IndexDefinition::new("empty_idx", vec![])]);
+        assert!(validation_error(&request, &config).contains("at least one column"));
+
+        // Default index options
+        let request = products_request().with_default_index(false);
+        assert!(validation_error(&request, &config).contains("requires the default index"));
+
+        let request = products_request().with_default_index_predicate("price > 0");
+        assert!(validation_error(&request, &config).contains("Invalid default index predicate"));
+
+        // Storage parameters and tablespace
+        let request = products_request().with_storage_param("oids", "true");
+        assert!(validation_error(&request, &config).contains("not allowed"));
+
+        let request = products_request().with_tablespace("fast disk");
+        assert!(validation_error(&request, &config).contains("is invalid"));
     }
 
     #[test]
@@ -202,10 +859,44 @@ mod tests {
             .with_description("New description");
 
         assert_eq!(request.name, Some("New Name".to_string()));
-        assert_eq!(request.description, Some("New description".to_string()));
+        assert_eq!(
+            request.description,
+            FieldUpdate::Set("New description".to_string())
+        );
         assert!(request.columns.is_none());
     }
 
+    #[test]
+    fn test_update_schema_request_clear_description() {
+        let request = UpdateSchemaRequest::new().clear_description();
+        assert_eq!(request.description, FieldUpdate::Clear);
+
+        let request = UpdateSchemaRequest::new();
+        assert!(request.description.is_unchanged());
+    }
+
+    #[test]
+    fn test_update_schema_request_description_deserialization() {
+        let unchanged: UpdateSchemaRequest = serde_json::from_str(r#"{"name": "x"}"#).unwrap();
+        assert_eq!(unchanged.description, FieldUpdate::Unchanged);
+
+        let cleared: UpdateSchemaRequest =
+            serde_json::from_str(r#"{"description": null}"#).unwrap();
+        assert_eq!(cleared.description, FieldUpdate::Clear);
+
+        let set: UpdateSchemaRequest = serde_json::from_str(r#"{"description": "New"}"#).unwrap();
+        assert_eq!(set.description, FieldUpdate::Set("New".to_string()));
+    }
+
+    #[test]
+    fn test_update_schema_request_description_serialization() {
+        let json = serde_json::to_value(UpdateSchemaRequest::new()).unwrap();
+        assert!(json.get("description").is_none());
+
+        let json = serde_json::to_value(UpdateSchemaRequest::new().clear_description()).unwrap();
+        assert_eq!(json["description"], serde_json::Value::Null);
+    }
+
     #[test]
     fn test_schema_serialization() {
         let schema = Schema::new(
@@ -220,4 +911,68 @@ mod tests {
         assert!(json.contains("\"updatedAt\""));
         assert!(json.contains("\"tableName\""));
     }
+
+    #[test]
+    fn test_schema_default_index_defaults_to_true() {
+        let json = r#"{
+            "id": "1",
+            "createdAt": "2024-01-01T00:00:00Z",
+            "updatedAt": "2024-01-01T00:00:00Z",
+            "name": "Test",
+            "description": null,
+            "tableName": "test_table",
+            "columns": []
+        }"#;
+
+        let schema: Schema = serde_json::from_str(json).unwrap();
+        assert!(schema.default_index);
+    }
+
+    #[test]
+    fn test_create_schema_request_with_default_index() {
+        let request = CreateSchemaRequest::new("Lookup", "lookup", vec![]);
+        assert_eq!(request.create_default_index, None);
+
+        let request = request.with_default_index(false);
+        assert_eq!(request.create_default_index, Some(false));
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["createDefaultIndex"], false);
+    }
+
+    #[test]
+    fn test_create_schema_request_with_storage_params() {
+        let request = CreateSchemaRequest::new("Counters", "counters", vec![])
+            .with_storage_param("fillfactor", "70")
+            .with_storage_param("autovacuum_enabled", "true");
+
+        let params = request.storage_params.as_ref().unwrap();
+        assert_eq!(params.get("fillfactor").map(String::as_str), Some("70"));
+        assert_eq!(params.len(), 2);
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["storageParams"]["fillfactor"], "70");
+
+        let request: CreateSchemaRequest = serde_json::from_value(serde_json::json!({
+            "name": "Counters",
+            "description": null,
+            "tableName": "counters",
+            "columns": []
+        }))
+        .unwrap();
+        assert!(request.storage_params.is_none());
+    }
+
+    #[test]
+    fn test_create_schema_request_with_tablespace() {
+        let request =
+            CreateSchemaRequest::new("Events", "events", vec![]).with_tablespace("fast_ssd");
+        assert_eq!(request.tablespace.as_deref(), Some("fast_ssd"));
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["tablespace"], "fast_ssd");
+
+        let plain = serde_json::to_value(CreateSchemaRequest::new("Events", "events", vec![]));
+        assert!(plain.unwrap().get("tablespace").is_none());
+    }
 }
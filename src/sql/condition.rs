@@ -2,12 +2,106 @@
 //!
 //! Converts JSON condition structures to SQL WHERE clauses.
 
+use sqlx::Postgres;
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo};
+
+use crate::config::NullsOrdering;
+use crate::error::ObjectStoreError;
 use crate::instance::Condition;
 use crate::schema::Schema;
 use crate::sql::sanitize::quote_identifier;
 use crate::types::ColumnType;
 
+/// A positional query parameter bound with its native PostgreSQL type
+///
+/// Produced by [`build_condition_clause`] and
+/// [`QueryBuilder`](crate::sql::query::QueryBuilder). Each variant is sent as
+/// the matching PostgreSQL type (`bigint`, `float8`, `boolean`, `text`,
+/// `jsonb`, `text[]`), so the placeholder casts in the generated SQL convert real values
+/// instead of reparsing text.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqlParam {
+    /// Bound as `bigint`
+    Int(i64),
+    /// Bound as `float8`
+    Float(f64),
+    /// Bound as `boolean`
+    Bool(bool),
+    /// Bound as `text`
+    Text(String),
+    /// Bound as `jsonb`
+    Json(serde_json::Value),
+    /// Bound as `text[]`
+    TextArray(Vec<String>),
+}
+
+impl SqlParam {
+    /// Text parameter holding a JSON value's string form (strings unquoted)
+    fn text(value: &serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::String(s) => SqlParam::Text(s.clone()),
+            other => SqlParam::Text(other.to_string()),
+        }
+    }
+
+    /// Parameter for a value compared against a field cast to `cast`
+    ///
+    /// Whole numbers bind as `bigint` for integer and numeric fields and
+    /// booleans as `boolean` for boolean fields. Anything else binds as text
+    /// and is converted by the placeholder's cast, which keeps decimals exact
+    /// and still rejects values the column type cannot hold.
+    fn for_cast(value: &serde_json::Value, cast: &str) -> Self {
+        match value {
+            serde_json::Value::Number(n) if matches!(cast, "bigint" | "numeric") => {
+                n.as_i64().map_or_else(|| Self::text(value), SqlParam::Int)
+            }
+            serde_json::Value::Bool(b) if cast == "boolean" => SqlParam::Bool(*b),
+            _ => Self::text(value),
+        }
+    }
+}
+
+impl sqlx::Type<Postgres> for SqlParam {
+    fn type_info() -> PgTypeInfo {
+        <String as sqlx::Type<Postgres>>::type_info()
+    }
+}
+
+impl<'q> sqlx::Encode<'q, Postgres> for SqlParam {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        match self {
+            SqlParam::Int(v) => <i64 as sqlx::Encode<'q, Postgres>>::encode_by_ref(v, buf),
+            SqlParam::Float(v) => <f64 as sqlx::Encode<'q, Postgres>>::encode_by_ref(v, buf),
+            SqlParam::Bool(v) => <bool as sqlx::Encode<'q, Postgres>>::encode_by_ref(v, buf),
+            SqlParam::Text(v) => <String as sqlx::Encode<'q, Postgres>>::encode_by_ref(v, buf),
+            SqlParam::Json(v) => {
+                <serde_json::Value as sqlx::Encode<'q, Postgres>>::encode_by_ref(v, buf)
+            }
+            SqlParam::TextArray(v) => {
+                <Vec<String> as sqlx::Encode<'q, Postgres>>::encode_by_ref(v, buf)
+            }
+        }
+    }
+
+    fn produces(&self) -> Option<PgTypeInfo> {
+        Some(match self {
+            SqlParam::Int(_) => <i64 as sqlx::Type<Postgres>>::type_info(),
+            SqlParam::Float(_) => <f64 as sqlx::Type<Postgres>>::type_info(),
+            SqlParam::Bool(_) => <bool as sqlx::Type<Postgres>>::type_info(),
+            SqlParam::Text(_) => <String as sqlx::Type<Postgres>>::type_info(),
+            SqlParam::Json(_) => <serde_json::Value as sqlx::Type<Postgres>>::type_info(),
+            SqlParam::TextArray(_) => <Vec<String> as sqlx::Type<Postgres>>::type_info(),
+        })
+    }
+}
+
 /// Map camelCase system field names to their snake_case SQL column equivalents.
+///
+/// This is the only field-name mapping: conditions and sort orders both go
+/// through it, so a name resolves to the same column in a filter and a sort.
+/// Schema column names are used verbatim (and case-sensitively) by both.
 fn field_to_sql(field: &str) -> &str {
     match field {
         "createdAt" => "created_at",
@@ -36,35 +130,164 @@ fn resolve_sql_cast(field: &str, schema: &Schema) -> &'static str {
             ColumnType::Integer => "bigint",
             ColumnType::Decimal { .. } => "numeric",
             ColumnType::Boolean => "boolean",
-            ColumnType::Timestamp => "timestamptz",
+            ColumnType::Timestamp { with_tz: true } => "timestamptz",
+            ColumnType::Timestamp { with_tz: false } => "timestamp",
             ColumnType::Json => "text",
+            #[cfg(feature = "postgis")]
+            ColumnType::Point => "text",
         }
     } else {
         "text"
     }
 }
 
+/// Whether an empty-string check is meaningful for a field
+///
+/// True for text-like schema columns (String, Enum, Json). Fields the schema
+/// doesn't describe (including system fields) keep the combined NULL-or-empty
+/// check.
+fn has_empty_text(field: &str, schema: &Schema) -> bool {
+    match schema.columns.iter().find(|c| c.name == field) {
+        Some(col) => matches!(
+            col.column_type,
+            ColumnType::String | ColumnType::Enum { .. } | ColumnType::Json
+        ),
+        None => true,
+    }
+}
+
+/// Escape LIKE metacharacters (`%`, `_`, and the `\` escape itself) so the
+/// value matches literally.
+fn escape_like(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Parse a JSON_EQ path into a PostgreSQL `text[]` literal.
+///
+/// Accepts a dotted string (`"address.city"`) or an array of keys and array
+/// indexes (`["items", 0, "sku"]`). Keys are limited to letters, digits, `_`
+/// and `-` so they never need escaping inside the literal.
+fn parse_json_path(path: &serde_json::Value) -> Result<String, String> {
+    let segments: Vec<String> = match path {
+        serde_json::Value::String(s) => s.split('.').map(str::to_string).collect(),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .map(|item| match item {
+                serde_json::Value::String(s) => Ok(s.clone()),
+                serde_json::Value::Number(n) if n.is_u64() => Ok(n.to_string()),
+                _ => Err("JSON path segments must be strings or array indexes".to_string()),
+            })
+            .collect::<Result<_, _>>()?,
+        _ => return Err("JSON path must be a string or an array".to_string()),
+    };
+
+    if segments.is_empty() {
+        return Err("JSON path cannot be empty".to_string());
+    }
+    for segment in &segments {
+        if segment.is_empty()
+            || !segment
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+        {
+            return Err(format!("Invalid JSON path segment '{}'", segment));
+        }
+    }
+
+    // Quoted so keys like NULL are not read as array NULLs
+    let quoted: Vec<String> = segments.iter().map(|s| format!("\"{}\"", s)).collect();
+    Ok(format!("{{{}}}", quoted.join(",")))
+}
+
+/// Typed array parameter for an IN list on a field of known scalar type
+///
+/// Returns the SQL cast and a PostgreSQL array literal (`{"a","b"}`) when the
+/// field's type is known and every value is valid for it, so the list can be
+/// compared as `"field" = ANY($n::cast[])`, which uses the column's index.
+/// Returns `None` for unknown fields, JSON columns, or values the column type
+/// would reject; those fall back to the text comparison.
+fn typed_in_array(
+    field: &str,
+    values: &[serde_json::Value],
+    schema: &Schema,
+) -> Option<(&'static str, String)> {
+    let column_type = match field {
+        "id" => ColumnType::String,
+        "created_at" | "updated_at" => ColumnType::timestamp(),
+        _ => schema
+            .columns
+            .iter()
+            .find(|c| c.name == field)
+            .map(|c| c.column_type.clone())?,
+    };
+    if !matches!(
+        column_type,
+        ColumnType::String
+            | ColumnType::Enum { .. }
+            | ColumnType::Integer
+            | ColumnType::Decimal { .. }
+            | ColumnType::Boolean
+            | ColumnType::Timestamp { .. }
+    ) {
+        return None;
+    }
+
+    let mut elements = Vec::with_capacity(values.len());
+    for value in values {
+        let element = match value {
+            serde_json::Value::Null => {
+                elements.push("NULL".to_string());
+                continue;
+            }
+            serde_json::Value::String(s) => s.clone(),
+            serde_json::Value::Number(_) | serde_json::Value::Bool(_) => value.to_string(),
+            _ => return None,
+        };
+        column_type.validate_value(value).ok()?;
+        elements.push(format!(
+            "\"{}\"",
+            element.replace('\\', "\\\\").replace('"', "\\\"")
+        ));
+    }
+
+    Some((
+        resolve_sql_cast(field, schema),
+        format!("{{{}}}", elements.join(",")),
+    ))
+}
+
 /// Build SQL WHERE clause from condition structure
 ///
 /// Returns (clause, params) tuple where:
 /// - `clause` is the SQL WHERE condition string with parameter placeholders ($1, $2, etc.)
-/// - `params` is a vector of parameter values to bind
+/// - `params` is a vector of typed parameter values to bind, in placeholder order
 ///
 /// # Arguments
 /// * `condition` - The condition structure to convert
 /// * `param_offset` - Starting parameter number (mutated to track next available)
 ///
 /// # Supported Operations
-/// - Logical: AND, OR, NOT
-/// - Comparison: EQ, NE, GT, LT, GTE, LTE
-/// - String: CONTAINS (LIKE with wildcards)
-/// - Array: IN, NOT_IN
+/// - Logical: AND, OR, NOT (several arguments are negated as one AND group)
+/// - Comparison: EQ, NE, GT, LT, GTE, LTE (value may be `{"field": "other"}` to compare columns)
+/// - Range: BETWEEN (inclusive, bounds cast to the column type)
+/// - String: CONTAINS, STARTS_WITH, ENDS_WITH (escaped LIKE), NOT_CONTAINS (escaped NOT LIKE),
+///   CONTAINS_ANY (escaped ILIKE ANY)
+/// - JSON: JSON_EQ (value at a path inside a Json column)
+/// - Array: IN, NOT_IN, TUPLE_IN (multi-column IN)
 /// - Nullability: IS_EMPTY, IS_NOT_EMPTY, IS_DEFINED
+/// - Geographic (`postgis` feature): WITHIN_RADIUS
 pub fn build_condition_clause(
     condition: &Condition,
     param_offset: &mut i32,
     schema: &Schema,
-) -> Result<(String, Vec<serde_json::Value>), String> {
+) -> Result<(String, Vec<SqlParam>), String> {
     let op = condition.op.to_uppercase();
     let args = condition.arguments.as_ref();
 
@@ -111,16 +334,24 @@ pub fn build_condition_clause(
         }
         "NOT" => {
             if let Some(args) = args {
-                if args.len() != 1 {
-                    return Err("NOT operation requires exactly one argument".to_string());
+                if args.is_empty() {
+                    return Err("NOT operation requires at least one condition".to_string());
                 }
-                if let Ok(sub_condition) = serde_json::from_value::<Condition>(args[0].clone()) {
-                    let (clause, sub_params) =
+                // Several arguments are negated together: NOT (a AND b AND ...)
+                let mut clauses = Vec::new();
+                for arg in args {
+                    let sub_condition = serde_json::from_value::<Condition>(arg.clone())
+                        .map_err(|_| "NOT operation requires a Condition argument".to_string())?;
+                    let (clause, mut sub_params) =
                         build_condition_clause(&sub_condition, param_offset, schema)?;
-                    params.extend(sub_params);
-                    Ok((format!("NOT ({})", clause), params))
+                    clauses.push(clause);
+                    params.append(&mut sub_params);
+                }
+                if clauses.len() == 1 {
+                    Ok((format!("NOT ({})", clauses[0]), params))
                 } else {
-                    Err("NOT operation requires a Condition argument".to_string())
+                    let joined: Vec<String> = clauses.iter().map(|c| format!("({})", c)).collect();
+                    Ok((format!("NOT ({})", joined.join(" AND ")), params))
                 }
             } else {
                 Err("NOT operation requires an argument".to_string())
@@ -162,6 +393,29 @@ pub fn build_condition_clause(
                     _ => unreachable!(),
                 };
 
+                // Compare against another column: {"field": "other_column"}
+                if let Some(other_field) = value
+                    .as_object()
+                    .filter(|obj| obj.len() == 1)
+                    .and_then(|obj| obj.get("field"))
+                    .and_then(|v| v.as_str())
+                {
+                    if other_field.is_empty() {
+                        return Err("Referenced field name cannot be empty".to_string());
+                    }
+                    if !other_field
+                        .chars()
+                        .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+                    {
+                        return Err("Referenced field name contains invalid characters".to_string());
+                    }
+                    let other_field = field_to_sql(other_field);
+                    return Ok((
+                        format!("\"{}\" {} \"{}\"", field, operator, other_field),
+                        params,
+                    ));
+                }
+
                 // Handle NULL values specially - use IS NULL / IS NOT NULL
                 if value.is_null() {
                     let null_operator = match op.as_str() {
@@ -177,18 +431,9 @@ pub fn build_condition_clause(
                     return Ok((format!("\"{}\" {}", field, null_operator), params));
                 }
 
-                // Convert value to string for comparison
-                let value_str = match value {
-                    serde_json::Value::String(s) => s.clone(),
-                    serde_json::Value::Number(n) => n.to_string(),
-                    serde_json::Value::Bool(b) => b.to_string(),
-                    serde_json::Value::Null => "null".to_string(),
-                    _ => value.to_string(),
-                };
-
-                params.push(serde_json::Value::String(value_str));
-
                 let cast = resolve_sql_cast(field, schema);
+                params.push(SqlParam::for_cast(value, cast));
+
                 let clause = format!(
                     "\"{}\"::{} {} ${}::{}",
                     field, cast, operator, param_offset, cast
@@ -200,10 +445,117 @@ pub fn build_condition_clause(
                 Err(format!("{} operation requires arguments", op))
             }
         }
-        "CONTAINS" => {
+        "BETWEEN" => {
+            if let Some(args) = args {
+                if args.len() != 3 {
+                    return Err("BETWEEN operation requires exactly 3 arguments".to_string());
+                }
+                let raw_field = args[0]
+                    .as_str()
+                    .ok_or("First argument must be a field name")?;
+
+                if raw_field.is_empty() {
+                    return Err("Field name cannot be empty".to_string());
+                }
+
+                // Validate field name
+                if !raw_field
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+                {
+                    return Err("Field name contains invalid characters".to_string());
+                }
+
+                if args[1].is_null() || args[2].is_null() {
+                    return Err("BETWEEN bounds cannot be null".to_string());
+                }
+
+                let field = field_to_sql(raw_field);
+
+                // Bounds are cast to the column's SQL type so ranges compare by value
+                let cast = resolve_sql_cast(field, schema);
+                for bound in &args[1..] {
+                    params.push(SqlParam::for_cast(bound, cast));
+                }
+
+                let clause = format!(
+                    "\"{}\"::{} BETWEEN ${}::{} AND ${}::{}",
+                    field,
+                    cast,
+                    param_offset,
+                    cast,
+                    *param_offset + 1,
+                    cast
+                );
+                *param_offset += 2;
+
+                Ok((clause, params))
+            } else {
+                Err("BETWEEN operation requires arguments".to_string())
+            }
+        }
+        "JSON_EQ" => {
+            if let Some(args) = args {
+                if args.len() != 3 {
+                    return Err("JSON_EQ operation requires exactly 3 arguments".to_string());
+                }
+                let field = args[0]
+                    .as_str()
+                    .ok_or("First argument must be a field name")?;
+
+                if field.is_empty() {
+                    return Err("Field name cannot be empty".to_string());
+                }
+
+                let is_json = schema
+                    .columns
+                    .iter()
+                    .any(|c| c.name == field && c.column_type == ColumnType::Json);
+                if !is_json {
+                    return Err(format!("JSON_EQ field '{}' is not a Json column", field));
+                }
+
+                let path = parse_json_path(&args[1])?;
+                let value = &args[2];
+
+                // Scalars compare as text; objects and arrays compare as jsonb
+                let clause = match value {
+                    serde_json::Value::Null => {
+                        params.push(SqlParam::Text(path));
+                        format!("\"{}\" #>> ${}::text[] IS NULL", field, param_offset)
+                    }
+                    serde_json::Value::Object(_) | serde_json::Value::Array(_) => {
+                        params.push(SqlParam::Text(path));
+                        params.push(SqlParam::Json(value.clone()));
+                        format!(
+                            "\"{}\" #> ${}::text[] = ${}::jsonb",
+                            field,
+                            param_offset,
+                            *param_offset + 1
+                        )
+                    }
+                    scalar => {
+                        params.push(SqlParam::Text(path));
+                        params.push(SqlParam::text(scalar));
+                        format!(
+                            "\"{}\" #>> ${}::text[] = ${}::text",
+                            field,
+                            param_offset,
+                            *param_offset + 1
+                        )
+                    }
+                };
+                *param_offset += params.len() as i32;
+
+                Ok((clause, params))
+            } else {
+                Err("JSON_EQ operation requires arguments".to_string())
+            }
+        }
+        "CONTAINS" | "STARTS_WITH" | "ENDS_WITH" => {
             if let Some(args) = args {
                 if args.len() != 2 {
-                    return Err("CONTAINS operation requires exactly 2 arguments".to_string());
+                    return Err(format!("{} operation requires exactly 2 arguments", op));
                 }
                 let raw_field = args[0]
                     .as_str()
@@ -224,20 +576,29 @@ pub fn build_condition_clause(
 
                 let field = field_to_sql(raw_field);
 
-                params.push(serde_json::Value::String(format!("%{}%", value)));
+                let value = escape_like(value);
+                let pattern = match op.as_str() {
+                    "STARTS_WITH" => format!("{}%", value),
+                    "ENDS_WITH" => format!("%{}", value),
+                    _ => format!("%{}%", value),
+                };
+                params.push(SqlParam::Text(pattern));
 
-                let clause = format!("\"{}\"::text LIKE ${}::text", field, param_offset);
+                let clause = format!(
+                    "\"{}\"::text LIKE ${}::text ESCAPE '\\'",
+                    field, param_offset
+                );
                 *param_offset += 1;
 
                 Ok((clause, params))
             } else {
-                Err("CONTAINS operation requires arguments".to_string())
+                Err(format!("{} operation requires arguments", op))
             }
         }
-        "IN" => {
+        "CONTAINS_ANY" => {
             if let Some(args) = args {
                 if args.len() != 2 {
-                    return Err("IN operation requires exactly 2 arguments".to_string());
+                    return Err("CONTAINS_ANY operation requires exactly 2 arguments".to_string());
                 }
                 let raw_field = args[0]
                     .as_str()
@@ -258,16 +619,172 @@ pub fn build_condition_clause(
                     return Err("Field name contains invalid characters".to_string());
                 }
 
+                if values.is_empty() {
+                    return Err("CONTAINS_ANY requires at least one substring".to_string());
+                }
+
+                let field = field_to_sql(raw_field);
+
+                let mut patterns = Vec::with_capacity(values.len());
+                for value in values {
+                    let value = value.as_str().ok_or("Substrings must be strings")?;
+                    patterns.push(format!("%{}%", escape_like(value)));
+                }
+                params.push(SqlParam::TextArray(patterns));
+
+                let clause = format!("\"{}\"::text ILIKE ANY(${}::text[])", field, param_offset);
+                *param_offset += 1;
+
+                Ok((clause, params))
+            } else {
+                Err("CONTAINS_ANY operation requires arguments".to_string())
+            }
+        }
+        "TUPLE_IN" => {
+            if let Some(args) = args {
+                if args.len() != 2 {
+                    return Err("TUPLE_IN operation requires exactly 2 arguments".to_string());
+                }
+                let raw_fields = args[0]
+                    .as_array()
+                    .ok_or("First argument must be an array of field names")?;
+                let tuples = args[1]
+                    .as_array()
+                    .ok_or("Second argument must be an array of value tuples")?;
+
+                if raw_fields.is_empty() {
+                    return Err("TUPLE_IN requires at least one field".to_string());
+                }
+                if tuples.is_empty() {
+                    return Err("TUPLE_IN requires at least one tuple".to_string());
+                }
+
+                let mut fields = Vec::with_capacity(raw_fields.len());
+                for raw_field in raw_fields {
+                    let raw_field = raw_field.as_str().ok_or("Field names must be strings")?;
+                    if raw_field.is_empty() {
+                        return Err("Field name cannot be empty".to_string());
+                    }
+                    if !raw_field
+                        .chars()
+                        .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+                    {
+                        return Err("Field name contains invalid characters".to_string());
+                    }
+                    let field = field_to_sql(raw_field);
+                    fields.push((field, resolve_sql_cast(field, schema)));
+                }
+
+                let mut tuple_clauses = Vec::with_capacity(tuples.len());
+                for tuple in tuples {
+                    let values = tuple.as_array().ok_or("Each tuple must be an array")?;
+                    if values.len() != fields.len() {
+                        return Err(format!(
+                            "Each tuple must have {} values to match the fields",
+                            fields.len()
+                        ));
+                    }
+
+                    let mut placeholders = Vec::with_capacity(values.len());
+                    for (value, (_, cast)) in values.iter().zip(&fields) {
+                        if value.is_null() {
+                            return Err("TUPLE_IN values cannot be null".to_string());
+                        }
+                        params.push(SqlParam::for_cast(value, cast));
+                        placeholders.push(format!("${}::{}", param_offset, cast));
+                        *param_offset += 1;
+                    }
+                    tuple_clauses.push(format!("({})", placeholders.join(", ")));
+                }
+
+                let columns: Vec<String> = fields
+                    .iter()
+                    .map(|(field, cast)| format!("\"{}\"::{}", field, cast))
+                    .collect();
+
+                Ok((
+                    format!("({}) IN ({})", columns.join(", "), tuple_clauses.join(", ")),
+                    params,
+                ))
+            } else {
+                Err("TUPLE_IN operation requires arguments".to_string())
+            }
+        }
+        "NOT_CONTAINS" => {
+            if let Some(args) = args {
+                if args.len() != 2 {
+                    return Err("NOT_CONTAINS operation requires exactly 2 arguments".to_string());
+                }
+                let raw_field = args[0]
+                    .as_str()
+                    .ok_or("First argument must be a field name")?;
+                let value = args[1].as_str().ok_or("Second argument must be a string")?;
+
+                if raw_field.is_empty() {
+                    return Err("Field name cannot be empty".to_string());
+                }
+
+                // Validate field name
+                if !raw_field
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+                {
+                    return Err("Field name contains invalid characters".to_string());
+                }
+
                 let field = field_to_sql(raw_field);
 
-                params.push(serde_json::Value::Array(values.clone()));
+                params.push(SqlParam::Text(format!("%{}%", escape_like(value))));
 
                 let clause = format!(
-                    "\"{}\"::text = ANY(SELECT jsonb_array_elements_text(${}::jsonb))",
+                    "\"{}\"::text NOT LIKE ${}::text ESCAPE '\\'",
                     field, param_offset
                 );
                 *param_offset += 1;
 
+                Ok((clause, params))
+            } else {
+                Err("NOT_CONTAINS operation requires arguments".to_string())
+            }
+        }
+        "IN" => {
+            if let Some(args) = args {
+                if args.len() != 2 {
+                    return Err("IN operation requires exactly 2 arguments".to_string());
+                }
+                let raw_field = args[0]
+                    .as_str()
+                    .ok_or("First argument must be a field name")?;
+                let values = args[1]
+                    .as_array()
+                    .ok_or("Second argument must be an array")?;
+
+                if raw_field.is_empty() {
+                    return Err("Field name cannot be empty".to_string());
+                }
+
+                // Validate field name
+                if !raw_field
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+                {
+                    return Err("Field name contains invalid characters".to_string());
+                }
+
+                let field = field_to_sql(raw_field);
+
+                let clause = if let Some((cast, array)) = typed_in_array(field, values, schema) {
+                    params.push(SqlParam::Text(array));
+                    format!("\"{}\" = ANY(${}::{}[])", field, param_offset, cast)
+                } else {
+                    params.push(SqlParam::Json(serde_json::Value::Array(values.clone())));
+                    format!(
+                        "\"{}\"::text = ANY(SELECT jsonb_array_elements_text(${}::jsonb))",
+                        field, param_offset
+                    )
+                };
+                *param_offset += 1;
+
                 Ok((clause, params))
             } else {
                 Err("IN operation requires arguments".to_string())
@@ -299,12 +816,16 @@ pub fn build_condition_clause(
 
                 let field = field_to_sql(raw_field);
 
-                params.push(serde_json::Value::Array(values.clone()));
-
-                let clause = format!(
-                    "NOT (\"{}\"::text = ANY(SELECT jsonb_array_elements_text(${}::jsonb)))",
-                    field, param_offset
-                );
+                let clause = if let Some((cast, array)) = typed_in_array(field, values, schema) {
+                    params.push(SqlParam::Text(array));
+                    format!("NOT (\"{}\" = ANY(${}::{}[]))", field, param_offset, cast)
+                } else {
+                    params.push(SqlParam::Json(serde_json::Value::Array(values.clone())));
+                    format!(
+                        "NOT (\"{}\"::text = ANY(SELECT jsonb_array_elements_text(${}::jsonb)))",
+                        field, param_offset
+                    )
+                };
                 *param_offset += 1;
 
                 Ok((clause, params))
@@ -333,7 +854,11 @@ pub fn build_condition_clause(
 
                 let field = field_to_sql(raw_field);
 
-                let clause = format!("(\"{}\" IS NULL OR \"{}\"::text = '')", field, field);
+                let clause = if has_empty_text(field, schema) {
+                    format!("(\"{}\" IS NULL OR \"{}\"::text = '')", field, field)
+                } else {
+                    format!("\"{}\" IS NULL", field)
+                };
 
                 Ok((clause, params))
             } else {
@@ -361,7 +886,11 @@ pub fn build_condition_clause(
 
                 let field = field_to_sql(raw_field);
 
-                let clause = format!("(\"{}\" IS NOT NULL AND \"{}\"::text != '')", field, field);
+                let clause = if has_empty_text(field, schema) {
+                    format!("(\"{}\" IS NOT NULL AND \"{}\"::text != '')", field, field)
+                } else {
+                    format!("\"{}\" IS NOT NULL", field)
+                };
 
                 Ok((clause, params))
             } else {
@@ -396,15 +925,132 @@ pub fn build_condition_clause(
                 Err("IS_DEFINED operation requires an argument".to_string())
             }
         }
+        #[cfg(feature = "postgis")]
+        "WITHIN_RADIUS" => {
+            if let Some(args) = args {
+                if args.len() != 3 {
+                    return Err("WITHIN_RADIUS operation requires exactly 3 arguments".to_string());
+                }
+                let raw_field = args[0]
+                    .as_str()
+                    .ok_or("First argument must be a field name")?;
+                let (lng, lat) = crate::types::parse_point(&args[1])?;
+                let meters = args[2]
+                    .as_f64()
+                    .ok_or("Third argument must be a distance in meters")?;
+
+                if raw_field.is_empty() {
+                    return Err("Field name cannot be empty".to_string());
+                }
+
+                // Validate field name
+                if !raw_field
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+                {
+                    return Err("Field name contains invalid characters".to_string());
+                }
+
+                if meters < 0.0 {
+                    return Err("Distance must not be negative".to_string());
+                }
+
+                let field = field_to_sql(raw_field);
+
+                params.push(SqlParam::Float(lng));
+                params.push(SqlParam::Float(lat));
+                params.push(SqlParam::Float(meters));
+
+                let clause = format!(
+                    "ST_DWithin(\"{}\", ST_SetSRID(ST_MakePoint(${}::float8, ${}::float8), 4326)::geography, ${}::float8)",
+                    field,
+                    param_offset,
+                    *param_offset + 1,
+                    *param_offset + 2
+                );
+                *param_offset += 3;
+
+                Ok((clause, params))
+            } else {
+                Err("WITHIN_RADIUS operation requires arguments".to_string())
+            }
+        }
         _ => Err(format!("Unsupported operation: {}", op)),
     }
 }
 
-/// Build ORDER BY clause from sort parameters
+/// Validate a condition against a schema without building a query
 ///
-/// # Arguments
+/// Checks everything [`build_condition_clause`] checks (supported operators,
+/// argument counts and shapes), and additionally that every referenced field
+/// is a schema column or a system field (`id`, `createdAt`, `updatedAt`).
+/// The first problem is returned as [`ObjectStoreError::InvalidCondition`].
+pub fn validate_condition(condition: &Condition, schema: &Schema) -> crate::error::Result<()> {
+    let mut param_offset = 1;
+    build_condition_clause(condition, &mut param_offset, schema)
+        .map_err(ObjectStoreError::InvalidCondition)?;
+    check_condition_fields(condition, schema).map_err(ObjectStoreError::InvalidCondition)
+}
+
+/// Check that every field a (well-formed) condition references exists
+fn check_condition_fields(condition: &Condition, schema: &Schema) -> Result<(), String> {
+    let Some(args) = condition.arguments.as_ref() else {
+        return Ok(());
+    };
+
+    let check_field = |raw_field: &str| {
+        let field = field_to_sql(raw_field);
+        if matches!(field, "id" | "created_at" | "updated_at")
+            || schema.columns.iter().any(|c| c.name == field)
+        {
+            Ok(())
+        } else {
+            Err(format!(
+                "Field '{}' does not exist in schema '{}'",
+                raw_field, schema.name
+            ))
+        }
+    };
+
+    match condition.op.to_uppercase().as_str() {
+        "AND" | "OR" | "NOT" => {
+            for arg in args {
+                if let Ok(sub_condition) = serde_json::from_value::<Condition>(arg.clone()) {
+                    check_condition_fields(&sub_condition, schema)?;
+                }
+            }
+        }
+        "TUPLE_IN" => {
+            for field in args[0].as_array().into_iter().flatten() {
+                check_field(field.as_str().unwrap_or_default())?;
+            }
+        }
+        op => {
+            if let Some(field) = args.first().and_then(|v| v.as_str()) {
+                check_field(field)?;
+            }
+            // Column-to-column comparisons reference a second field
+            if matches!(op, "EQ" | "NE" | "GT" | "LT" | "GTE" | "LTE")
+                && let Some(other_field) = args[1]
+                    .as_object()
+                    .filter(|obj| obj.len() == 1)
+                    .and_then(|obj| obj.get("field"))
+                    .and_then(|v| v.as_str())
+            {
+                check_field(other_field)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Build ORDER BY clause from sort parameters
+///
+/// # Arguments
 /// * `sort_by` - Optional list of field names to sort by
-/// * `sort_order` - Optional list of sort orders ("asc" or "desc")
+/// * `sort_order` - Optional list of sort orders ("asc" or "desc", optionally
+///   followed by "nulls first" or "nulls last")
 /// * `schema` - The schema to validate field names against
 ///
 /// # Returns
@@ -414,9 +1060,28 @@ pub fn build_order_by_clause(
     sort_order: &Option<Vec<String>>,
     schema: &Schema,
 ) -> Result<String, String> {
+    build_order_by_clause_with_nulls(sort_by, sort_order, schema, None)
+}
+
+/// Build ORDER BY clause with a default NULL placement
+///
+/// Same as [`build_order_by_clause`], with `default_nulls` applied to every
+/// sort key (including the default `created_at` sort) whose order doesn't
+/// carry its own `nulls first` / `nulls last`. `None` keeps PostgreSQL's
+/// default.
+pub fn build_order_by_clause_with_nulls(
+    sort_by: &Option<Vec<String>>,
+    sort_order: &Option<Vec<String>>,
+    schema: &Schema,
+    default_nulls: Option<NullsOrdering>,
+) -> Result<String, String> {
+    let default_nulls_suffix = default_nulls
+        .map(|nulls| format!(" {}", nulls.as_sql()))
+        .unwrap_or_default();
+
     let sort_fields = match sort_by {
         Some(fields) if !fields.is_empty() => fields,
-        _ => return Ok("created_at ASC".to_string()), // Default
+        _ => return Ok(format!("created_at ASC{}", default_nulls_suffix)), // Default
     };
 
     let orders = sort_order.as_ref();
@@ -439,25 +1104,70 @@ pub fn build_order_by_clause(
             ));
         }
 
-        // Get order (default: ASC)
-        let order = orders
+        // Get order (default: ASC), optionally followed by a NULLS placement
+        let order_spec = orders
             .and_then(|o| o.get(i))
             .map(|s| s.to_uppercase())
             .unwrap_or_else(|| "ASC".to_string());
+        let mut tokens = order_spec.split_whitespace();
+        let order = tokens.next().unwrap_or_default();
 
         if order != "ASC" && order != "DESC" {
             return Err(format!(
                 "Invalid sort order: '{}'. Must be 'asc' or 'desc'.",
-                order
+                order_spec
             ));
         }
 
-        order_parts.push(format!("{} {}", quote_identifier(sql_field), order));
+        let nulls_suffix = match (tokens.next(), tokens.next(), tokens.next()) {
+            (None, _, _) => default_nulls_suffix.clone(),
+            (Some("NULLS"), Some("FIRST"), None) => " NULLS FIRST".to_string(),
+            (Some("NULLS"), Some("LAST"), None) => " NULLS LAST".to_string(),
+            _ => {
+                return Err(format!(
+                    "Invalid sort order: '{}'. NULL placement must be 'nulls first' or 'nulls last'.",
+                    order_spec
+                ));
+            }
+        };
+
+        order_parts.push(format!(
+            "{} {}{}",
+            quote_identifier(sql_field),
+            order,
+            nulls_suffix
+        ));
     }
 
     Ok(order_parts.join(", "))
 }
 
+/// Build ORDER BY clause ending in a unique tiebreaker column
+///
+/// Rows that tie on every sort key come back in no particular order, so OFFSET
+/// pagination could repeat or skip them between pages. `tiebreaker` (sorted
+/// ascending) is appended unless it is already the last sort key. The sort
+/// keys get `default_nulls` as in [`build_order_by_clause_with_nulls`].
+pub fn build_stable_order_by_clause(
+    sort_by: &Option<Vec<String>>,
+    sort_order: &Option<Vec<String>>,
+    schema: &Schema,
+    tiebreaker: &str,
+    default_nulls: Option<NullsOrdering>,
+) -> Result<String, String> {
+    let clause = build_order_by_clause_with_nulls(sort_by, sort_order, schema, default_nulls)?;
+
+    let last_key = sort_by
+        .as_ref()
+        .and_then(|fields| fields.last())
+        .map(|field| field_to_sql(field));
+    if last_key == Some(tiebreaker) {
+        return Ok(clause);
+    }
+
+    Ok(format!("{}, {} ASC", clause, quote_identifier(tiebreaker)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -476,6 +1186,9 @@ mod tests {
                 ColumnDefinition::new("active", crate::types::ColumnType::Boolean),
             ],
             indexes: None,
+            default_index: true,
+            storage_params: None,
+            tablespace: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
         }
@@ -496,7 +1209,7 @@ mod tests {
 
         assert_eq!(clause, "\"name\"::text = $1::text");
         assert_eq!(params.len(), 1);
-        assert_eq!(params[0], serde_json::json!("test"));
+        assert_eq!(params[0], SqlParam::Text("test".to_string()));
         assert_eq!(offset, 2);
     }
 
@@ -512,7 +1225,7 @@ mod tests {
         let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
 
         assert_eq!(clause, "\"age\"::text = $1::text"); // "age" not in schema, falls back to text
-        assert_eq!(params[0], serde_json::json!("25")); // Numbers are converted to strings
+        assert_eq!(params[0], SqlParam::Text("25".to_string())); // Unknown fields compare as text
     }
 
     #[test]
@@ -527,7 +1240,7 @@ mod tests {
         let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
 
         assert_eq!(clause, "\"active\"::boolean = $1::boolean");
-        assert_eq!(params[0], serde_json::json!("true"));
+        assert_eq!(params[0], SqlParam::Bool(true));
     }
 
     #[test]
@@ -559,7 +1272,7 @@ mod tests {
         let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
 
         assert_eq!(clause, "\"status\"::text != $1::text");
-        assert_eq!(params[0], serde_json::json!("deleted"));
+        assert_eq!(params[0], SqlParam::Text("deleted".to_string()));
     }
 
     #[test]
@@ -576,6 +1289,31 @@ mod tests {
         assert_eq!(clause, "\"price\"::numeric > $1::numeric");
     }
 
+    #[test]
+    fn test_params_follow_column_types() {
+        let schema = make_test_schema();
+        let cases = [
+            (Condition::gt("quantity", 10), SqlParam::Int(10)),
+            (Condition::gt("price", 100), SqlParam::Int(100)),
+            (
+                Condition::gt("price", 10.25),
+                SqlParam::Text("10.25".to_string()),
+            ),
+            (Condition::eq("active", false), SqlParam::Bool(false)),
+            (Condition::eq("name", 7), SqlParam::Text("7".to_string())),
+            (
+                Condition::gt("createdAt", "2024-01-01T00:00:00Z"),
+                SqlParam::Text("2024-01-01T00:00:00Z".to_string()),
+            ),
+        ];
+
+        for (condition, expected) in cases {
+            let mut offset = 1;
+            let (_, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+            assert_eq!(params, vec![expected]);
+        }
+    }
+
     #[test]
     fn test_lt_condition() {
         let schema = make_test_schema();
@@ -590,6 +1328,115 @@ mod tests {
         assert_eq!(clause, "\"quantity\"::bigint < $1::bigint");
     }
 
+    #[test]
+    fn test_between_condition() {
+        let schema = make_test_schema();
+        let condition = Condition::between("quantity", 5, 10);
+
+        let mut offset = 1;
+        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+
+        assert_eq!(
+            clause,
+            "\"quantity\"::bigint BETWEEN $1::bigint AND $2::bigint"
+        );
+        assert_eq!(params, vec![SqlParam::Int(5), SqlParam::Int(10)]);
+        assert_eq!(offset, 3);
+    }
+
+    #[test]
+    fn test_between_casts_timestamps() {
+        let schema = make_test_schema();
+        let condition = Condition::between("createdAt", "2024-01-01", "2024-02-01");
+
+        let mut offset = 1;
+        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+
+        assert_eq!(
+            clause,
+            "\"created_at\"::timestamptz BETWEEN $1::timestamptz AND $2::timestamptz"
+        );
+        assert_eq!(
+            params,
+            vec![
+                SqlParam::Text("2024-01-01".to_string()),
+                SqlParam::Text("2024-02-01".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_between_requires_three_arguments() {
+        let schema = make_test_schema();
+        let condition = Condition {
+            op: "BETWEEN".to_string(),
+            arguments: Some(vec![serde_json::json!("quantity"), serde_json::json!(1)]),
+        };
+
+        let mut offset = 1;
+        assert!(build_condition_clause(&condition, &mut offset, &schema).is_err());
+
+        let condition = Condition::new(
+            "BETWEEN",
+            vec![
+                serde_json::json!("quantity"),
+                serde_json::json!(1),
+                serde_json::json!(5),
+                serde_json::json!(9),
+            ],
+        );
+        let err = build_condition_clause(&condition, &mut offset, &schema).unwrap_err();
+        assert!(err.contains("exactly 3 arguments"));
+        assert_eq!(offset, 1);
+    }
+
+    #[test]
+    fn test_between_validates_field_name() {
+        let schema = make_test_schema();
+
+        let condition = Condition::between("price; DROP TABLE x", 1, 5);
+        let mut offset = 1;
+        let err = build_condition_clause(&condition, &mut offset, &schema).unwrap_err();
+        assert!(err.contains("invalid characters"));
+
+        let condition = Condition::between("", 1, 5);
+        let err = build_condition_clause(&condition, &mut offset, &schema).unwrap_err();
+        assert!(err.contains("cannot be empty"));
+    }
+
+    #[test]
+    fn test_column_to_column_comparison() {
+        let schema = make_test_schema();
+        let condition = Condition::lt("start_date", Condition::field_ref("end_date"));
+
+        let mut offset = 3;
+        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+
+        assert_eq!(clause, "\"start_date\" < \"end_date\"");
+        assert!(params.is_empty());
+        assert_eq!(offset, 3);
+    }
+
+    #[test]
+    fn test_column_to_column_maps_system_fields() {
+        let schema = make_test_schema();
+        let condition = Condition::eq("updatedAt", Condition::field_ref("createdAt"));
+
+        let mut offset = 1;
+        let (clause, _) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+
+        assert_eq!(clause, "\"updated_at\" = \"created_at\"");
+    }
+
+    #[test]
+    fn test_column_to_column_rejects_invalid_reference() {
+        let schema = make_test_schema();
+        let condition = Condition::eq("name", serde_json::json!({"field": "x\"; DROP"}));
+
+        let mut offset = 1;
+        assert!(build_condition_clause(&condition, &mut offset, &schema).is_err());
+    }
+
     #[test]
     fn test_gte_condition() {
         let schema = make_test_schema();
@@ -642,141 +1489,547 @@ mod tests {
     }
 
     #[test]
-    fn test_and_with_three_conditions() {
+    fn test_and_with_three_conditions() {
+        let schema = make_test_schema();
+        let condition = Condition {
+            op: "AND".to_string(),
+            arguments: Some(vec![
+                serde_json::json!({"op": "EQ", "arguments": ["a", "1"]}),
+                serde_json::json!({"op": "EQ", "arguments": ["b", "2"]}),
+                serde_json::json!({"op": "EQ", "arguments": ["c", "3"]}),
+            ]),
+        };
+
+        let mut offset = 1;
+        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+
+        // Count AND occurrences
+        let and_count = clause.matches(" AND ").count();
+        assert_eq!(and_count, 2);
+        assert_eq!(params.len(), 3);
+    }
+
+    #[test]
+    fn test_or_condition() {
+        let schema = make_test_schema();
+        let condition = Condition {
+            op: "OR".to_string(),
+            arguments: Some(vec![
+                serde_json::json!({"op": "EQ", "arguments": ["status", "active"]}),
+                serde_json::json!({"op": "EQ", "arguments": ["status", "pending"]}),
+            ]),
+        };
+
+        let mut offset = 1;
+        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+
+        assert!(clause.contains(" OR "));
+        assert_eq!(params.len(), 2);
+    }
+
+    #[test]
+    fn test_not_condition() {
+        let schema = make_test_schema();
+        let condition = Condition {
+            op: "NOT".to_string(),
+            arguments: Some(vec![
+                serde_json::json!({"op": "EQ", "arguments": ["deleted", true]}),
+            ]),
+        };
+
+        let mut offset = 1;
+        let (clause, _) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+
+        assert!(clause.starts_with("NOT ("));
+        assert!(clause.ends_with(")"));
+    }
+
+    #[test]
+    fn test_nested_and_or_conditions() {
+        let schema = make_test_schema();
+        let condition = Condition {
+            op: "AND".to_string(),
+            arguments: Some(vec![
+                serde_json::json!({"op": "EQ", "arguments": ["type", "product"]}),
+                serde_json::json!({
+                    "op": "OR",
+                    "arguments": [
+                        {"op": "EQ", "arguments": ["status", "active"]},
+                        {"op": "EQ", "arguments": ["status", "pending"]}
+                    ]
+                }),
+            ]),
+        };
+
+        let mut offset = 1;
+        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+
+        assert!(clause.contains(" AND "));
+        assert!(clause.contains(" OR "));
+        assert_eq!(params.len(), 3);
+    }
+
+    // ==================== String Operations ====================
+
+    #[test]
+    fn test_contains_condition() {
+        let schema = make_test_schema();
+        let condition = Condition {
+            op: "CONTAINS".to_string(),
+            arguments: Some(vec![serde_json::json!("name"), serde_json::json!("test")]),
+        };
+
+        let mut offset = 1;
+        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+
+        assert_eq!(clause, "\"name\"::text LIKE $1::text ESCAPE '\\'");
+        assert_eq!(params[0], SqlParam::Text("%test%".to_string()));
+    }
+
+    #[test]
+    fn test_contains_escapes_wildcards() {
+        let schema = make_test_schema();
+
+        let mut offset = 1;
+        let (_, params) =
+            build_condition_clause(&Condition::contains("name", "100%"), &mut offset, &schema)
+                .unwrap();
+        assert_eq!(params[0], SqlParam::Text("%100\\%%".to_string()));
+
+        let (_, params) =
+            build_condition_clause(&Condition::contains("name", "a_b"), &mut offset, &schema)
+                .unwrap();
+        assert_eq!(params[0], SqlParam::Text("%a\\_b%".to_string()));
+
+        let (_, params) =
+            build_condition_clause(&Condition::contains("name", "C:\\"), &mut offset, &schema)
+                .unwrap();
+        assert_eq!(params[0], SqlParam::Text("%C:\\\\%".to_string()));
+    }
+
+    #[test]
+    fn test_starts_with_and_ends_with() {
+        let schema = make_test_schema();
+
+        let mut offset = 1;
+        let (clause, params) = build_condition_clause(
+            &Condition::starts_with("name", "50%_"),
+            &mut offset,
+            &schema,
+        )
+        .unwrap();
+        assert_eq!(clause, "\"name\"::text LIKE $1::text ESCAPE '\\'");
+        assert_eq!(params, vec![SqlParam::Text("50\\%\\_%".to_string())]);
+
+        let (clause, params) =
+            build_condition_clause(&Condition::ends_with("name", ".pdf"), &mut offset, &schema)
+                .unwrap();
+        assert_eq!(clause, "\"name\"::text LIKE $2::text ESCAPE '\\'");
+        assert_eq!(params, vec![SqlParam::Text("%.pdf".to_string())]);
+        assert_eq!(offset, 3);
+    }
+
+    #[test]
+    fn test_starts_with_validation() {
+        let schema = make_test_schema();
+        let mut offset = 1;
+
+        let condition = Condition {
+            op: "STARTS_WITH".to_string(),
+            arguments: Some(vec![serde_json::json!("name")]),
+        };
+        let err = build_condition_clause(&condition, &mut offset, &schema).unwrap_err();
+        assert_eq!(err, "STARTS_WITH operation requires exactly 2 arguments");
+
+        let condition = Condition {
+            op: "ENDS_WITH".to_string(),
+            arguments: None,
+        };
+        let err = build_condition_clause(&condition, &mut offset, &schema).unwrap_err();
+        assert_eq!(err, "ENDS_WITH operation requires arguments");
+
+        let err = build_condition_clause(&Condition::ends_with("na;me", "x"), &mut offset, &schema)
+            .unwrap_err();
+        assert_eq!(err, "Field name contains invalid characters");
+
+        let err = build_condition_clause(&Condition::starts_with("", "x"), &mut offset, &schema)
+            .unwrap_err();
+        assert_eq!(err, "Field name cannot be empty");
+    }
+
+    #[test]
+    fn test_contains_any_condition() {
+        let schema = make_test_schema();
+        let condition = Condition::contains_any("name", ["red", "50%", "a_b"]);
+
+        let mut offset = 3;
+        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+
+        assert_eq!(clause, "\"name\"::text ILIKE ANY($3::text[])");
+        assert_eq!(
+            params,
+            vec![SqlParam::TextArray(vec![
+                "%red%".to_string(),
+                "%50\\%%".to_string(),
+                "%a\\_b%".to_string(),
+            ])]
+        );
+        assert_eq!(offset, 4);
+    }
+
+    #[test]
+    fn test_contains_any_validation() {
+        let schema = make_test_schema();
+        let mut offset = 1;
+
+        let condition = Condition::contains_any("name", Vec::<String>::new());
+        let err = build_condition_clause(&condition, &mut offset, &schema).unwrap_err();
+        assert!(err.contains("at least one substring"));
+
+        let condition = Condition::new(
+            "CONTAINS_ANY",
+            vec![serde_json::json!("name"), serde_json::json!([1, 2])],
+        );
+        let err = build_condition_clause(&condition, &mut offset, &schema).unwrap_err();
+        assert!(err.contains("must be strings"));
+
+        let condition = Condition::new(
+            "CONTAINS_ANY",
+            vec![serde_json::json!("name"), serde_json::json!("red")],
+        );
+        let err = build_condition_clause(&condition, &mut offset, &schema).unwrap_err();
+        assert!(err.contains("must be an array"));
+
+        let condition = Condition::contains_any("na me", ["red"]);
+        let err = build_condition_clause(&condition, &mut offset, &schema).unwrap_err();
+        assert_eq!(err, "Field name contains invalid characters");
+        assert_eq!(offset, 1);
+    }
+
+    #[test]
+    fn test_not_contains_condition() {
+        let schema = make_test_schema();
+        let condition = Condition::not_contains("name", "test");
+
+        let mut offset = 1;
+        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+
+        assert_eq!(clause, "\"name\"::text NOT LIKE $1::text ESCAPE '\\'");
+        assert_eq!(params[0], SqlParam::Text("%test%".to_string()));
+        assert_eq!(offset, 2);
+    }
+
+    #[test]
+    fn test_not_contains_escapes_wildcards() {
+        let schema = make_test_schema();
+        let condition = Condition::not_contains("name", "50%_off\\");
+
+        let mut offset = 1;
+        let (_, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+
+        assert_eq!(params[0], SqlParam::Text("%50\\%\\_off\\\\%".to_string()));
+    }
+
+    // ==================== JSON Operations ====================
+
+    fn make_json_schema() -> Schema {
+        Schema::new(
+            "json-id",
+            "events",
+            "events",
+            vec![
+                ColumnDefinition::new("metadata", crate::types::ColumnType::Json),
+                ColumnDefinition::new("name", crate::types::ColumnType::String),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_json_eq_scalar_value() {
+        let schema = make_json_schema();
+        let condition = Condition::new(
+            "JSON_EQ",
+            vec![
+                serde_json::json!("metadata"),
+                serde_json::json!("address.city"),
+                serde_json::json!("Berlin"),
+            ],
+        );
+
+        let mut offset = 1;
+        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+
+        assert_eq!(clause, "\"metadata\" #>> $1::text[] = $2::text");
+        assert_eq!(
+            params,
+            vec![
+                SqlParam::Text("{\"address\",\"city\"}".to_string()),
+                SqlParam::Text("Berlin".to_string())
+            ]
+        );
+        assert_eq!(offset, 3);
+    }
+
+    #[test]
+    fn test_json_eq_helper_paths() {
+        let schema = make_json_schema();
+
+        let mut offset = 1;
+        let (clause, params) = build_condition_clause(
+            &Condition::json_eq("metadata", "region", "us"),
+            &mut offset,
+            &schema,
+        )
+        .unwrap();
+        assert_eq!(clause, "\"metadata\" #>> $1::text[] = $2::text");
+        assert_eq!(
+            params,
+            vec![
+                SqlParam::Text("{\"region\"}".to_string()),
+                SqlParam::Text("us".to_string())
+            ]
+        );
+
+        let (clause, params) = build_condition_clause(
+            &Condition::json_eq("metadata", "address.zip", 10115),
+            &mut offset,
+            &schema,
+        )
+        .unwrap();
+        assert_eq!(clause, "\"metadata\" #>> $3::text[] = $4::text");
+        assert_eq!(
+            params,
+            vec![
+                SqlParam::Text("{\"address\",\"zip\"}".to_string()),
+                SqlParam::Text("10115".to_string())
+            ]
+        );
+
+        let result = build_condition_clause(
+            &Condition::json_eq("metadata", "a}'.b", "x"),
+            &mut offset,
+            &schema,
+        );
+        assert!(result.unwrap_err().contains("Invalid JSON path segment"));
+    }
+
+    #[test]
+    fn test_json_eq_array_path_and_object_value() {
+        let schema = make_json_schema();
+        let condition = Condition::new(
+            "JSON_EQ",
+            vec![
+                serde_json::json!("metadata"),
+                serde_json::json!(["items", 0]),
+                serde_json::json!({"sku": "A-1"}),
+            ],
+        );
+
+        let mut offset = 1;
+        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+
+        assert_eq!(clause, "\"metadata\" #> $1::text[] = $2::jsonb");
+        assert_eq!(params[0], SqlParam::Text("{\"items\",\"0\"}".to_string()));
+        assert_eq!(params[1], SqlParam::Json(serde_json::json!({"sku": "A-1"})));
+    }
+
+    #[test]
+    fn test_json_eq_rejects_non_json_column() {
+        let schema = make_json_schema();
+        let condition = Condition::new(
+            "JSON_EQ",
+            vec![
+                serde_json::json!("name"),
+                serde_json::json!("a"),
+                serde_json::json!(1),
+            ],
+        );
+
+        let mut offset = 1;
+        let result = build_condition_clause(&condition, &mut offset, &schema);
+
+        assert!(result.unwrap_err().contains("is not a Json column"));
+    }
+
+    #[test]
+    fn test_json_eq_rejects_malformed_path() {
+        let schema = make_json_schema();
+        for path in [
+            serde_json::json!(""),
+            serde_json::json!("a..b"),
+            serde_json::json!("a.b'; DROP TABLE x"),
+            serde_json::json!([]),
+            serde_json::json!([true]),
+            serde_json::json!(42),
+        ] {
+            let condition = Condition::new(
+                "JSON_EQ",
+                vec![serde_json::json!("metadata"), path, serde_json::json!(1)],
+            );
+            let mut offset = 1;
+            assert!(build_condition_clause(&condition, &mut offset, &schema).is_err());
+        }
+    }
+
+    // ==================== Array Operations ====================
+
+    #[test]
+    fn test_in_condition() {
         let schema = make_test_schema();
         let condition = Condition {
-            op: "AND".to_string(),
+            op: "IN".to_string(),
             arguments: Some(vec![
-                serde_json::json!({"op": "EQ", "arguments": ["a", "1"]}),
-                serde_json::json!({"op": "EQ", "arguments": ["b", "2"]}),
-                serde_json::json!({"op": "EQ", "arguments": ["c", "3"]}),
+                serde_json::json!("status"),
+                serde_json::json!(["active", "pending", "draft"]),
             ]),
         };
 
         let mut offset = 1;
         let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
 
-        // Count AND occurrences
-        let and_count = clause.matches(" AND ").count();
-        assert_eq!(and_count, 2);
-        assert_eq!(params.len(), 3);
+        assert!(clause.contains("ANY"));
+        assert!(clause.contains("jsonb_array_elements_text"));
+        assert_eq!(
+            params[0],
+            SqlParam::Json(serde_json::json!(["active", "pending", "draft"]))
+        );
     }
 
     #[test]
-    fn test_or_condition() {
+    fn test_not_in_condition() {
         let schema = make_test_schema();
         let condition = Condition {
-            op: "OR".to_string(),
+            op: "NOT_IN".to_string(),
             arguments: Some(vec![
-                serde_json::json!({"op": "EQ", "arguments": ["status", "active"]}),
-                serde_json::json!({"op": "EQ", "arguments": ["status", "pending"]}),
+                serde_json::json!("status"),
+                serde_json::json!(["deleted", "archived"]),
             ]),
         };
 
         let mut offset = 1;
         let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
 
-        assert!(clause.contains(" OR "));
-        assert_eq!(params.len(), 2);
+        assert!(clause.starts_with("NOT"));
+        assert!(clause.contains("ANY"));
+        assert_eq!(
+            params[0],
+            SqlParam::Json(serde_json::json!(["deleted", "archived"]))
+        );
     }
 
     #[test]
-    fn test_not_condition() {
+    fn test_tuple_in_condition() {
         let schema = make_test_schema();
-        let condition = Condition {
-            op: "NOT".to_string(),
-            arguments: Some(vec![
-                serde_json::json!({"op": "EQ", "arguments": ["deleted", true]}),
-            ]),
-        };
+        let condition = Condition::new(
+            "TUPLE_IN",
+            vec![
+                serde_json::json!(["quantity", "name"]),
+                serde_json::json!([[1, "a"], [2, "b"]]),
+            ],
+        );
 
-        let mut offset = 1;
-        let (clause, _) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+        let mut offset = 3;
+        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
 
-        assert!(clause.starts_with("NOT ("));
-        assert!(clause.ends_with(")"));
+        assert_eq!(
+            clause,
+            "(\"quantity\"::bigint, \"name\"::text) IN \
+             (($3::bigint, $4::text), ($5::bigint, $6::text))"
+        );
+        assert_eq!(
+            params,
+            vec![
+                SqlParam::Int(1),
+                SqlParam::Text("a".to_string()),
+                SqlParam::Int(2),
+                SqlParam::Text("b".to_string())
+            ]
+        );
+        assert_eq!(offset, 7);
     }
 
     #[test]
-    fn test_nested_and_or_conditions() {
+    fn test_tuple_in_rejects_arity_mismatch() {
         let schema = make_test_schema();
-        let condition = Condition {
-            op: "AND".to_string(),
-            arguments: Some(vec![
-                serde_json::json!({"op": "EQ", "arguments": ["type", "product"]}),
-                serde_json::json!({
-                    "op": "OR",
-                    "arguments": [
-                        {"op": "EQ", "arguments": ["status", "active"]},
-                        {"op": "EQ", "arguments": ["status", "pending"]}
-                    ]
-                }),
-            ]),
-        };
+        let condition = Condition::new(
+            "TUPLE_IN",
+            vec![
+                serde_json::json!(["quantity", "name"]),
+                serde_json::json!([[1, "a"], [2]]),
+            ],
+        );
 
         let mut offset = 1;
-        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+        let result = build_condition_clause(&condition, &mut offset, &schema);
 
-        assert!(clause.contains(" AND "));
-        assert!(clause.contains(" OR "));
-        assert_eq!(params.len(), 3);
+        assert!(result.unwrap_err().contains("must have 2 values"));
     }
 
-    // ==================== String Operations ====================
-
     #[test]
-    fn test_contains_condition() {
+    fn test_tuple_in_rejects_invalid_field() {
         let schema = make_test_schema();
-        let condition = Condition {
-            op: "CONTAINS".to_string(),
-            arguments: Some(vec![serde_json::json!("name"), serde_json::json!("test")]),
-        };
+        let condition = Condition::new(
+            "TUPLE_IN",
+            vec![
+                serde_json::json!(["quantity", "name\"; DROP"]),
+                serde_json::json!([[1, "a"]]),
+            ],
+        );
 
         let mut offset = 1;
-        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+        let result = build_condition_clause(&condition, &mut offset, &schema);
 
-        assert_eq!(clause, "\"name\"::text LIKE $1::text");
-        assert_eq!(params[0], serde_json::json!("%test%"));
+        assert!(result.unwrap_err().contains("invalid characters"));
     }
 
-    // ==================== Array Operations ====================
+    // ==================== Geographic Operations ====================
 
+    #[cfg(feature = "postgis")]
     #[test]
-    fn test_in_condition() {
+    fn test_within_radius_condition() {
         let schema = make_test_schema();
         let condition = Condition {
-            op: "IN".to_string(),
+            op: "WITHIN_RADIUS".to_string(),
             arguments: Some(vec![
-                serde_json::json!("status"),
-                serde_json::json!(["active", "pending", "draft"]),
+                serde_json::json!("location"),
+                serde_json::json!({"lat": 52.52, "lng": 13.405}),
+                serde_json::json!(1500),
             ]),
         };
 
-        let mut offset = 1;
+        let mut offset = 2;
         let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
 
-        assert!(clause.contains("ANY"));
-        assert!(clause.contains("jsonb_array_elements_text"));
-        assert_eq!(params[0], serde_json::json!(["active", "pending", "draft"]));
+        assert_eq!(
+            clause,
+            "ST_DWithin(\"location\", ST_SetSRID(ST_MakePoint($2::float8, $3::float8), 4326)::geography, $4::float8)"
+        );
+        assert_eq!(
+            params,
+            vec![
+                SqlParam::Float(13.405),
+                SqlParam::Float(52.52),
+                SqlParam::Float(1500.0)
+            ]
+        );
+        assert_eq!(offset, 5);
     }
 
+    #[cfg(feature = "postgis")]
     #[test]
-    fn test_not_in_condition() {
+    fn test_within_radius_invalid_point() {
         let schema = make_test_schema();
         let condition = Condition {
-            op: "NOT_IN".to_string(),
+            op: "WITHIN_RADIUS".to_string(),
             arguments: Some(vec![
-                serde_json::json!("status"),
-                serde_json::json!(["deleted", "archived"]),
+                serde_json::json!("location"),
+                serde_json::json!({"lat": 120.0, "lng": 0.0}),
+                serde_json::json!(100),
             ]),
         };
 
         let mut offset = 1;
-        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
-
-        assert!(clause.starts_with("NOT"));
-        assert!(clause.contains("ANY"));
-        assert_eq!(params[0], serde_json::json!(["deleted", "archived"]));
+        assert!(build_condition_clause(&condition, &mut offset, &schema).is_err());
     }
 
     // ==================== Nullability Operations ====================
@@ -800,6 +2053,25 @@ mod tests {
         assert_eq!(offset, 1); // Offset unchanged
     }
 
+    #[test]
+    fn test_is_empty_depends_on_column_type() {
+        let schema = make_test_schema();
+
+        let mut offset = 1;
+        let (clause, _) =
+            build_condition_clause(&Condition::is_empty("name"), &mut offset, &schema).unwrap();
+        assert_eq!(clause, "(\"name\" IS NULL OR \"name\"::text = '')");
+
+        let (clause, _) =
+            build_condition_clause(&Condition::is_empty("quantity"), &mut offset, &schema).unwrap();
+        assert_eq!(clause, "\"quantity\" IS NULL");
+
+        let (clause, _) =
+            build_condition_clause(&Condition::is_not_empty("active"), &mut offset, &schema)
+                .unwrap();
+        assert_eq!(clause, "\"active\" IS NOT NULL");
+    }
+
     #[test]
     fn test_is_not_empty_condition() {
         let schema = make_test_schema();
@@ -902,24 +2174,63 @@ mod tests {
     }
 
     #[test]
-    fn test_not_wrong_argument_count() {
+    fn test_not_multiple_arguments_negates_conjunction() {
         let schema = make_test_schema();
         let condition = Condition {
             op: "NOT".to_string(),
             arguments: Some(vec![
-                serde_json::json!({"op": "EQ", "arguments": ["a", "1"]}),
-                serde_json::json!({"op": "EQ", "arguments": ["b", "2"]}),
+                serde_json::json!({"op": "EQ", "arguments": ["name", "a"]}),
+                serde_json::json!({"op": "NE", "arguments": ["name", "b"]}),
+            ]),
+        };
+
+        let mut offset = 1;
+        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+
+        assert_eq!(
+            clause,
+            "NOT ((\"name\"::text = $1::text) AND (\"name\"::text != $2::text))"
+        );
+        assert_eq!(
+            params,
+            vec![
+                SqlParam::Text("a".to_string()),
+                SqlParam::Text("b".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_not_single_argument_unchanged() {
+        let schema = make_test_schema();
+        let condition = Condition {
+            op: "NOT".to_string(),
+            arguments: Some(vec![
+                serde_json::json!({"op": "EQ", "arguments": ["name", "a"]}),
             ]),
         };
 
+        let mut offset = 1;
+        let (clause, _) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+
+        assert_eq!(clause, "NOT (\"name\"::text = $1::text)");
+    }
+
+    #[test]
+    fn test_not_without_conditions() {
+        let schema = make_test_schema();
+        let condition = Condition {
+            op: "NOT".to_string(),
+            arguments: Some(vec![]),
+        };
+
         let mut offset = 1;
         let result = build_condition_clause(&condition, &mut offset, &schema);
 
-        assert!(result.is_err());
         assert!(
             result
                 .unwrap_err()
-                .contains("requires exactly one argument")
+                .contains("requires at least one condition")
         );
     }
 
@@ -1089,6 +2400,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_id_in_condition() {
+        let schema = make_test_schema();
+        let condition = Condition::id_in(["id-1", "id-2"]);
+
+        let mut offset = 1;
+        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+
+        assert_eq!(clause, "\"id\" = ANY($1::text[])");
+        assert_eq!(
+            params,
+            vec![SqlParam::Text("{\"id-1\",\"id-2\"}".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_in_typed_array_for_known_columns() {
+        let schema = make_test_schema();
+        let mut offset = 1;
+
+        let condition = Condition::r#in("quantity", vec![1.into(), 2.into(), 3.into()]);
+        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+        assert_eq!(clause, "\"quantity\" = ANY($1::bigint[])");
+        assert_eq!(
+            params,
+            vec![SqlParam::Text("{\"1\",\"2\",\"3\"}".to_string())]
+        );
+
+        let condition = Condition::not_in("name", vec!["a\"b".into(), serde_json::Value::Null]);
+        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+        assert_eq!(clause, "NOT (\"name\" = ANY($2::text[]))");
+        assert_eq!(
+            params,
+            vec![SqlParam::Text("{\"a\\\"b\",NULL}".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_in_falls_back_to_text_for_invalid_values() {
+        let schema = make_test_schema();
+        let condition = Condition::r#in("quantity", vec![1.into(), "abc".into()]);
+
+        let mut offset = 1;
+        let (clause, params) = build_condition_clause(&condition, &mut offset, &schema).unwrap();
+
+        assert_eq!(
+            clause,
+            "\"quantity\"::text = ANY(SELECT jsonb_array_elements_text($1::jsonb))"
+        );
+        assert_eq!(params, vec![SqlParam::Json(serde_json::json!([1, "abc"]))]);
+    }
+
     #[test]
     fn test_system_field_id_uses_text() {
         let schema = make_test_schema();
@@ -1172,6 +2535,27 @@ mod tests {
         assert_eq!(result, "\"created_at\" ASC"); // camelCase -> snake_case
     }
 
+    #[test]
+    fn test_field_names_resolve_identically_in_condition_and_sort() {
+        let schema = make_test_schema();
+
+        for (field, column) in [
+            ("createdAt", "\"created_at\""),
+            ("created_at", "\"created_at\""),
+            ("updatedAt", "\"updated_at\""),
+            ("price", "\"price\""),
+        ] {
+            let mut offset = 1;
+            let (clause, _) =
+                build_condition_clause(&Condition::eq(field, "1"), &mut offset, &schema).unwrap();
+            let order =
+                build_order_by_clause(&Some(vec![field.to_string()]), &None, &schema).unwrap();
+
+            assert!(clause.starts_with(&format!("{}::", column)), "{}", clause);
+            assert_eq!(order, format!("{} ASC", column));
+        }
+    }
+
     #[test]
     fn test_order_by_system_field_updated_at() {
         let schema = make_test_schema();
@@ -1249,4 +2633,146 @@ mod tests {
 
         assert_eq!(result, "\"name\" ASC, \"created_at\" DESC, \"price\" ASC");
     }
+
+    #[test]
+    fn test_stable_order_by_appends_tiebreaker() {
+        let schema = make_test_schema();
+
+        let result = build_stable_order_by_clause(
+            &Some(vec!["name".to_string()]),
+            &Some(vec!["desc".to_string()]),
+            &schema,
+            "id",
+            None,
+        )
+        .unwrap();
+        assert_eq!(result, "\"name\" DESC, \"id\" ASC");
+
+        let result = build_stable_order_by_clause(&None, &None, &schema, "id", None).unwrap();
+        assert_eq!(result, "created_at ASC, \"id\" ASC");
+
+        // Already the last key: kept as requested
+        let result = build_stable_order_by_clause(
+            &Some(vec!["name".to_string(), "id".to_string()]),
+            &Some(vec!["asc".to_string(), "desc".to_string()]),
+            &schema,
+            "id",
+            None,
+        )
+        .unwrap();
+        assert_eq!(result, "\"name\" ASC, \"id\" DESC");
+    }
+
+    #[test]
+    fn test_order_by_nulls_placement() {
+        let schema = make_test_schema();
+        let sort_by = Some(vec!["name".to_string(), "price".to_string()]);
+
+        // Per-key placement
+        let result = build_order_by_clause(
+            &sort_by,
+            &Some(vec![
+                "asc nulls first".to_string(),
+                "DESC NULLS LAST".to_string(),
+            ]),
+            &schema,
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            "\"name\" ASC NULLS FIRST, \"price\" DESC NULLS LAST"
+        );
+
+        // Store default fills in keys without a placement, including the default sort
+        let result = build_order_by_clause_with_nulls(
+            &sort_by,
+            &Some(vec!["desc".to_string(), "asc nulls first".to_string()]),
+            &schema,
+            Some(NullsOrdering::Last),
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            "\"name\" DESC NULLS LAST, \"price\" ASC NULLS FIRST"
+        );
+
+        let result =
+            build_order_by_clause_with_nulls(&None, &None, &schema, Some(NullsOrdering::First))
+                .unwrap();
+        assert_eq!(result, "created_at ASC NULLS FIRST");
+
+        // The tiebreaker is never NULL and gets no placement
+        let result = build_stable_order_by_clause(
+            &sort_by,
+            &None,
+            &schema,
+            "id",
+            Some(NullsOrdering::First),
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            "\"name\" ASC NULLS FIRST, \"price\" ASC NULLS FIRST, \"id\" ASC"
+        );
+
+        let result = build_order_by_clause(
+            &sort_by,
+            &Some(vec!["asc nulls middle".to_string()]),
+            &schema,
+        );
+        assert!(result.unwrap_err().contains("nulls first"));
+    }
+
+    #[test]
+    fn test_validate_condition_valid_tree() {
+        let schema = make_test_schema();
+        let condition = Condition::and(vec![
+            Condition::eq("name", "widget"),
+            Condition::r#in("quantity", vec![1.into(), 2.into()]),
+            Condition::lt("createdAt", Condition::field_ref("updatedAt")),
+            Condition::id_in(["a", "b"]),
+        ]);
+
+        assert!(validate_condition(&condition, &schema).is_ok());
+    }
+
+    #[test]
+    fn test_validate_condition_unknown_operator() {
+        let schema = make_test_schema();
+        let condition = Condition {
+            op: "MATCHES".to_string(),
+            arguments: Some(vec![serde_json::json!("name"), serde_json::json!("x")]),
+        };
+
+        let err = validate_condition(&condition, &schema).unwrap_err();
+        assert!(matches!(err, ObjectStoreError::InvalidCondition(_)));
+    }
+
+    #[test]
+    fn test_validate_condition_bad_arity() {
+        let schema = make_test_schema();
+        let condition = Condition {
+            op: "EQ".to_string(),
+            arguments: Some(vec![serde_json::json!("name")]),
+        };
+
+        let err = validate_condition(&condition, &schema).unwrap_err();
+        assert!(err.to_string().contains("exactly 2 arguments"));
+    }
+
+    #[test]
+    fn test_validate_condition_unknown_field() {
+        let schema = make_test_schema();
+
+        let condition = Condition::or(vec![
+            Condition::eq("name", "widget"),
+            Condition::is_empty("colour"),
+        ]);
+        let err = validate_condition(&condition, &schema).unwrap_err();
+        assert!(err.to_string().contains("'colour'"));
+
+        let condition = Condition::eq("price", Condition::field_ref("cost"));
+        let err = validate_condition(&condition, &schema).unwrap_err();
+        assert!(err.to_string().contains("'cost'"));
+    }
 }
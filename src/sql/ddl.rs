@@ -2,19 +2,41 @@
 //!
 //! Generates PostgreSQL DDL statements for dynamically managing object model tables.
 
+use std::collections::HashMap;
+
 use crate::config::StoreConfig;
-use crate::sql::sanitize::quote_identifier;
+use crate::schema::{CreateSchemaRequest, Schema};
+use crate::sql::sanitize::{quote_identifier, safe_identifier};
 use crate::types::{ColumnDefinition, IndexDefinition};
 
 /// DDL Generator for object model tables
 pub struct DdlGenerator<'a> {
     config: &'a StoreConfig,
+    tablespace: Option<&'a str>,
 }
 
 impl<'a> DdlGenerator<'a> {
     /// Create a new DDL generator with the given configuration
     pub fn new(config: &'a StoreConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            tablespace: None,
+        }
+    }
+
+    /// Place generated tables and indexes in a tablespace
+    ///
+    /// The name must already be validated as an identifier.
+    pub fn with_tablespace(mut self, tablespace: Option<&'a str>) -> Self {
+        self.tablespace = tablespace;
+        self
+    }
+
+    /// ` TABLESPACE "name"` when a tablespace is set, otherwise empty
+    fn tablespace_clause(&self) -> String {
+        self.tablespace
+            .map(|tablespace| format!(" TABLESPACE {}", quote_identifier(tablespace)))
+            .unwrap_or_default()
     }
 
     /// Generate CREATE TABLE statement with auto-managed columns
@@ -24,38 +46,133 @@ impl<'a> DdlGenerator<'a> {
     /// - Auto-managed columns based on config: id, created_at, updated_at
     /// - Optional soft-delete column (deleted) if enabled in config
     pub fn generate_create_table(&self, table_name: &str, columns: &[ColumnDefinition]) -> String {
+        format!(
+            "{}{}",
+            self.create_table_definition(table_name, columns),
+            self.tablespace_clause()
+        )
+    }
+
+    /// CREATE TABLE with its column list, without storage clauses
+    fn create_table_definition(&self, table_name: &str, columns: &[ColumnDefinition]) -> String {
         let quoted_table = quote_identifier(table_name);
 
-        let mut column_defs = Vec::new();
+        let mut auto_defs = Vec::new();
 
         // Add auto-managed id column if enabled
         if self.config.auto_columns.id {
-            column_defs
+            auto_defs
                 .push("id VARCHAR(255) PRIMARY KEY DEFAULT gen_random_uuid()::text".to_string());
         }
 
         // Add user-defined columns
-        for col in columns {
-            column_defs.push(Self::format_column_definition(col));
-        }
+        let user_defs: Vec<String> = columns
+            .iter()
+            .map(|col| self.column_definition(table_name, col))
+            .collect();
 
         // Add auto-managed timestamp columns if enabled
         // Use TIMESTAMPTZ to match Rust's chrono::DateTime<Utc>
+        let mut trailing_defs = Vec::new();
         if self.config.auto_columns.created_at {
-            column_defs.push("created_at TIMESTAMPTZ DEFAULT NOW()".to_string());
+            trailing_defs.push("created_at TIMESTAMPTZ DEFAULT NOW()".to_string());
         }
         if self.config.auto_columns.updated_at {
-            column_defs.push("updated_at TIMESTAMPTZ DEFAULT NOW()".to_string());
+            trailing_defs.push("updated_at TIMESTAMPTZ DEFAULT NOW()".to_string());
         }
 
         // Add soft-delete column if enabled
         if self.config.soft_delete {
-            column_defs.push("deleted BOOLEAN DEFAULT FALSE".to_string());
+            trailing_defs.push("deleted BOOLEAN DEFAULT FALSE".to_string());
         }
 
+        // Column order is cosmetic: every query names its columns
+        let column_defs: Vec<String> = if self.config.auto_columns_last {
+            user_defs
+                .into_iter()
+                .chain(auto_defs)
+                .chain(trailing_defs)
+                .collect()
+        } else {
+            auto_defs
+                .into_iter()
+                .chain(user_defs)
+                .chain(trailing_defs)
+                .collect()
+        };
+
         format!("CREATE TABLE {} ({})", quoted_table, column_defs.join(", "))
     }
 
+    /// Generate CREATE TABLE statement with table storage parameters
+    ///
+    /// Same as [`generate_create_table`](Self::generate_create_table), followed
+    /// by `WITH (name = value, ...)` in name order when any parameters are
+    /// given. Parameters must already pass [`validate_storage_params`].
+    pub fn generate_create_table_with_storage(
+        &self,
+        table_name: &str,
+        columns: &[ColumnDefinition],
+        storage_params: &HashMap<String, String>,
+    ) -> String {
+        if storage_params.is_empty() {
+            return self.generate_create_table(table_name, columns);
+        }
+        let create_sql = self.create_table_definition(table_name, columns);
+
+        let mut params: Vec<_> = storage_params.iter().collect();
+        params.sort();
+        let params: Vec<String> = params
+            .into_iter()
+            .map(|(name, value)| format!("{} = {}", name, value))
+            .collect();
+
+        format!(
+            "{} WITH ({}){}",
+            create_sql,
+            params.join(", "),
+            self.tablespace_clause()
+        )
+    }
+
+    /// Plan the DDL that turns a stored schema's table into the desired definition
+    ///
+    /// Statements are ordered so each one can run after the previous:
+    /// 1. Drop declared indexes that were removed or changed
+    /// 2. Column and constraint changes from
+    ///    [`generate_alter_table`](Self::generate_alter_table)
+    /// 3. Create declared indexes that were added or changed
+    ///
+    /// The plan always targets the current table; renaming the table, the
+    /// description, and the default index settings are not part of it.
+    pub fn plan_migration(&self, current: &Schema, desired: &CreateSchemaRequest) -> Vec<String> {
+        let table_name = &current.table_name;
+        let current_indexes = current.indexes.as_deref().unwrap_or_default();
+        let desired_indexes = desired.indexes.as_deref().unwrap_or_default();
+
+        let mut statements = Vec::new();
+
+        for index in current_indexes {
+            if !desired_indexes.contains(index) {
+                statements.push(self.generate_drop_index(table_name, index));
+            }
+        }
+
+        statements.extend(self.generate_alter_table(
+            table_name,
+            &current.columns,
+            &desired.columns,
+        ));
+
+        for index in desired_indexes {
+            if !current_indexes.contains(index) {
+                statements.push(self.generate_create_index(table_name, index));
+            }
+        }
+
+        statements
+    }
+
     /// Generate ALTER TABLE statements to modify table structure
     pub fn generate_alter_table(
         &self,
@@ -72,8 +189,11 @@ impl<'a> DdlGenerator<'a> {
                 statements.push(format!(
                     "ALTER TABLE {} ADD COLUMN {}",
                     quoted_table,
-                    Self::format_column_definition(new_col)
+                    self.column_definition(table_name, new_col)
                 ));
+                if self.config.unique_as_index && new_col.unique {
+                    statements.push(self.generate_unique_column_index(table_name, &new_col.name));
+                }
             }
         }
 
@@ -91,13 +211,13 @@ impl<'a> DdlGenerator<'a> {
         // Find modified columns
         for new_col in new_columns {
             if let Some(old_col) = old_columns.iter().find(|c| c.name == new_col.name) {
-                // Type change
-                if old_col.column_type != new_col.column_type {
+                // Type change (enum value changes only touch the CHECK constraint)
+                if old_col.column_type.base_sql_type() != new_col.column_type.base_sql_type() {
                     statements.push(format!(
                         "ALTER TABLE {} ALTER COLUMN {} TYPE {}",
                         quoted_table,
                         quote_identifier(&new_col.name),
-                        new_col.column_type.to_sql_type(&new_col.name)
+                        new_col.column_type.base_sql_type()
                     ));
                 }
 
@@ -133,6 +253,8 @@ impl<'a> DdlGenerator<'a> {
                         ));
                     }
                 }
+
+                statements.extend(self.alter_named_constraints(table_name, old_col, new_col));
             }
         }
 
@@ -148,7 +270,8 @@ impl<'a> DdlGenerator<'a> {
     /// Generate CREATE INDEX statement
     pub fn generate_create_index(&self, table_name: &str, index: &IndexDefinition) -> String {
         let quoted_table = quote_identifier(table_name);
-        let quoted_index_name = quote_identifier(&format!("{}_{}", table_name, index.name));
+        let quoted_index_name =
+            quote_identifier(&safe_identifier(&format!("{}_{}", table_name, index.name)));
 
         let quoted_columns: Vec<String> = index
             .columns
@@ -159,37 +282,247 @@ impl<'a> DdlGenerator<'a> {
         let unique_clause = if index.unique { "UNIQUE " } else { "" };
 
         format!(
-            "CREATE {}INDEX {} ON {}({})",
+            "CREATE {}INDEX {} ON {}({}){}",
             unique_clause,
             quoted_index_name,
             quoted_table,
-            quoted_columns.join(", ")
+            quoted_columns.join(", "),
+            self.tablespace_clause()
         )
     }
 
+    /// Generate DROP INDEX statement for a declared index
+    pub fn generate_drop_index(&self, table_name: &str, index: &IndexDefinition) -> String {
+        format!(
+            "DROP INDEX IF EXISTS {}",
+            quote_identifier(&safe_identifier(&format!("{}_{}", table_name, index.name)))
+        )
+    }
+
+    /// Generate the named unique index for a `unique` column
+    ///
+    /// Used instead of an inline UNIQUE constraint when
+    /// [`StoreConfig::unique_as_index`] is set. The index shares the
+    /// [`constraint_name`](Self::constraint_name) a constraint would get, so
+    /// turning `unique` off can drop either form.
+    pub fn generate_unique_column_index(&self, table_name: &str, column_name: &str) -> String {
+        format!(
+            "CREATE UNIQUE INDEX IF NOT EXISTS {} ON {} ({}){}",
+            quote_identifier(&Self::constraint_name("uq", table_name, column_name)),
+            quote_identifier(table_name),
+            quote_identifier(column_name),
+            self.tablespace_clause()
+        )
+    }
+
+    /// Unique indexes to create after CREATE TABLE for `unique` columns
+    ///
+    /// Empty unless [`StoreConfig::unique_as_index`] is set, in which case
+    /// [`generate_create_table`](Self::generate_create_table) leaves the
+    /// columns' UNIQUE constraints out.
+    pub fn generate_unique_column_indexes(
+        &self,
+        table_name: &str,
+        columns: &[ColumnDefinition],
+    ) -> Vec<String> {
+        if !self.config.unique_as_index {
+            return Vec::new();
+        }
+        columns
+            .iter()
+            .filter(|col| col.unique)
+            .map(|col| self.generate_unique_column_index(table_name, &col.name))
+            .collect()
+    }
+
     /// Generate default index for efficient querying
     ///
     /// Creates an index on created_at for efficient time-based queries.
     /// If soft-delete is enabled, includes a WHERE clause to filter deleted rows.
     pub fn generate_default_index(&self, table_name: &str) -> String {
+        self.generate_default_index_where(table_name, None)
+    }
+
+    /// Generate default index restricted by an additional predicate
+    ///
+    /// The predicate must already be validated (see
+    /// [`validate_predicate`](super::expression::validate_predicate)); it is
+    /// combined with the soft-delete filter when soft-delete is enabled.
+    pub fn generate_default_index_where(
+        &self,
+        table_name: &str,
+        predicate: Option<&str>,
+    ) -> String {
         let quoted_table = quote_identifier(table_name);
         let index_name = format!("idx_{}_default", table_name);
-        let quoted_index = quote_identifier(&index_name);
+        let quoted_index = quote_identifier(&safe_identifier(&index_name));
 
+        let mut conditions = Vec::new();
         if self.config.soft_delete {
+            conditions.push("deleted = FALSE".to_string());
+        }
+        if let Some(predicate) = predicate {
+            conditions.push(format!("({})", predicate.trim()));
+        }
+
+        if conditions.is_empty() {
             format!(
-                "CREATE INDEX {} ON {}(created_at DESC) WHERE deleted = FALSE",
-                quoted_index, quoted_table
+                "CREATE INDEX {} ON {}(created_at DESC){}",
+                quoted_index,
+                quoted_table,
+                self.tablespace_clause()
             )
         } else {
             format!(
-                "CREATE INDEX {} ON {}(created_at DESC)",
-                quoted_index, quoted_table
+                "CREATE INDEX {} ON {}(created_at DESC){} WHERE {}",
+                quoted_index,
+                quoted_table,
+                self.tablespace_clause(),
+                conditions.join(" AND ")
             )
         }
     }
 
+    /// Deterministic name for a constraint the generator attaches to a column
+    ///
+    /// `kind` is a short prefix such as `uq` (unique), `fk` (foreign key), or
+    /// `ck` (check). Long names are shortened with [`safe_identifier`].
+    pub fn constraint_name(kind: &str, table_name: &str, column_name: &str) -> String {
+        safe_identifier(&format!("{}_{}_{}", kind, table_name, column_name))
+    }
+
+    /// Statements adding or dropping named constraints whose definition changed
+    fn alter_named_constraints(
+        &self,
+        table_name: &str,
+        old_col: &ColumnDefinition,
+        new_col: &ColumnDefinition,
+    ) -> Vec<String> {
+        let quoted_table = quote_identifier(table_name);
+        let quoted_column = quote_identifier(&new_col.name);
+        let mut statements = Vec::new();
+        let drop = |kind: &str| {
+            format!(
+                "ALTER TABLE {} DROP CONSTRAINT IF EXISTS {}",
+                quoted_table,
+                quote_identifier(&Self::constraint_name(kind, table_name, &new_col.name))
+            )
+        };
+        let add = |kind: &str, definition: String| {
+            format!(
+                "ALTER TABLE {} ADD CONSTRAINT {} {}",
+                quoted_table,
+                quote_identifier(&Self::constraint_name(kind, table_name, &new_col.name)),
+                definition
+            )
+        };
+
+        if old_col.unique != new_col.unique {
+            if !new_col.unique {
+                // The column may have been made unique with either a constraint or a
+                // named index, whatever `unique_as_index` was then; drop both. The
+                // constraint goes first and takes its backing index with it.
+                statements.push(drop("uq"));
+                statements.push(format!(
+                    "DROP INDEX IF EXISTS {}",
+                    quote_identifier(&Self::constraint_name("uq", table_name, &new_col.name))
+                ));
+            } else if self.config.unique_as_index {
+                statements.push(self.generate_unique_column_index(table_name, &new_col.name));
+            } else {
+                statements.push(add("uq", format!("UNIQUE ({})", quoted_column)));
+            }
+        }
+
+        if old_col.references != new_col.references {
+            if old_col.references.is_some() {
+                statements.push(drop("fk"));
+            }
+            if let Some(referenced_table) = &new_col.references {
+                statements.push(add(
+                    "fk",
+                    format!(
+                        "FOREIGN KEY ({}) REFERENCES {}(id)",
+                        quoted_column,
+                        quote_identifier(referenced_table)
+                    ),
+                ));
+            }
+        }
+
+        let old_check = old_col.column_type.check_constraint(&old_col.name);
+        let new_check = new_col.column_type.check_constraint(&new_col.name);
+        if old_check != new_check {
+            if old_check.is_some() {
+                statements.push(drop("ck"));
+            }
+            if let Some(check) = new_check {
+                statements.push(add("ck", check));
+            }
+        }
+
+        statements
+    }
+
+    /// Column definition as emitted by this generator's CREATE and ADD COLUMN
+    fn column_definition(&self, table_name: &str, col: &ColumnDefinition) -> String {
+        if self.config.unique_as_index && col.unique {
+            let without_unique = ColumnDefinition {
+                unique: false,
+                ..col.clone()
+            };
+            Self::format_named_column_definition(table_name, &without_unique)
+        } else {
+            Self::format_named_column_definition(table_name, col)
+        }
+    }
+
+    /// Format a column definition with named constraints for the given table
+    ///
+    /// Like [`format_column_definition`](Self::format_column_definition), but
+    /// each UNIQUE, CHECK, and REFERENCES constraint gets a
+    /// [`constraint_name`](Self::constraint_name) so it can be dropped later.
+    pub fn format_named_column_definition(table_name: &str, col: &ColumnDefinition) -> String {
+        let constraint = |kind: &str| {
+            format!(
+                "CONSTRAINT {}",
+                quote_identifier(&Self::constraint_name(kind, table_name, &col.name))
+            )
+        };
+
+        let mut parts = vec![quote_identifier(&col.name), col.column_type.base_sql_type()];
+
+        if let Some(check) = col.column_type.check_constraint(&col.name) {
+            parts.push(format!("{} {}", constraint("ck"), check));
+        }
+
+        if col.unique {
+            parts.push(format!("{} UNIQUE", constraint("uq")));
+        }
+
+        if !col.nullable {
+            parts.push("NOT NULL".to_string());
+        }
+
+        if let Some(default) = &col.default_value {
+            parts.push(format!("DEFAULT {}", default));
+        }
+
+        if let Some(referenced_table) = &col.references {
+            parts.push(format!(
+                "{} REFERENCES {}(id)",
+                constraint("fk"),
+                quote_identifier(referenced_table)
+            ));
+        }
+
+        parts.join(" ")
+    }
+
     /// Format a single column definition for CREATE TABLE or ALTER TABLE ADD COLUMN
+    ///
+    /// Constraints are left unnamed; the generator itself uses
+    /// [`format_named_column_definition`](Self::format_named_column_definition).
     pub fn format_column_definition(col: &ColumnDefinition) -> String {
         let mut parts = vec![
             quote_identifier(&col.name),
@@ -211,10 +544,70 @@ impl<'a> DdlGenerator<'a> {
             parts.push(format!("DEFAULT {}", default));
         }
 
+        // Foreign key to another object table
+        if let Some(referenced_table) = &col.references {
+            parts.push(format!(
+                "REFERENCES {}(id)",
+                quote_identifier(referenced_table)
+            ));
+        }
+
         parts.join(" ")
     }
 }
 
+/// Validate table storage parameters for `CREATE TABLE ... WITH (...)`
+///
+/// Allowed names are `fillfactor` (an integer from 10 to 100) and the
+/// `autovacuum_*` family. Values must be plain numbers or words, since they
+/// are emitted into the DDL unquoted.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use runtara_object_store::sql::ddl::validate_storage_params;
+///
+/// let params = HashMap::from([("fillfactor".to_string(), "70".to_string())]);
+/// assert!(validate_storage_params(&params).is_ok());
+///
+/// let params = HashMap::from([("oids".to_string(), "true".to_string())]);
+/// assert!(validate_storage_params(&params).is_err());
+/// ```
+pub fn validate_storage_params(params: &HashMap<String, String>) -> Result<(), String> {
+    for (name, value) in params {
+        let allowed_name = name == "fillfactor"
+            || name.strip_prefix("autovacuum_").is_some_and(|rest| {
+                !rest.is_empty() && rest.chars().all(|c| c.is_ascii_lowercase() || c == '_')
+            });
+        if !allowed_name {
+            return Err(format!(
+                "Storage parameter '{}' is not allowed (use fillfactor or autovacuum_*)",
+                name
+            ));
+        }
+
+        if value.is_empty()
+            || !value
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '_')
+        {
+            return Err(format!(
+                "Invalid value '{}' for storage parameter '{}'",
+                value, name
+            ));
+        }
+
+        if name == "fillfactor" && !matches!(value.parse::<u32>(), Ok(10..=100)) {
+            return Err(format!(
+                "fillfactor must be an integer between 10 and 100, got '{}'",
+                value
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -278,13 +671,42 @@ mod tests {
         assert!(ddl.contains("CREATE TABLE"));
         assert!(ddl.contains("\"products\""));
         assert!(ddl.contains("id VARCHAR(255) PRIMARY KEY"));
-        assert!(ddl.contains("\"sku\" TEXT UNIQUE NOT NULL"));
+        assert!(ddl.contains("\"sku\" TEXT CONSTRAINT \"uq_products_sku\" UNIQUE NOT NULL"));
         assert!(ddl.contains("\"price\" NUMERIC(10,2) DEFAULT 0.00"));
         assert!(ddl.contains("created_at TIMESTAMPTZ"));
         assert!(ddl.contains("updated_at TIMESTAMPTZ"));
         assert!(ddl.contains("deleted BOOLEAN"));
     }
 
+    #[test]
+    fn test_generate_create_table_column_order() {
+        let columns = vec![
+            ColumnDefinition::new("name", ColumnType::String),
+            ColumnDefinition::new("qty", ColumnType::Integer),
+        ];
+
+        let config = default_config();
+        let ddl = DdlGenerator::new(&config).generate_create_table("items", &columns);
+        assert_eq!(
+            ddl,
+            "CREATE TABLE \"items\" (id VARCHAR(255) PRIMARY KEY DEFAULT gen_random_uuid()::text, \
+             \"name\" TEXT, \"qty\" BIGINT, created_at TIMESTAMPTZ DEFAULT NOW(), \
+             updated_at TIMESTAMPTZ DEFAULT NOW(), deleted BOOLEAN DEFAULT FALSE)"
+        );
+
+        let config = StoreConfig::builder("postgres://localhost/test")
+            .auto_columns_last(true)
+            .build();
+        let ddl = DdlGenerator::new(&config).generate_create_table("items", &columns);
+        assert_eq!(
+            ddl,
+            "CREATE TABLE \"items\" (\"name\" TEXT, \"qty\" BIGINT, \
+             id VARCHAR(255) PRIMARY KEY DEFAULT gen_random_uuid()::text, \
+             created_at TIMESTAMPTZ DEFAULT NOW(), updated_at TIMESTAMPTZ DEFAULT NOW(), \
+             deleted BOOLEAN DEFAULT FALSE)"
+        );
+    }
+
     #[test]
     fn test_generate_create_table_no_soft_delete() {
         let config = config_no_soft_delete();
@@ -378,7 +800,7 @@ mod tests {
             ColumnDefinition::new("bool_col", ColumnType::Boolean),
             ColumnDefinition::new("json_col", ColumnType::Json),
             ColumnDefinition::new("dec_col", ColumnType::decimal(18, 4)),
-            ColumnDefinition::new("ts_col", ColumnType::Timestamp),
+            ColumnDefinition::new("ts_col", ColumnType::timestamp()),
         ];
 
         let ddl = generator.generate_create_table("all_types", &columns);
@@ -408,7 +830,7 @@ mod tests {
 
         let ddl = generator.generate_create_table("users", &columns);
 
-        assert!(ddl.contains("\"email\" TEXT UNIQUE NOT NULL"));
+        assert!(ddl.contains("\"email\" TEXT CONSTRAINT \"uq_users_email\" UNIQUE NOT NULL"));
         assert!(ddl.contains("\"status\" TEXT NOT NULL DEFAULT 'active'"));
         assert!(ddl.contains("\"notes\" TEXT")); // No NOT NULL
     }
@@ -529,6 +951,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_default_index_with_predicate() {
+        let config = default_config();
+        let generator = DdlGenerator::new(&config);
+
+        let ddl = generator.generate_default_index_where("orders", Some("status = 'open'"));
+
+        assert_eq!(
+            ddl,
+            "CREATE INDEX \"idx_orders_default\" ON \"orders\"(created_at DESC) WHERE deleted = FALSE AND (status = 'open')"
+        );
+    }
+
+    #[test]
+    fn test_generate_default_index_with_predicate_without_soft_delete() {
+        let config = config_no_soft_delete();
+        let generator = DdlGenerator::new(&config);
+
+        let ddl = generator.generate_default_index_where("orders", Some("status = 'open'"));
+
+        assert_eq!(
+            ddl,
+            "CREATE INDEX \"idx_orders_default\" ON \"orders\"(created_at DESC) WHERE (status = 'open')"
+        );
+    }
+
+    #[test]
+    fn test_generated_index_names_fit_identifier_limit() {
+        let config = default_config();
+        let generator = DdlGenerator::new(&config);
+        let table = "t".repeat(60);
+
+        let index = IndexDefinition::new("status_idx", vec!["status".to_string()]);
+        let ddl = generator.generate_create_index(&table, &index);
+        let expected = safe_identifier(&format!("{}_status_idx", table));
+        assert_eq!(expected.len(), 63);
+        assert!(ddl.starts_with(&format!("CREATE INDEX \"{}\" ON", expected)));
+
+        let ddl = generator.generate_default_index(&table);
+        let expected = safe_identifier(&format!("idx_{}_default", table));
+        assert!(ddl.starts_with(&format!("CREATE INDEX \"{}\" ON", expected)));
+    }
+
     // ==================== ALTER TABLE Tests ====================
 
     #[test]
@@ -708,6 +1173,190 @@ mod tests {
         assert!(combined.contains("TYPE"));
     }
 
+    #[test]
+    fn test_generate_alter_table_toggle_unique() {
+        let config = default_config();
+        let generator = DdlGenerator::new(&config);
+
+        let plain = vec![ColumnDefinition::new("sku", ColumnType::String)];
+        let unique = vec![ColumnDefinition::new("sku", ColumnType::String).unique()];
+
+        let added = generator.generate_alter_table("products", &plain, &unique);
+        assert_eq!(
+            added,
+            vec![
+                "ALTER TABLE \"products\" ADD CONSTRAINT \"uq_products_sku\" UNIQUE (\"sku\")"
+                    .to_string()
+            ]
+        );
+
+        // Drops the unique index too, in case the column was created with
+        // `unique_as_index` by another store
+        let dropped = generator.generate_alter_table("products", &unique, &plain);
+        assert_eq!(
+            dropped,
+            vec![
+                "ALTER TABLE \"products\" DROP CONSTRAINT IF EXISTS \"uq_products_sku\""
+                    .to_string(),
+                "DROP INDEX IF EXISTS \"uq_products_sku\"".to_string(),
+            ]
+        );
+
+        let as_index = StoreConfig::builder("postgres://localhost/test")
+            .unique_as_index(true)
+            .build();
+        assert_eq!(
+            DdlGenerator::new(&as_index).generate_alter_table("products", &unique, &plain),
+            dropped
+        );
+    }
+
+    #[test]
+    fn test_unique_as_index() {
+        let config = StoreConfig::builder("postgres://localhost/test")
+            .unique_as_index(true)
+            .build();
+        let generator = DdlGenerator::new(&config);
+
+        let plain = vec![ColumnDefinition::new("sku", ColumnType::String)];
+        let unique = vec![ColumnDefinition::new("sku", ColumnType::String).unique()];
+
+        let ddl = generator.generate_create_table("products", &unique);
+        assert!(ddl.contains("\"sku\" TEXT,"));
+        assert_eq!(
+            generator.generate_unique_column_indexes("products", &unique),
+            vec![
+                "CREATE UNIQUE INDEX IF NOT EXISTS \"uq_products_sku\" ON \"products\" (\"sku\")"
+                    .to_string()
+            ]
+        );
+        assert!(
+            generator
+                .generate_unique_column_indexes("products", &plain)
+                .is_empty()
+        );
+
+        let added = generator.generate_alter_table("products", &plain, &unique);
+        assert_eq!(
+            added,
+            generator.generate_unique_column_indexes("products", &unique)
+        );
+
+        let dropped = generator.generate_alter_table("products", &unique, &plain);
+        assert_eq!(
+            dropped,
+            vec![
+                "ALTER TABLE \"products\" DROP CONSTRAINT IF EXISTS \"uq_products_sku\""
+                    .to_string(),
+                "DROP INDEX IF EXISTS \"uq_products_sku\"".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unique_as_index_disabled_by_default() {
+        let config = default_config();
+        let generator = DdlGenerator::new(&config);
+        let unique = vec![ColumnDefinition::new("sku", ColumnType::String).unique()];
+
+        assert!(
+            generator
+                .generate_unique_column_indexes("products", &unique)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_generate_alter_table_change_reference() {
+        let config = default_config();
+        let generator = DdlGenerator::new(&config);
+
+        let old_columns =
+            vec![ColumnDefinition::new("owner_id", ColumnType::String).references("users")];
+        let new_columns =
+            vec![ColumnDefinition::new("owner_id", ColumnType::String).references("teams")];
+
+        let statements = generator.generate_alter_table("projects", &old_columns, &new_columns);
+
+        assert_eq!(
+            statements,
+            vec![
+                "ALTER TABLE \"projects\" DROP CONSTRAINT IF EXISTS \"fk_projects_owner_id\""
+                    .to_string(),
+                "ALTER TABLE \"projects\" ADD CONSTRAINT \"fk_projects_owner_id\" \
+                 FOREIGN KEY (\"owner_id\") REFERENCES \"teams\"(id)"
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generate_alter_table_change_enum_values() {
+        let config = default_config();
+        let generator = DdlGenerator::new(&config);
+
+        let old_columns = vec![ColumnDefinition::new(
+            "status",
+            ColumnType::Enum {
+                values: vec!["a".to_string()],
+            },
+        )];
+        let new_columns = vec![ColumnDefinition::new(
+            "status",
+            ColumnType::Enum {
+                values: vec!["a".to_string(), "b".to_string()],
+            },
+        )];
+
+        let statements = generator.generate_alter_table("orders", &old_columns, &new_columns);
+
+        // Only the CHECK constraint is replaced; the column stays TEXT
+        assert_eq!(
+            statements,
+            vec![
+                "ALTER TABLE \"orders\" DROP CONSTRAINT IF EXISTS \"ck_orders_status\"".to_string(),
+                "ALTER TABLE \"orders\" ADD CONSTRAINT \"ck_orders_status\" \
+                 CHECK (status IN ('a', 'b'))"
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generate_create_table_names_constraints() {
+        let config = config_no_auto_columns();
+        let generator = DdlGenerator::new(&config);
+
+        let columns = vec![
+            ColumnDefinition::new(
+                "status",
+                ColumnType::Enum {
+                    values: vec!["a".to_string()],
+                },
+            ),
+            ColumnDefinition::new("owner_id", ColumnType::String).references("users"),
+        ];
+
+        let ddl = generator.generate_create_table("orders", &columns);
+
+        assert!(
+            ddl.contains("\"status\" TEXT CONSTRAINT \"ck_orders_status\" CHECK (status IN ('a'))")
+        );
+        assert!(ddl.contains(
+            "\"owner_id\" TEXT CONSTRAINT \"fk_orders_owner_id\" REFERENCES \"users\"(id)"
+        ));
+    }
+
+    #[test]
+    fn test_constraint_name_fits_identifier_limit() {
+        let table = "t".repeat(60);
+        let name = DdlGenerator::constraint_name("uq", &table, "column");
+
+        assert!(name.len() <= crate::sql::sanitize::MAX_IDENTIFIER_BYTES);
+        assert_eq!(name, DdlGenerator::constraint_name("uq", &table, "column"));
+        assert_ne!(name, DdlGenerator::constraint_name("fk", &table, "column"));
+    }
+
     #[test]
     fn test_generate_alter_table_no_changes() {
         let config = default_config();
@@ -785,6 +1434,19 @@ mod tests {
         assert_eq!(formatted, "\"amount\" NUMERIC(10,2) NOT NULL");
     }
 
+    #[test]
+    fn test_format_column_definition_references() {
+        let col = ColumnDefinition::new("customer_id", ColumnType::String)
+            .not_null()
+            .references("customers");
+        let formatted = DdlGenerator::format_column_definition(&col);
+
+        assert_eq!(
+            formatted,
+            "\"customer_id\" TEXT NOT NULL REFERENCES \"customers\"(id)"
+        );
+    }
+
     // ==================== Edge Cases ====================
 
     #[test]
@@ -813,4 +1475,145 @@ mod tests {
         assert!(ddl.contains("\"user-id\""));
         assert!(ddl.contains("\"order\""));
     }
+
+    #[test]
+    fn test_create_table_with_storage_params() {
+        let config = config_no_auto_columns();
+        let ddl = DdlGenerator::new(&config);
+        let columns = vec![ColumnDefinition::new("name", ColumnType::String)];
+
+        let params = HashMap::from([
+            ("fillfactor".to_string(), "70".to_string()),
+            (
+                "autovacuum_vacuum_scale_factor".to_string(),
+                "0.05".to_string(),
+            ),
+        ]);
+        let sql = ddl.generate_create_table_with_storage("items", &columns, &params);
+        assert_eq!(
+            sql,
+            "CREATE TABLE \"items\" (\"name\" TEXT) \
+             WITH (autovacuum_vacuum_scale_factor = 0.05, fillfactor = 70)"
+        );
+
+        let sql = ddl.generate_create_table_with_storage("items", &columns, &HashMap::new());
+        assert_eq!(sql, ddl.generate_create_table("items", &columns));
+    }
+
+    #[test]
+    fn test_tablespace_on_table_and_indexes() {
+        let config = config_no_auto_columns();
+        let ddl = DdlGenerator::new(&config).with_tablespace(Some("fast_ssd"));
+        let columns = vec![ColumnDefinition::new("name", ColumnType::String)];
+
+        assert_eq!(
+            ddl.generate_create_table("items", &columns),
+            "CREATE TABLE \"items\" (\"name\" TEXT) TABLESPACE \"fast_ssd\""
+        );
+
+        let params = HashMap::from([("fillfactor".to_string(), "70".to_string())]);
+        assert_eq!(
+            ddl.generate_create_table_with_storage("items", &columns, &params),
+            "CREATE TABLE \"items\" (\"name\" TEXT) WITH (fillfactor = 70) \
+             TABLESPACE \"fast_ssd\""
+        );
+
+        let index = IndexDefinition::new("by_name", vec!["name".to_string()]);
+        assert_eq!(
+            ddl.generate_create_index("items", &index),
+            "CREATE INDEX \"items_by_name\" ON \"items\"(\"name\") TABLESPACE \"fast_ssd\""
+        );
+
+        let config = default_config();
+        let ddl = DdlGenerator::new(&config).with_tablespace(Some("fast_ssd"));
+        assert_eq!(
+            ddl.generate_default_index("items"),
+            "CREATE INDEX \"idx_items_default\" ON \"items\"(created_at DESC) \
+             TABLESPACE \"fast_ssd\" WHERE deleted = FALSE"
+        );
+    }
+
+    #[test]
+    fn test_validate_storage_params() {
+        let params =
+            |name: &str, value: &str| HashMap::from([(name.to_string(), value.to_string())]);
+
+        assert!(validate_storage_params(&params("fillfactor", "70")).is_ok());
+        assert!(validate_storage_params(&params("autovacuum_enabled", "false")).is_ok());
+        assert!(validate_storage_params(&params("fillfactor", "5")).is_err());
+        assert!(validate_storage_params(&params("fillfactor", "abc")).is_err());
+        assert!(validate_storage_params(&params("toast_tuple_target", "128")).is_err());
+        assert!(validate_storage_params(&params("autovacuum_", "1")).is_err());
+        assert!(
+            validate_storage_params(&params("autovacuum_enabled", "true); DROP TABLE x")).is_err()
+        );
+    }
+
+    #[test]
+    fn test_plan_migration_orders_statements() {
+        let config = config_no_auto_columns();
+        let ddl = DdlGenerator::new(&config);
+
+        let mut current = Schema::new(
+            "1",
+            "orders",
+            "orders",
+            vec![
+                ColumnDefinition::new("code", ColumnType::String),
+                ColumnDefinition::new("total", ColumnType::Integer),
+            ],
+        );
+        current.indexes = Some(vec![IndexDefinition::new(
+            "by_code",
+            vec!["code".to_string()],
+        )]);
+
+        let desired = CreateSchemaRequest::new(
+            "orders",
+            "orders",
+            vec![
+                ColumnDefinition::new("code", ColumnType::String),
+                ColumnDefinition::new("total", ColumnType::decimal(12, 2)),
+                ColumnDefinition::new("note", ColumnType::String),
+            ],
+        );
+
+        assert_eq!(
+            ddl.plan_migration(&current, &desired),
+            vec![
+                "DROP INDEX IF EXISTS \"orders_by_code\"",
+                "ALTER TABLE \"orders\" ADD COLUMN \"note\" TEXT",
+                "ALTER TABLE \"orders\" ALTER COLUMN \"total\" TYPE NUMERIC(12,2)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_migration_recreates_changed_index() {
+        let config = config_no_auto_columns();
+        let ddl = DdlGenerator::new(&config);
+        let columns = vec![
+            ColumnDefinition::new("a", ColumnType::String),
+            ColumnDefinition::new("b", ColumnType::String),
+        ];
+
+        let mut current = Schema::new("1", "pairs", "pairs", columns.clone());
+        current.indexes = Some(vec![IndexDefinition::new("by_a", vec!["a".to_string()])]);
+
+        let desired =
+            CreateSchemaRequest::new("pairs", "pairs", columns.clone()).with_indexes(vec![
+                IndexDefinition::new("by_a", vec!["a".to_string(), "b".to_string()]),
+            ]);
+        assert_eq!(
+            ddl.plan_migration(&current, &desired),
+            vec![
+                "DROP INDEX IF EXISTS \"pairs_by_a\"",
+                "CREATE INDEX \"pairs_by_a\" ON \"pairs\"(\"a\", \"b\")",
+            ]
+        );
+
+        let unchanged = CreateSchemaRequest::new("pairs", "pairs", columns)
+            .with_indexes(current.indexes.clone().unwrap());
+        assert!(ddl.plan_migration(&current, &unchanged).is_empty());
+    }
 }
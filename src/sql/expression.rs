@@ -0,0 +1,467 @@
+//! Validation of user-supplied SQL expressions
+//!
+//! Provides a conservative tokenizer for accepting small SQL fragments (such as
+//! partial index predicates and computed fields) that may only reference known
+//! columns and literals, and a type-aware check for column default values.
+
+use crate::types::ColumnType;
+
+/// Keywords permitted inside a predicate
+const PREDICATE_KEYWORDS: &[&str] = &[
+    "AND", "OR", "NOT", "IS", "NULL", "TRUE", "FALSE", "IN", "BETWEEN", "LIKE", "ILIKE",
+];
+
+/// A lexical token of a SQL expression
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// Unquoted word (keyword or column name)
+    Word(String),
+    /// Double-quoted identifier
+    QuotedIdentifier(String),
+    Number,
+    StringLiteral,
+    Operator,
+    OpenParen,
+    CloseParen,
+    Comma,
+}
+
+/// Split an expression into tokens, rejecting anything outside the safe subset
+fn tokenize(expr: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = expr.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        if c.is_ascii_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push(Token::Word(chars[start..i].iter().collect()));
+            continue;
+        }
+
+        if c.is_ascii_digit() {
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            tokens.push(Token::Number);
+            continue;
+        }
+
+        if c == '"' {
+            let start = i + 1;
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                i += 1;
+            }
+            if i >= chars.len() {
+                return Err("Unterminated quoted identifier".to_string());
+            }
+            tokens.push(Token::QuotedIdentifier(chars[start..i].iter().collect()));
+            i += 1;
+            continue;
+        }
+
+        if c == '\'' {
+            i += 1;
+            loop {
+                if i >= chars.len() {
+                    return Err("Unterminated string literal".to_string());
+                }
+                if chars[i] == '\'' {
+                    // A doubled quote is an escaped quote inside the literal
+                    if chars.get(i + 1) == Some(&'\'') {
+                        i += 2;
+                        continue;
+                    }
+                    i += 1;
+                    break;
+                }
+                i += 1;
+            }
+            tokens.push(Token::StringLiteral);
+            continue;
+        }
+
+        if (c == '-' && next == Some('-')) || (c == '/' && next == Some('*')) {
+            return Err("Comments are not allowed in expressions".to_string());
+        }
+
+        match (c, next) {
+            ('<', Some('=')) | ('>', Some('=')) | ('<', Some('>')) | ('!', Some('=')) => {
+                tokens.push(Token::Operator);
+                i += 2;
+                continue;
+            }
+            ('=' | '<' | '>' | '+' | '-' | '*' | '/' | '%', _) => tokens.push(Token::Operator),
+            ('(', _) => tokens.push(Token::OpenParen),
+            (')', _) => tokens.push(Token::CloseParen),
+            (',', _) => tokens.push(Token::Comma),
+            _ => return Err(format!("Unexpected character '{}' in expression", c)),
+        }
+        i += 1;
+    }
+
+    Ok(tokens)
+}
+
+/// Validate a boolean predicate (e.g. a partial index `WHERE` clause)
+///
+/// The predicate may only contain references to `known_columns`, string and
+/// numeric literals, comparison/arithmetic operators, parentheses, and the
+/// keywords AND, OR, NOT, IS, NULL, TRUE, FALSE, IN, BETWEEN, LIKE and ILIKE.
+/// Function calls, casts, comments, and statement separators are rejected.
+///
+/// # Example
+/// ```
+/// use runtara_object_store::sql::expression::validate_predicate;
+///
+/// assert!(validate_predicate("status = 'active'", &["status"]).is_ok());
+/// assert!(validate_predicate("status = 'active'; DROP TABLE x", &["status"]).is_err());
+/// assert!(validate_predicate("other = 1", &["status"]).is_err());
+/// ```
+pub fn validate_predicate(predicate: &str, known_columns: &[&str]) -> Result<(), String> {
+    let tokens = tokenize(predicate)?;
+    if tokens.is_empty() {
+        return Err("Predicate cannot be empty".to_string());
+    }
+
+    let mut depth = 0i32;
+    for token in &tokens {
+        match token {
+            Token::Word(word) => {
+                if PREDICATE_KEYWORDS.contains(&word.to_uppercase().as_str()) {
+                    continue;
+                }
+                // Unquoted identifiers fold to lowercase in PostgreSQL
+                let folded = word.to_lowercase();
+                if !known_columns.contains(&folded.as_str()) {
+                    return Err(format!("Unknown column '{}' in predicate", word));
+                }
+            }
+            Token::QuotedIdentifier(name) => {
+                if !known_columns.contains(&name.as_str()) {
+                    return Err(format!("Unknown column '{}' in predicate", name));
+                }
+            }
+            Token::OpenParen => depth += 1,
+            Token::CloseParen => {
+                depth -= 1;
+                if depth < 0 {
+                    return Err("Unbalanced parentheses in predicate".to_string());
+                }
+            }
+            Token::Number | Token::StringLiteral | Token::Operator | Token::Comma => {}
+        }
+    }
+
+    if depth != 0 {
+        return Err("Unbalanced parentheses in predicate".to_string());
+    }
+
+    Ok(())
+}
+
+/// Validate an arithmetic expression for a computed field (e.g. `price * 1.2`)
+///
+/// The expression may only contain references to `known_columns`, numeric
+/// literals, the operators `+ - * / %`, and parentheses. Keywords, string
+/// literals, function calls, casts, comments, and statement separators are
+/// rejected.
+///
+/// # Example
+/// ```
+/// use runtara_object_store::sql::expression::validate_computed_expression;
+///
+/// assert!(validate_computed_expression("(price - discount) * 1.2", &["price", "discount"]).is_ok());
+/// assert!(validate_computed_expression("price; DROP TABLE x", &["price"]).is_err());
+/// assert!(validate_computed_expression("upper(name)", &["name"]).is_err());
+/// ```
+pub fn validate_computed_expression(expr: &str, known_columns: &[&str]) -> Result<(), String> {
+    let tokens = tokenize(expr)?;
+    if tokens.is_empty() {
+        return Err("Expression cannot be empty".to_string());
+    }
+
+    // Only arithmetic operators; comparisons belong in conditions
+    if let Some(c) = expr.chars().find(|c| matches!(c, '=' | '<' | '>' | '!')) {
+        return Err(format!("Operator '{}' is not allowed in expression", c));
+    }
+
+    let mut depth = 0i32;
+    for token in &tokens {
+        match token {
+            Token::Word(word) => {
+                // Unquoted identifiers fold to lowercase in PostgreSQL
+                let folded = word.to_lowercase();
+                if !known_columns.contains(&folded.as_str()) {
+                    return Err(format!("Unknown column '{}' in expression", word));
+                }
+            }
+            Token::QuotedIdentifier(name) => {
+                if !known_columns.contains(&name.as_str()) {
+                    return Err(format!("Unknown column '{}' in expression", name));
+                }
+            }
+            Token::OpenParen => depth += 1,
+            Token::CloseParen => {
+                depth -= 1;
+                if depth < 0 {
+                    return Err("Unbalanced parentheses in expression".to_string());
+                }
+            }
+            Token::StringLiteral => {
+                return Err("String literals are not allowed in expression".to_string());
+            }
+            Token::Comma => return Err("Commas are not allowed in expression".to_string()),
+            Token::Number | Token::Operator => {}
+        }
+    }
+
+    if depth != 0 {
+        return Err("Unbalanced parentheses in expression".to_string());
+    }
+
+    Ok(())
+}
+
+/// Default functions permitted for a column type
+///
+/// Entries are uppercase with no whitespace, as produced by normalizing the
+/// default expression.
+fn allowed_default_functions(column_type: &ColumnType) -> &'static [&'static str] {
+    match column_type {
+        ColumnType::Timestamp { .. } => &["NOW()", "CURRENT_TIMESTAMP"],
+        ColumnType::String => &["GEN_RANDOM_UUID()"],
+        _ => &[],
+    }
+}
+
+/// Parse a single-quoted SQL string literal, returning its unescaped content
+fn parse_string_literal(expr: &str) -> Option<String> {
+    let inner = expr.strip_prefix('\'')?.strip_suffix('\'')?;
+    let mut content = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            // Inside the literal a quote must be doubled
+            if chars.next() != Some('\'') {
+                return None;
+            }
+        }
+        content.push(c);
+    }
+    Some(content)
+}
+
+/// Validate a column default value against the column's type
+///
+/// Accepts `NULL`, a literal the column type would accept as a value (numbers,
+/// `TRUE`/`FALSE`, single-quoted strings), or a function from the per-type
+/// whitelist: `NOW()` and `CURRENT_TIMESTAMP` for timestamps and
+/// `gen_random_uuid()` for strings. JSON defaults must be a quoted JSON
+/// document. Anything else is rejected.
+///
+/// # Example
+/// ```
+/// use runtara_object_store::sql::expression::validate_default_value;
+/// use runtara_object_store::ColumnType;
+///
+/// assert!(validate_default_value("NOW()", &ColumnType::timestamp()).is_ok());
+/// assert!(validate_default_value("NOW()", &ColumnType::Integer).is_err());
+/// assert!(validate_default_value("0", &ColumnType::timestamp()).is_err());
+/// ```
+pub fn validate_default_value(default: &str, column_type: &ColumnType) -> Result<(), String> {
+    let expr = default.trim();
+    if expr.is_empty() {
+        return Err("Default value cannot be empty".to_string());
+    }
+
+    if expr.eq_ignore_ascii_case("NULL") {
+        return Ok(());
+    }
+
+    let value = if let Some(content) = parse_string_literal(expr) {
+        if matches!(column_type, ColumnType::Json) {
+            serde_json::from_str::<serde_json::Value>(&content)
+                .map_err(|e| format!("Default value is not valid JSON: {}", e))?;
+        }
+        serde_json::Value::String(content)
+    } else if expr.eq_ignore_ascii_case("TRUE") || expr.eq_ignore_ascii_case("FALSE") {
+        serde_json::Value::Bool(expr.eq_ignore_ascii_case("TRUE"))
+    } else if let Ok(number) = serde_json::from_str::<serde_json::Number>(expr) {
+        serde_json::Value::Number(number)
+    } else {
+        let normalized: String = expr
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_uppercase();
+        if allowed_default_functions(column_type).contains(&normalized.as_str()) {
+            return Ok(());
+        }
+        return Err(format!(
+            "Default '{}' is not allowed for {:?} columns",
+            expr, column_type
+        ));
+    };
+
+    match column_type {
+        // JSON defaults must be a quoted document (checked above)
+        ColumnType::Json if !value.is_string() => {
+            Err("JSON defaults must be a quoted JSON document".to_string())
+        }
+        // Point defaults have no literal form; only NULL is accepted
+        #[cfg(feature = "postgis")]
+        ColumnType::Point => Err("Point columns only accept a NULL default".to_string()),
+        _ => column_type
+            .validate_value(&value)
+            .map_err(|e| format!("Invalid default value '{}': {}", expr, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLUMNS: &[&str] = &["status", "price", "created_at"];
+
+    #[test]
+    fn test_predicate_simple_comparison() {
+        assert!(validate_predicate("status = 'active'", COLUMNS).is_ok());
+        assert!(validate_predicate("price >= 10.5", COLUMNS).is_ok());
+        assert!(validate_predicate("\"status\" <> 'archived'", COLUMNS).is_ok());
+    }
+
+    #[test]
+    fn test_predicate_logical_operators() {
+        assert!(
+            validate_predicate(
+                "(status = 'active' OR status = 'pending') AND price IS NOT NULL",
+                COLUMNS
+            )
+            .is_ok()
+        );
+        assert!(validate_predicate("status IN ('a', 'b')", COLUMNS).is_ok());
+    }
+
+    #[test]
+    fn test_predicate_escaped_quote_in_literal() {
+        assert!(validate_predicate("status = 'it''s'", COLUMNS).is_ok());
+    }
+
+    #[test]
+    fn test_predicate_unknown_column() {
+        let result = validate_predicate("owner = 'me'", COLUMNS);
+        assert!(result.unwrap_err().contains("Unknown column 'owner'"));
+    }
+
+    #[test]
+    fn test_predicate_rejects_function_calls() {
+        assert!(validate_predicate("lower(status) = 'a'", COLUMNS).is_err());
+    }
+
+    #[test]
+    fn test_predicate_rejects_injection() {
+        assert!(validate_predicate("status = 'a'; DROP TABLE users", COLUMNS).is_err());
+        assert!(validate_predicate("status = 'a' -- comment", COLUMNS).is_err());
+        assert!(validate_predicate("status = 'a' /* c */", COLUMNS).is_err());
+        assert!(validate_predicate("status::text = 'a'", COLUMNS).is_err());
+    }
+
+    #[test]
+    fn test_predicate_rejects_unterminated_literal() {
+        assert!(validate_predicate("status = 'active", COLUMNS).is_err());
+    }
+
+    #[test]
+    fn test_predicate_rejects_unbalanced_parentheses() {
+        assert!(validate_predicate("(status = 'a'", COLUMNS).is_err());
+        assert!(validate_predicate("status = 'a')", COLUMNS).is_err());
+    }
+
+    #[test]
+    fn test_predicate_rejects_empty() {
+        assert!(validate_predicate("   ", COLUMNS).is_err());
+    }
+
+    #[test]
+    fn test_default_value_type_appropriate() {
+        assert!(validate_default_value("NOW()", &ColumnType::timestamp()).is_ok());
+        assert!(validate_default_value("current_timestamp", &ColumnType::timestamp()).is_ok());
+        assert!(validate_default_value("'2024-01-01T00:00:00Z'", &ColumnType::timestamp()).is_ok());
+        assert!(validate_default_value("gen_random_uuid()", &ColumnType::String).is_ok());
+        assert!(validate_default_value("0", &ColumnType::Integer).is_ok());
+        assert!(validate_default_value("-5", &ColumnType::Integer).is_ok());
+        assert!(validate_default_value("0.00", &ColumnType::decimal(10, 2)).is_ok());
+        assert!(validate_default_value("TRUE", &ColumnType::Boolean).is_ok());
+        assert!(validate_default_value("'it''s'", &ColumnType::String).is_ok());
+        assert!(validate_default_value("'{\"a\": 1}'", &ColumnType::Json).is_ok());
+        assert!(validate_default_value("NULL", &ColumnType::Integer).is_ok());
+    }
+
+    #[test]
+    fn test_default_value_type_inappropriate() {
+        assert!(validate_default_value("NOW()", &ColumnType::Integer).is_err());
+        assert!(validate_default_value("0", &ColumnType::timestamp()).is_err());
+        assert!(validate_default_value("gen_random_uuid()", &ColumnType::Integer).is_err());
+        assert!(validate_default_value("1.5", &ColumnType::Integer).is_err());
+        assert!(validate_default_value("'abc'", &ColumnType::Integer).is_err());
+        assert!(validate_default_value("0", &ColumnType::String).is_err());
+        assert!(validate_default_value("'{oops'", &ColumnType::Json).is_err());
+        assert!(validate_default_value("1", &ColumnType::Json).is_err());
+
+        let status = ColumnType::Enum {
+            values: vec!["active".to_string()],
+        };
+        assert!(validate_default_value("'active'", &status).is_ok());
+        assert!(validate_default_value("'archived'", &status).is_err());
+    }
+
+    #[test]
+    fn test_default_value_rejects_unknown_expressions() {
+        assert!(validate_default_value("", &ColumnType::String).is_err());
+        assert!(validate_default_value("'a'); DROP TABLE x; --", &ColumnType::String).is_err());
+        assert!(validate_default_value("'a' || 'b'", &ColumnType::String).is_err());
+        assert!(validate_default_value("pg_sleep(1)", &ColumnType::String).is_err());
+        assert!(validate_default_value("'unterminated", &ColumnType::String).is_err());
+        assert!(validate_default_value("(SELECT 1)", &ColumnType::String).is_err());
+        assert!(validate_default_value("'a' || current_user", &ColumnType::String).is_err());
+        assert!(validate_default_value(r"'\'; DROP TABLE users;--'", &ColumnType::String).is_err());
+        assert!(validate_default_value("0; DROP TABLE users", &ColumnType::Integer).is_err());
+        assert!(
+            validate_default_value("'x'); DROP TABLE users;--", &ColumnType::timestamp()).is_err()
+        );
+    }
+
+    #[test]
+    fn test_computed_expression_arithmetic() {
+        assert!(validate_computed_expression("price * 1.2", COLUMNS).is_ok());
+        assert!(validate_computed_expression("(price - 2) / 3 % 4", COLUMNS).is_ok());
+        assert!(validate_computed_expression("\"price\" + -1", COLUMNS).is_ok());
+    }
+
+    #[test]
+    fn test_computed_expression_rejects_unsafe_input() {
+        assert!(validate_computed_expression("", COLUMNS).is_err());
+        assert!(validate_computed_expression("cost * 2", COLUMNS).is_err());
+        assert!(validate_computed_expression("price > 2", COLUMNS).is_err());
+        assert!(validate_computed_expression("status || 'x'", COLUMNS).is_err());
+        assert!(validate_computed_expression("price::text", COLUMNS).is_err());
+        assert!(validate_computed_expression("abs(price)", COLUMNS).is_err());
+        assert!(validate_computed_expression("price, status", COLUMNS).is_err());
+        assert!(validate_computed_expression("(price * 2", COLUMNS).is_err());
+        assert!(validate_computed_expression("price -- comment", COLUMNS).is_err());
+        assert!(validate_computed_expression("price); DROP TABLE x; --", COLUMNS).is_err());
+    }
+}
@@ -4,8 +4,17 @@
 
 pub mod condition;
 pub mod ddl;
+pub mod expression;
+pub mod query;
 pub mod sanitize;
 
-pub use condition::{build_condition_clause, build_order_by_clause};
+pub use condition::{
+    SqlParam, build_condition_clause, build_order_by_clause, build_order_by_clause_with_nulls,
+    build_stable_order_by_clause,
+};
 pub use ddl::DdlGenerator;
-pub use sanitize::{POSTGRES_RESERVED_WORDS, quote_identifier, validate_identifier};
+pub use query::{BuiltQuery, QueryBuilder};
+pub use sanitize::{
+    MAX_IDENTIFIER_BYTES, POSTGRES_RESERVED_WORDS, quote_identifier, safe_identifier,
+    validate_identifier, validate_identifier_length,
+};
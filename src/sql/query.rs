@@ -0,0 +1,471 @@
+//! SELECT query building for instance tables
+//!
+//! Assembles filtered, sorted, and paginated queries without executing them.
+
+use crate::config::StoreConfig;
+use crate::error::{ObjectStoreError, Result};
+use crate::instance::{Cursor, FilterRequest};
+use crate::schema::Schema;
+use crate::sql::condition::{
+    SqlParam, build_condition_clause, build_order_by_clause_with_nulls,
+    build_stable_order_by_clause,
+};
+use crate::sql::expression::validate_computed_expression;
+use crate::sql::sanitize::{quote_identifier, validate_identifier};
+use crate::types::ColumnDefinition;
+
+/// A generated SQL statement with its positional parameters
+///
+/// Parameters are bound in order (`$1`, `$2`, ...) with their own PostgreSQL
+/// types; the SQL casts them to the column types they are compared with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuiltQuery {
+    /// SQL statement with `$n` placeholders
+    pub sql: String,
+    /// Parameter values in placeholder order
+    pub params: Vec<SqlParam>,
+}
+
+/// Builder for SELECT and COUNT queries over a schema's table
+///
+/// Applies the soft-delete filter, the request's condition, sort order, and
+/// pagination the same way the store does.
+///
+/// # Example
+/// ```
+/// use runtara_object_store::sql::query::QueryBuilder;
+/// use runtara_object_store::{
+///     ColumnDefinition, ColumnType, Condition, FilterRequest, Schema, SqlParam, StoreConfig,
+/// };
+///
+/// let config = StoreConfig::builder("postgres://localhost/db").build();
+/// let schema = Schema::new(
+///     "1",
+///     "Products",
+///     "products",
+///     vec![ColumnDefinition::new("sku", ColumnType::String)],
+/// );
+/// let filter = FilterRequest {
+///     condition: Some(Condition::eq("sku", "A-1")),
+///     ..Default::default()
+/// };
+///
+/// let query = QueryBuilder::new(&schema, &config, &filter).build_select().unwrap();
+/// assert!(query.sql.starts_with("SELECT id, created_at, updated_at, \"sku\" FROM"));
+/// assert_eq!(
+///     query.params,
+///     vec![SqlParam::Text("A-1".to_string()), SqlParam::Int(100), SqlParam::Int(0)]
+/// );
+/// ```
+pub struct QueryBuilder<'a> {
+    schema: &'a Schema,
+    config: &'a StoreConfig,
+    filter: &'a FilterRequest,
+}
+
+impl<'a> QueryBuilder<'a> {
+    /// Create a query builder for a schema, store configuration, and filter
+    pub fn new(schema: &'a Schema, config: &'a StoreConfig, filter: &'a FilterRequest) -> Self {
+        Self {
+            schema,
+            config,
+            filter,
+        }
+    }
+
+    /// Select-list entries for the auto columns and every schema column
+    pub fn select_columns(&self) -> Vec<String> {
+        let mut columns = Vec::new();
+
+        if self.config.auto_columns.id {
+            columns.push("id".to_string());
+        }
+        if self.config.auto_columns.created_at {
+            columns.push("created_at".to_string());
+        }
+        if self.config.auto_columns.updated_at {
+            columns.push("updated_at".to_string());
+        }
+
+        for col in &self.schema.columns {
+            columns.push(column_select("", col, &col.name));
+        }
+
+        columns
+    }
+
+    /// Select-list entries for the filter's computed fields
+    ///
+    /// Each expression is validated against the schema and managed columns and
+    /// selected as `to_jsonb(expr) AS "alias"`. Aliases must be valid
+    /// identifiers that do not shadow a column or another computed field.
+    pub fn computed_columns(&self) -> Result<Vec<String>> {
+        let mut known: Vec<&str> = self
+            .schema
+            .columns
+            .iter()
+            .map(|col| col.name.as_str())
+            .collect();
+        known.extend(self.config.managed_column_names());
+
+        let mut aliases: Vec<&str> = Vec::new();
+        let mut columns = Vec::new();
+        for field in &self.filter.computed {
+            validate_identifier(&field.alias, &known).map_err(ObjectStoreError::validation)?;
+            if aliases.contains(&field.alias.as_str()) {
+                return Err(ObjectStoreError::validation(format!(
+                    "Computed field '{}' is defined more than once",
+                    field.alias
+                )));
+            }
+            validate_computed_expression(&field.expression, &known).map_err(|e| {
+                ObjectStoreError::validation(format!(
+                    "Invalid expression for computed field '{}': {}",
+                    field.alias, e
+                ))
+            })?;
+
+            aliases.push(&field.alias);
+            columns.push(format!(
+                "to_jsonb({}) AS {}",
+                field.expression,
+                quote_identifier(&field.alias)
+            ));
+        }
+
+        Ok(columns)
+    }
+
+    /// WHERE clause (without the keyword) including the soft-delete filter
+    pub fn where_clause(&self) -> Result<(String, Vec<SqlParam>)> {
+        let (condition_clause, params) = if let Some(condition) = &self.filter.condition {
+            let mut param_offset = 1;
+            build_condition_clause(condition, &mut param_offset, self.schema)
+                .map_err(ObjectStoreError::InvalidCondition)?
+        } else {
+            ("TRUE".to_string(), Vec::new())
+        };
+
+        let clause = if self.config.soft_delete {
+            format!("deleted = FALSE AND ({})", condition_clause)
+        } else {
+            format!("({})", condition_clause)
+        };
+
+        Ok((clause, params))
+    }
+
+    /// ORDER BY clause (without the keyword)
+    ///
+    /// Ends with `id` as a tiebreaker so OFFSET pagination is stable, unless
+    /// the filter declares a unique sort or the table has no `id` column.
+    pub fn order_by_clause(&self) -> Result<String> {
+        let filter = self.filter;
+        if self.config.auto_columns.id && !filter.unique_sort {
+            build_stable_order_by_clause(
+                &filter.sort_by,
+                &filter.sort_order,
+                self.schema,
+                "id",
+                self.config.nulls_ordering,
+            )
+        } else {
+            build_order_by_clause_with_nulls(
+                &filter.sort_by,
+                &filter.sort_order,
+                self.schema,
+                self.config.nulls_ordering,
+            )
+        }
+        .map_err(ObjectStoreError::validation)
+    }
+
+    /// Build a `SELECT COUNT(*)` over the filtered rows
+    pub fn build_count(&self) -> Result<BuiltQuery> {
+        let (where_clause, params) = self.where_clause()?;
+
+        Ok(BuiltQuery {
+            sql: format!(
+                "SELECT COUNT(*) FROM {} WHERE {}",
+                quote_identifier(&self.schema.table_name),
+                where_clause
+            ),
+            params,
+        })
+    }
+
+    /// Build the sorted, paginated SELECT over the filtered rows
+    ///
+    /// The limit and offset are the last two parameters.
+    pub fn build_select(&self) -> Result<BuiltQuery> {
+        let (where_clause, mut params) = self.where_clause()?;
+
+        let order_by_clause = self.order_by_clause()?;
+
+        let mut columns = self.select_columns();
+        columns.extend(self.computed_columns()?);
+
+        let sql = format!(
+            "SELECT {} FROM {} WHERE {} ORDER BY {} LIMIT ${}::bigint OFFSET ${}::bigint",
+            columns.join(", "),
+            quote_identifier(&self.schema.table_name),
+            where_clause,
+            order_by_clause,
+            params.len() + 1,
+            params.len() + 2
+        );
+        params.push(SqlParam::Int(self.filter.limit));
+        params.push(SqlParam::Int(self.filter.offset));
+
+        Ok(BuiltQuery { sql, params })
+    }
+
+    /// Build a keyset-paginated SELECT over the filtered rows
+    ///
+    /// Rows are ordered by `(created_at, id)` and start just after `after`;
+    /// the filter's sort and offset are ignored. One row more than the limit
+    /// is fetched so the caller can tell whether another page follows.
+    pub fn build_select_after(&self, after: Option<&Cursor>) -> Result<BuiltQuery> {
+        let (mut where_clause, mut params) = self.where_clause()?;
+
+        if let Some(cursor) = after {
+            where_clause = format!(
+                "{} AND (created_at, id) > (${}::timestamptz, ${}::text)",
+                where_clause,
+                params.len() + 1,
+                params.len() + 2
+            );
+            params.push(SqlParam::Text(cursor.created_at_rfc3339()));
+            params.push(SqlParam::Text(cursor.id.clone()));
+        }
+
+        let mut columns = self.select_columns();
+        columns.extend(self.computed_columns()?);
+
+        let sql = format!(
+            "SELECT {} FROM {} WHERE {} ORDER BY created_at ASC, id ASC LIMIT ${}::bigint",
+            columns.join(", "),
+            quote_identifier(&self.schema.table_name),
+            where_clause,
+            params.len() + 1
+        );
+        params.push(SqlParam::Int(self.filter.limit + 1));
+
+        Ok(BuiltQuery { sql, params })
+    }
+}
+
+/// Select-list entry for a data column
+///
+/// Columns read through an expression (see
+/// [`ColumnType::select_expression`](crate::types::ColumnType::select_expression))
+/// are aliased so they can still be fetched by name.
+pub(crate) fn column_select(qualifier: &str, col: &ColumnDefinition, alias: &str) -> String {
+    let column_ref = format!("{}{}", qualifier, quote_identifier(&col.name));
+    let expr = col.column_type.select_expression(&column_ref);
+    if expr == column_ref && alias == col.name {
+        expr
+    } else {
+        format!("{} AS {}", expr, quote_identifier(alias))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instance::Condition;
+    use crate::types::ColumnType;
+
+    fn make_schema() -> Schema {
+        Schema::new(
+            "schema-1",
+            "Products",
+            "products",
+            vec![
+                ColumnDefinition::new("sku", ColumnType::String),
+                ColumnDefinition::new("price", ColumnType::decimal(10, 2)),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_build_select_with_condition_and_sort() {
+        let schema = make_schema();
+        let config = StoreConfig::builder("postgres://localhost/test").build();
+        let filter = FilterRequest {
+            offset: 20,
+            limit: 10,
+            condition: Some(Condition::gt("price", 5)),
+            sort_by: Some(vec!["price".to_string()]),
+            sort_order: Some(vec!["desc".to_string()]),
+            computed: Vec::new(),
+            unique_sort: false,
+        };
+
+        let query = QueryBuilder::new(&schema, &config, &filter)
+            .build_select()
+            .unwrap();
+
+        assert_eq!(
+            query.sql,
+            "SELECT id, created_at, updated_at, \"sku\", \"price\" FROM \"products\" \
+             WHERE deleted = FALSE AND (\"price\"::numeric > $1::numeric) \
+             ORDER BY \"price\" DESC, \"id\" ASC LIMIT $2::bigint OFFSET $3::bigint"
+        );
+        assert_eq!(
+            query.params,
+            vec![SqlParam::Int(5), SqlParam::Int(10), SqlParam::Int(20)]
+        );
+    }
+
+    #[test]
+    fn test_build_select_after_cursor() {
+        let schema = make_schema();
+        let config = StoreConfig::builder("postgres://localhost/test").build();
+        let filter = FilterRequest {
+            limit: 10,
+            offset: 30,
+            condition: Some(Condition::gt("price", 5)),
+            ..Default::default()
+        };
+        let builder = QueryBuilder::new(&schema, &config, &filter);
+
+        let query = builder.build_select_after(None).unwrap();
+        assert_eq!(
+            query.sql,
+            "SELECT id, created_at, updated_at, \"sku\", \"price\" FROM \"products\" \
+             WHERE deleted = FALSE AND (\"price\"::numeric > $1::numeric) \
+             ORDER BY created_at ASC, id ASC LIMIT $2::bigint"
+        );
+        assert_eq!(query.params, vec![SqlParam::Int(5), SqlParam::Int(11)]);
+
+        let created_at = chrono::DateTime::parse_from_rfc3339("2024-05-01T12:30:45.5Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let cursor = Cursor::new(created_at, "abc");
+        let query = builder.build_select_after(Some(&cursor)).unwrap();
+        assert_eq!(
+            query.sql,
+            "SELECT id, created_at, updated_at, \"sku\", \"price\" FROM \"products\" \
+             WHERE deleted = FALSE AND (\"price\"::numeric > $1::numeric) \
+             AND (created_at, id) > ($2::timestamptz, $3::text) \
+             ORDER BY created_at ASC, id ASC LIMIT $4::bigint"
+        );
+        assert_eq!(
+            query.params,
+            vec![
+                SqlParam::Int(5),
+                SqlParam::Text("2024-05-01T12:30:45.500000Z".to_string()),
+                SqlParam::Text("abc".to_string()),
+                SqlParam::Int(11),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_order_by_tiebreaker_opt_out() {
+        let schema = make_schema();
+        let config = StoreConfig::builder("postgres://localhost/test").build();
+
+        let filter = FilterRequest {
+            sort_by: Some(vec!["sku".to_string()]),
+            ..Default::default()
+        };
+        let builder = QueryBuilder::new(&schema, &config, &filter);
+        assert_eq!(
+            builder.order_by_clause().unwrap(),
+            "\"sku\" ASC, \"id\" ASC"
+        );
+
+        let filter = filter.clone().with_unique_sort();
+        let builder = QueryBuilder::new(&schema, &config, &filter);
+        assert_eq!(builder.order_by_clause().unwrap(), "\"sku\" ASC");
+
+        let config = StoreConfig::builder("postgres://localhost/test")
+            .without_auto_columns()
+            .build();
+        let filter = FilterRequest::default();
+        let builder = QueryBuilder::new(&schema, &config, &filter);
+        assert_eq!(builder.order_by_clause().unwrap(), "created_at ASC");
+    }
+
+    #[test]
+    fn test_build_count_without_condition() {
+        let schema = make_schema();
+        let config = StoreConfig::builder("postgres://localhost/test")
+            .soft_delete(false)
+            .build();
+        let filter = FilterRequest::default();
+
+        let query = QueryBuilder::new(&schema, &config, &filter)
+            .build_count()
+            .unwrap();
+
+        assert_eq!(query.sql, "SELECT COUNT(*) FROM \"products\" WHERE (TRUE)");
+        assert!(query.params.is_empty());
+    }
+
+    #[test]
+    fn test_select_columns_respect_auto_columns() {
+        let schema = make_schema();
+        let config = StoreConfig::builder("postgres://localhost/test")
+            .without_auto_columns()
+            .build();
+        let filter = FilterRequest::default();
+
+        let columns = QueryBuilder::new(&schema, &config, &filter).select_columns();
+
+        assert_eq!(columns, vec!["\"sku\"", "\"price\""]);
+    }
+
+    #[test]
+    fn test_build_select_with_computed_field() {
+        let schema = make_schema();
+        let config = StoreConfig::builder("postgres://localhost/test").build();
+        let filter = FilterRequest::default().with_computed("gross", "price * 1.2");
+
+        let query = QueryBuilder::new(&schema, &config, &filter)
+            .build_select()
+            .unwrap();
+
+        assert!(query.sql.starts_with(
+            "SELECT id, created_at, updated_at, \"sku\", \"price\", \
+             to_jsonb(price * 1.2) AS \"gross\" FROM \"products\""
+        ));
+    }
+
+    #[test]
+    fn test_build_select_rejects_invalid_computed_field() {
+        let schema = make_schema();
+        let config = StoreConfig::builder("postgres://localhost/test").build();
+
+        for filter in [
+            FilterRequest::default().with_computed("gross", "price; DROP TABLE products"),
+            FilterRequest::default().with_computed("gross", "cost * 2"),
+            FilterRequest::default().with_computed("sku", "price * 2"),
+            FilterRequest::default()
+                .with_computed("gross", "price * 2")
+                .with_computed("gross", "price * 3"),
+        ] {
+            let result = QueryBuilder::new(&schema, &config, &filter).build_select();
+            assert!(matches!(result, Err(ObjectStoreError::Validation(_))));
+        }
+    }
+
+    #[test]
+    fn test_build_select_invalid_condition() {
+        let schema = make_schema();
+        let config = StoreConfig::builder("postgres://localhost/test").build();
+        let filter = FilterRequest {
+            condition: Some(Condition {
+                op: "UNKNOWN".to_string(),
+                arguments: None,
+            }),
+            ..Default::default()
+        };
+
+        let result = QueryBuilder::new(&schema, &config, &filter).build_select();
+
+        assert!(matches!(result, Err(ObjectStoreError::InvalidCondition(_))));
+    }
+}
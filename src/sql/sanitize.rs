@@ -5,6 +5,11 @@
 use regex::Regex;
 
 /// PostgreSQL reserved keywords that cannot be used as unquoted identifiers
+///
+/// Covers both keyword categories PostgreSQL refuses as column or table names:
+/// fully reserved words and those reserved except as function or type names
+/// (`AUTHORIZATION`, `JOIN`, `LIKE`, ...). Entries are uppercase; callers
+/// compare case-insensitively.
 pub const POSTGRES_RESERVED_WORDS: &[&str] = &[
     "ALL",
     "ANALYSE",
@@ -15,17 +20,23 @@ pub const POSTGRES_RESERVED_WORDS: &[&str] = &[
     "AS",
     "ASC",
     "ASYMMETRIC",
+    "AUTHORIZATION",
+    "BINARY",
     "BOTH",
     "CASE",
     "CAST",
     "CHECK",
     "COLLATE",
+    "COLLATION",
     "COLUMN",
+    "CONCURRENTLY",
     "CONSTRAINT",
     "CREATE",
+    "CROSS",
     "CURRENT_CATALOG",
     "CURRENT_DATE",
     "CURRENT_ROLE",
+    "CURRENT_SCHEMA",
     "CURRENT_TIME",
     "CURRENT_TIMESTAMP",
     "CURRENT_USER",
@@ -41,35 +52,52 @@ pub const POSTGRES_RESERVED_WORDS: &[&str] = &[
     "FETCH",
     "FOR",
     "FOREIGN",
+    "FREEZE",
     "FROM",
+    "FULL",
     "GRANT",
     "GROUP",
     "HAVING",
+    "ILIKE",
     "IN",
     "INITIALLY",
+    "INNER",
     "INTERSECT",
     "INTO",
+    "IS",
+    "ISNULL",
+    "JOIN",
     "LATERAL",
     "LEADING",
+    "LEFT",
+    "LIKE",
     "LIMIT",
     "LOCALTIME",
     "LOCALTIMESTAMP",
+    "NATURAL",
     "NOT",
+    "NOTNULL",
     "NULL",
     "OFFSET",
     "ON",
     "ONLY",
     "OR",
     "ORDER",
+    "OUTER",
+    "OVERLAPS",
     "PLACING",
     "PRIMARY",
     "REFERENCES",
     "RETURNING",
+    "RIGHT",
     "SELECT",
     "SESSION_USER",
+    "SIMILAR",
     "SOME",
     "SYMMETRIC",
+    "SYSTEM_USER",
     "TABLE",
+    "TABLESAMPLE",
     "THEN",
     "TO",
     "TRAILING",
@@ -79,6 +107,7 @@ pub const POSTGRES_RESERVED_WORDS: &[&str] = &[
     "USER",
     "USING",
     "VARIADIC",
+    "VERBOSE",
     "WHEN",
     "WHERE",
     "WINDOW",
@@ -106,12 +135,80 @@ pub fn quote_identifier(identifier: &str) -> String {
     format!("\"{}\"", escaped)
 }
 
+/// Maximum identifier length in bytes accepted by PostgreSQL (NAMEDATALEN - 1)
+pub const MAX_IDENTIFIER_BYTES: usize = 63;
+
+/// Fit a crate-generated identifier within PostgreSQL's byte limit
+///
+/// Names that already fit are returned unchanged. Longer names are truncated
+/// on a character boundary and suffixed with a hash of the full name, so the
+/// result is deterministic and distinct names stay distinct. Use this only for
+/// names the crate derives (index names and the like); user-supplied names
+/// should be rejected with [`validate_identifier_length`] instead.
+///
+/// # Example
+/// ```
+/// use runtara_object_store::sql::sanitize::{MAX_IDENTIFIER_BYTES, safe_identifier};
+///
+/// assert_eq!(safe_identifier("products_sku"), "products_sku");
+///
+/// let long = format!("{}_idx", "a".repeat(80));
+/// let safe = safe_identifier(&long);
+/// assert!(safe.len() <= MAX_IDENTIFIER_BYTES);
+/// assert_eq!(safe, safe_identifier(&long));
+/// ```
+pub fn safe_identifier(name: &str) -> String {
+    if name.len() <= MAX_IDENTIFIER_BYTES {
+        return name.to_string();
+    }
+
+    let suffix = format!("_{:08x}", fnv1a_32(name.as_bytes()));
+    let mut end = MAX_IDENTIFIER_BYTES - suffix.len();
+    while !name.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    format!("{}{}", &name[..end], suffix)
+}
+
+/// 32-bit FNV-1a hash, stable across platforms and releases
+fn fnv1a_32(bytes: &[u8]) -> u32 {
+    let mut hash: u32 = 0x811c_9dc5;
+    for byte in bytes {
+        hash ^= u32::from(*byte);
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    hash
+}
+
+/// Reject identifiers that PostgreSQL would silently truncate
+///
+/// # Example
+/// ```
+/// use runtara_object_store::sql::sanitize::validate_identifier_length;
+///
+/// assert!(validate_identifier_length("products").is_ok());
+/// assert!(validate_identifier_length(&"a".repeat(64)).is_err());
+/// ```
+pub fn validate_identifier_length(name: &str) -> Result<(), String> {
+    if name.len() > MAX_IDENTIFIER_BYTES {
+        return Err(format!(
+            "Identifier '{}' is {} bytes long; the maximum is {} bytes.",
+            name,
+            name.len(),
+            MAX_IDENTIFIER_BYTES
+        ));
+    }
+    Ok(())
+}
+
 /// Validate a table or column name
 ///
 /// Rules:
 /// - Must start with a letter (a-z)
 /// - Can only contain lowercase letters, numbers, and underscores
 /// - Cannot be a PostgreSQL reserved word
+/// - Cannot be longer than [`MAX_IDENTIFIER_BYTES`] bytes
 /// - Cannot be an auto-managed column name (for columns)
 ///
 /// # Arguments
@@ -135,6 +232,8 @@ pub fn validate_identifier(name: &str, reserved_columns: &[&str]) -> Result<(),
         return Err("Identifier cannot be empty".to_string());
     }
 
+    validate_identifier_length(name)?;
+
     // Check pattern: must start with letter, only lowercase alphanumeric + underscore
     let re = Regex::new(r"^[a-z][a-z0-9_]*$").unwrap();
     if !re.is_match(name) {
@@ -363,6 +462,50 @@ mod tests {
         assert!(result.unwrap_err().contains("reserved"));
     }
 
+    #[test]
+    fn test_validate_identifier_too_long() {
+        let name = "a".repeat(MAX_IDENTIFIER_BYTES + 1);
+        let result = validate_identifier(&name, &[]);
+        assert!(result.unwrap_err().contains("maximum is 63 bytes"));
+        assert!(validate_identifier(&"a".repeat(MAX_IDENTIFIER_BYTES), &[]).is_ok());
+    }
+
+    // =========================================================================
+    // safe_identifier Tests
+    // =========================================================================
+
+    #[test]
+    fn test_safe_identifier_short_name_unchanged() {
+        assert_eq!(safe_identifier("products_sku_idx"), "products_sku_idx");
+        let exact = "a".repeat(MAX_IDENTIFIER_BYTES);
+        assert_eq!(safe_identifier(&exact), exact);
+    }
+
+    #[test]
+    fn test_safe_identifier_truncates_long_name() {
+        let long = format!("{}_sku_idx", "products".repeat(10));
+        let safe = safe_identifier(&long);
+        assert_eq!(safe.len(), MAX_IDENTIFIER_BYTES);
+        assert!(safe.starts_with("productsproducts"));
+        assert_eq!(safe, safe_identifier(&long));
+    }
+
+    #[test]
+    fn test_safe_identifier_distinguishes_shared_prefix() {
+        let prefix = "x".repeat(70);
+        let a = safe_identifier(&format!("{}_a", prefix));
+        let b = safe_identifier(&format!("{}_b", prefix));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_safe_identifier_respects_char_boundaries() {
+        let long = "é".repeat(40);
+        let safe = safe_identifier(&long);
+        assert!(safe.len() <= MAX_IDENTIFIER_BYTES);
+        assert!(safe.starts_with("é"));
+    }
+
     // =========================================================================
     // POSTGRES_RESERVED_WORDS Tests
     // =========================================================================
@@ -376,6 +519,28 @@ mod tests {
         assert!(POSTGRES_RESERVED_WORDS.contains(&"CREATE"));
     }
 
+    #[test]
+    fn test_validate_identifier_rejects_type_function_keywords() {
+        for name in ["authorization", "binary", "concurrently", "freeze"] {
+            let result = validate_identifier(name, &[]);
+            assert!(
+                result.unwrap_err().contains("reserved keyword"),
+                "{} should be rejected",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_reserved_words_are_uppercase_and_sorted() {
+        assert!(
+            POSTGRES_RESERVED_WORDS
+                .iter()
+                .all(|word| *word == word.to_uppercase())
+        );
+        assert!(POSTGRES_RESERVED_WORDS.windows(2).all(|w| w[0] < w[1]));
+    }
+
     #[test]
     fn test_reserved_words_not_empty() {
         // POSTGRES_RESERVED_WORDS is a const, so we just validate it has sufficient entries
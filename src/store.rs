@@ -3,16 +3,40 @@
 //! This module provides the main `ObjectStore` struct that manages dynamic schemas
 //! and their instances in a PostgreSQL database.
 
-use sqlx::{PgPool, Row};
+use std::collections::{HashMap, HashSet};
 
-use crate::config::StoreConfig;
+use sqlx::{PgConnection, PgPool, Row};
+
+use crate::config::{DriftCheck, StoreConfig, UnknownEnumValues};
 use crate::error::{ObjectStoreError, Result};
-use crate::instance::{Condition, FilterRequest, Instance, SimpleFilter};
-use crate::schema::{CreateSchemaRequest, Schema, UpdateSchemaRequest};
-use crate::sql::condition::{build_condition_clause, build_order_by_clause};
+use crate::instance::{
+    BulkCreateResult, Condition, Cursor, FilterRequest, Instance, Page, SimpleFilter,
+};
+use crate::schema::{
+    ColumnTypeMismatch, CreateSchemaRequest, FieldUpdate, Schema, SchemaDescription, SchemaDrift,
+    SchemaUpdatePreview, UpdateSchemaRequest,
+};
+use crate::sql::condition::{SqlParam, build_condition_clause};
 use crate::sql::ddl::DdlGenerator;
-use crate::sql::sanitize::quote_identifier;
-use crate::types::{ColumnDefinition, ColumnType};
+use crate::sql::expression::validate_default_value;
+use crate::sql::query::{QueryBuilder, column_select};
+use crate::sql::sanitize::{quote_identifier, validate_identifier_length};
+use crate::types::{ColumnDefinition, ColumnType, parse_naive_timestamp};
+
+/// An instance that passed bulk validation and is ready to insert
+struct ValidatedInstance {
+    id: String,
+    properties: serde_json::Map<String, serde_json::Value>,
+    created_at: Option<chrono::DateTime<chrono::Utc>>,
+    updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// A validated condition-based UPDATE with its parameters in bind order
+struct BulkUpdate<'a> {
+    sql: String,
+    set_values: Vec<(&'a ColumnDefinition, &'a serde_json::Value)>,
+    condition_params: Vec<SqlParam>,
+}
 
 /// Schema-driven dynamic PostgreSQL object store
 ///
@@ -63,7 +87,10 @@ impl ObjectStore {
         &self.config
     }
 
-    /// Ensures the metadata table exists
+    /// Ensures the metadata table exists and has a compatible shape
+    ///
+    /// A pre-existing table missing the `deleted` column is migrated when schema
+    /// soft delete is enabled; any other missing column is reported as an error.
     async fn ensure_metadata_table(&self) -> Result<()> {
         let metadata_table = quote_identifier(&self.config.metadata_table);
 
@@ -76,12 +103,15 @@ impl ObjectStore {
                 table_name VARCHAR(255) UNIQUE NOT NULL,
                 columns JSONB NOT NULL,
                 indexes JSONB,
+                default_index BOOLEAN NOT NULL DEFAULT TRUE,
+                storage_params JSONB,
+                tablespace TEXT,
                 created_at TIMESTAMPTZ DEFAULT NOW(),
                 updated_at TIMESTAMPTZ DEFAULT NOW(){}
             )
             "#,
             metadata_table,
-            if self.config.soft_delete {
+            if self.config.soft_delete_schemas() {
                 ",\n                deleted BOOLEAN DEFAULT FALSE"
             } else {
                 ""
@@ -90,6 +120,79 @@ impl ObjectStore {
 
         sqlx::query(&create_sql).execute(&self.pool).await?;
 
+        self.check_metadata_table_shape().await
+    }
+
+    /// Verify an existing metadata table has every column the store relies on
+    async fn check_metadata_table_shape(&self) -> Result<()> {
+        const REQUIRED_COLUMNS: &[&str] = &[
+            "id",
+            "name",
+            "description",
+            "table_name",
+            "columns",
+            "indexes",
+            "created_at",
+            "updated_at",
+        ];
+
+        let existing: Vec<String> = sqlx::query_scalar(
+            "SELECT column_name::text FROM information_schema.columns \
+             WHERE table_schema = current_schema() AND table_name = $1",
+        )
+        .bind(&self.config.metadata_table)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let missing: Vec<&str> = REQUIRED_COLUMNS
+            .iter()
+            .copied()
+            .filter(|col| !existing.iter().any(|e| e == col))
+            .collect();
+        if !missing.is_empty() {
+            return Err(ObjectStoreError::database(format!(
+                "Metadata table '{}' is incompatible: missing columns {}",
+                self.config.metadata_table,
+                missing.join(", ")
+            )));
+        }
+
+        // Tables from before per-schema default indexes always had one
+        if !existing.iter().any(|e| e == "default_index") {
+            let alter_sql = format!(
+                "ALTER TABLE {} ADD COLUMN IF NOT EXISTS default_index BOOLEAN NOT NULL DEFAULT TRUE",
+                quote_identifier(&self.config.metadata_table)
+            );
+            sqlx::query(&alter_sql).execute(&self.pool).await?;
+        }
+
+        // Tables from before storage parameters have none recorded
+        if !existing.iter().any(|e| e == "storage_params") {
+            let alter_sql = format!(
+                "ALTER TABLE {} ADD COLUMN IF NOT EXISTS storage_params JSONB",
+                quote_identifier(&self.config.metadata_table)
+            );
+            sqlx::query(&alter_sql).execute(&self.pool).await?;
+        }
+
+        // ...and no tablespace
+        if !existing.iter().any(|e| e == "tablespace") {
+            let alter_sql = format!(
+                "ALTER TABLE {} ADD COLUMN IF NOT EXISTS tablespace TEXT",
+                quote_identifier(&self.config.metadata_table)
+            );
+            sqlx::query(&alter_sql).execute(&self.pool).await?;
+        }
+
+        // Tables created without soft delete can be upgraded in place
+        if self.config.soft_delete_schemas() && !existing.iter().any(|e| e == "deleted") {
+            let alter_sql = format!(
+                "ALTER TABLE {} ADD COLUMN IF NOT EXISTS deleted BOOLEAN DEFAULT FALSE",
+                quote_identifier(&self.config.metadata_table)
+            );
+            sqlx::query(&alter_sql).execute(&self.pool).await?;
+        }
+
         Ok(())
     }
 
@@ -99,11 +202,16 @@ impl ObjectStore {
 
     /// Create a new schema
     ///
+    /// The request is checked with [`CreateSchemaRequest::validate`] first.
     /// This will:
     /// 1. Insert the schema metadata into the metadata table
     /// 2. Create the data table with the specified columns
-    /// 3. Create any specified indexes
+    /// 3. Create the default `created_at` index, unless disabled by
+    ///    [`StoreConfig::default_index`] or the request
+    /// 4. Create any specified indexes
     pub async fn create_schema(&self, request: CreateSchemaRequest) -> Result<Schema> {
+        request.validate(&self.config)?;
+
         // Check if schema name already exists
         if self.get_schema(&request.name).await?.is_some() {
             return Err(ObjectStoreError::conflict(format!(
@@ -120,6 +228,10 @@ impl ObjectStore {
             )));
         }
 
+        let default_index = request
+            .create_default_index
+            .unwrap_or(self.config.default_index);
+
         let schema_id = uuid::Uuid::new_v4().to_string();
         let metadata_table = quote_identifier(&self.config.metadata_table);
 
@@ -130,12 +242,18 @@ impl ObjectStore {
             .as_ref()
             .map(serde_json::to_value)
             .transpose()?;
+        let storage_params_json = request
+            .storage_params
+            .as_ref()
+            .filter(|params| !params.is_empty())
+            .map(serde_json::to_value)
+            .transpose()?;
 
-        let insert_sql = if self.config.soft_delete {
+        let insert_sql = if self.config.soft_delete_schemas() {
             format!(
                 r#"
-                INSERT INTO {} (id, name, description, table_name, columns, indexes, deleted)
-                VALUES ($1, $2, $3, $4, $5, $6, FALSE)
+                INSERT INTO {} (id, name, description, table_name, columns, indexes, default_index, storage_params, tablespace, deleted)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, FALSE)
                 RETURNING created_at, updated_at
                 "#,
                 metadata_table
@@ -143,8 +261,8 @@ impl ObjectStore {
         } else {
             format!(
                 r#"
-                INSERT INTO {} (id, name, description, table_name, columns, indexes)
-                VALUES ($1, $2, $3, $4, $5, $6)
+                INSERT INTO {} (id, name, description, table_name, columns, indexes, default_index, storage_params, tablespace)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
                 RETURNING created_at, updated_at
                 "#,
                 metadata_table
@@ -158,6 +276,9 @@ impl ObjectStore {
             .bind(&request.table_name)
             .bind(&columns_json)
             .bind(&indexes_json)
+            .bind(default_index)
+            .bind(&storage_params_json)
+            .bind(&request.tablespace)
             .fetch_one(&self.pool)
             .await?;
 
@@ -165,13 +286,28 @@ impl ObjectStore {
         let updated_at: chrono::DateTime<chrono::Utc> = row.try_get("updated_at")?;
 
         // Create the data table
-        let ddl = DdlGenerator::new(&self.config);
-        let create_table_sql = ddl.generate_create_table(&request.table_name, &request.columns);
+        let ddl = DdlGenerator::new(&self.config).with_tablespace(request.tablespace.as_deref());
+        let create_table_sql = ddl.generate_create_table_with_storage(
+            &request.table_name,
+            &request.columns,
+            request.storage_params.as_ref().unwrap_or(&HashMap::new()),
+        );
         sqlx::query(&create_table_sql).execute(&self.pool).await?;
 
+        for unique_index_sql in
+            ddl.generate_unique_column_indexes(&request.table_name, &request.columns)
+        {
+            sqlx::query(&unique_index_sql).execute(&self.pool).await?;
+        }
+
         // Create default index
-        let default_index_sql = ddl.generate_default_index(&request.table_name);
-        sqlx::query(&default_index_sql).execute(&self.pool).await?;
+        if default_index {
+            let default_index_sql = ddl.generate_default_index_where(
+                &request.table_name,
+                request.default_index_predicate.as_deref(),
+            );
+            sqlx::query(&default_index_sql).execute(&self.pool).await?;
+        }
 
         // Create any specified indexes
         if let Some(indexes) = &request.indexes {
@@ -190,6 +326,9 @@ impl ObjectStore {
             table_name: request.table_name,
             columns: request.columns,
             indexes: request.indexes,
+            default_index,
+            storage_params: request.storage_params.filter(|params| !params.is_empty()),
+            tablespace: request.tablespace,
         })
     }
 
@@ -197,10 +336,10 @@ impl ObjectStore {
     pub async fn get_schema(&self, name: &str) -> Result<Option<Schema>> {
         let metadata_table = quote_identifier(&self.config.metadata_table);
 
-        let select_sql = if self.config.soft_delete {
+        let select_sql = if self.config.soft_delete_schemas() {
             format!(
                 r#"
-                SELECT id, created_at, updated_at, name, description, table_name, columns, indexes
+                SELECT id, created_at, updated_at, name, description, table_name, columns, indexes, default_index, storage_params, tablespace
                 FROM {}
                 WHERE name = $1 AND deleted = FALSE
                 "#,
@@ -209,7 +348,7 @@ impl ObjectStore {
         } else {
             format!(
                 r#"
-                SELECT id, created_at, updated_at, name, description, table_name, columns, indexes
+                SELECT id, created_at, updated_at, name, description, table_name, columns, indexes, default_index, storage_params, tablespace
                 FROM {}
                 WHERE name = $1
                 "#,
@@ -228,14 +367,83 @@ impl ObjectStore {
         }
     }
 
+    /// Get several schemas by name in a single query
+    ///
+    /// The result is keyed by schema name; names that don't exist are simply
+    /// absent from the map.
+    pub async fn get_schemas(&self, names: &[String]) -> Result<HashMap<String, Schema>> {
+        if names.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let metadata_table = quote_identifier(&self.config.metadata_table);
+
+        let select_sql = if self.config.soft_delete_schemas() {
+            format!(
+                r#"
+                SELECT id, created_at, updated_at, name, description, table_name, columns, indexes, default_index, storage_params, tablespace
+                FROM {}
+                WHERE name = ANY($1) AND deleted = FALSE
+                "#,
+                metadata_table
+            )
+        } else {
+            format!(
+                r#"
+                SELECT id, created_at, updated_at, name, description, table_name, columns, indexes, default_index, storage_params, tablespace
+                FROM {}
+                WHERE name = ANY($1)
+                "#,
+                metadata_table
+            )
+        };
+
+        let rows = sqlx::query(&select_sql)
+            .bind(names)
+            .fetch_all(&self.pool)
+            .await?;
+
+        rows.iter()
+            .map(|row| {
+                let schema = self.row_to_schema(row)?;
+                Ok((schema.name.clone(), schema))
+            })
+            .collect()
+    }
+
+    /// Get a schema's version without loading its definition
+    ///
+    /// The version is the schema's `updated_at` timestamp, in the same format as
+    /// `Schema::updated_at`, so a cached schema is stale when the two differ.
+    /// Returns `None` if the schema does not exist.
+    pub async fn get_schema_version(&self, name: &str) -> Result<Option<String>> {
+        let metadata_table = quote_identifier(&self.config.metadata_table);
+
+        let select_sql = if self.config.soft_delete_schemas() {
+            format!(
+                "SELECT updated_at FROM {} WHERE name = $1 AND deleted = FALSE",
+                metadata_table
+            )
+        } else {
+            format!("SELECT updated_at FROM {} WHERE name = $1", metadata_table)
+        };
+
+        let updated_at: Option<chrono::DateTime<chrono::Utc>> = sqlx::query_scalar(&select_sql)
+            .bind(name)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(updated_at.map(|t| t.to_rfc3339()))
+    }
+
     /// Get schema by ID
     pub async fn get_schema_by_id(&self, id: &str) -> Result<Option<Schema>> {
         let metadata_table = quote_identifier(&self.config.metadata_table);
 
-        let select_sql = if self.config.soft_delete {
+        let select_sql = if self.config.soft_delete_schemas() {
             format!(
                 r#"
-                SELECT id, created_at, updated_at, name, description, table_name, columns, indexes
+                SELECT id, created_at, updated_at, name, description, table_name, columns, indexes, default_index, storage_params, tablespace
                 FROM {}
                 WHERE id = $1 AND deleted = FALSE
                 "#,
@@ -244,7 +452,7 @@ impl ObjectStore {
         } else {
             format!(
                 r#"
-                SELECT id, created_at, updated_at, name, description, table_name, columns, indexes
+                SELECT id, created_at, updated_at, name, description, table_name, columns, indexes, default_index, storage_params, tablespace
                 FROM {}
                 WHERE id = $1
                 "#,
@@ -267,10 +475,10 @@ impl ObjectStore {
     async fn schema_by_table(&self, table_name: &str) -> Result<Option<Schema>> {
         let metadata_table = quote_identifier(&self.config.metadata_table);
 
-        let select_sql = if self.config.soft_delete {
+        let select_sql = if self.config.soft_delete_schemas() {
             format!(
                 r#"
-                SELECT id, created_at, updated_at, name, description, table_name, columns, indexes
+                SELECT id, created_at, updated_at, name, description, table_name, columns, indexes, default_index, storage_params, tablespace
                 FROM {}
                 WHERE table_name = $1 AND deleted = FALSE
                 "#,
@@ -279,7 +487,7 @@ impl ObjectStore {
         } else {
             format!(
                 r#"
-                SELECT id, created_at, updated_at, name, description, table_name, columns, indexes
+                SELECT id, created_at, updated_at, name, description, table_name, columns, indexes, default_index, storage_params, tablespace
                 FROM {}
                 WHERE table_name = $1
                 "#,
@@ -300,12 +508,304 @@ impl ObjectStore {
 
     /// List all schemas
     pub async fn list_schemas(&self) -> Result<Vec<Schema>> {
+        self.fetch_schemas(false).await
+    }
+
+    /// Run a maintenance callback for each schema
+    ///
+    /// Schemas are visited newest first. With `include_deleted`, soft-deleted
+    /// schemas (whose tables still exist) are visited too. The callback can use
+    /// [`pool`](Self::pool) to act on each table; the first error stops the
+    /// iteration. Returns the number of schemas visited.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let pool = store.pool().clone();
+    /// store.for_each_schema(false, |schema| {
+    ///     let pool = pool.clone();
+    ///     async move {
+    ///         let sql = format!("ANALYZE {}", quote_identifier(&schema.table_name));
+    ///         sqlx::query(&sql).execute(&pool).await?;
+    ///         Ok::<(), ObjectStoreError>(())
+    ///     }
+    /// }).await?;
+    /// ```
+    pub async fn for_each_schema<F, Fut>(&self, include_deleted: bool, mut f: F) -> Result<usize>
+    where
+        F: FnMut(Schema) -> Fut,
+        Fut: std::future::Future<Output = Result<()>>,
+    {
+        let schemas = self.fetch_schemas(include_deleted).await?;
+        let count = schemas.len();
+        for schema in schemas {
+            f(schema).await?;
+        }
+        Ok(count)
+    }
+
+    /// Rebuild all indexes of a schema's table
+    ///
+    /// Issues `REINDEX TABLE`, e.g. to shed bloat after a bulk load. With
+    /// `concurrent`, uses `REINDEX TABLE CONCURRENTLY` (PostgreSQL 12+), which
+    /// doesn't block writes but can't run inside a transaction, so the
+    /// statement runs on its own pooled connection.
+    pub async fn reindex(&self, schema_name: &str, concurrent: bool) -> Result<()> {
+        let schema = self.require_schema(schema_name).await?;
+
+        let reindex_sql = format!(
+            "REINDEX TABLE {}{}",
+            if concurrent { "CONCURRENTLY " } else { "" },
+            quote_identifier(&schema.table_name)
+        );
+
+        let mut conn = self.pool.acquire().await?;
+        sqlx::query(&reindex_sql).execute(&mut *conn).await?;
+
+        Ok(())
+    }
+
+    /// Check whether a schema's data table physically exists
+    ///
+    /// Looks the table up in `information_schema.tables` in the current
+    /// PostgreSQL schema, so drift between the metadata and the database (a
+    /// table dropped out of band, a partially failed operation) shows up as
+    /// `false`. An unknown schema name is an error.
+    pub async fn table_exists(&self, schema_name: &str) -> Result<bool> {
+        let schema = self.require_schema(schema_name).await?;
+
+        let exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM information_schema.tables \
+             WHERE table_schema = current_schema() AND table_name = $1)",
+        )
+        .bind(&schema.table_name)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(exists)
+    }
+
+    /// Columns present in a schema's data table, with their SQL types
+    ///
+    /// Reads the physical table rather than the metadata, in column order.
+    /// Types are as PostgreSQL prints them (e.g. `numeric(10,2)`,
+    /// `timestamp with time zone`). Empty if the table does not exist.
+    pub async fn actual_columns(&self, schema_name: &str) -> Result<Vec<(String, String)>> {
+        let schema = self.require_schema(schema_name).await?;
+        self.table_columns(&schema.table_name).await
+    }
+
+    /// Compare a schema's metadata with its data table
+    ///
+    /// Reports columns missing from the table (including the enabled
+    /// auto-managed columns), table columns the metadata doesn't list, and
+    /// schema columns whose SQL type differs. Drift comes from DDL run outside
+    /// the store or from failed migrations; an empty result means the two
+    /// agree. A missing table reports every column as missing (see
+    /// [`repair_schema`](Self::repair_schema)).
+    pub async fn schema_drift(&self, schema_name: &str) -> Result<SchemaDrift> {
+        let schema = self.require_schema(schema_name).await?;
+        let actual = self.table_columns(&schema.table_name).await?;
+        Ok(self.drift_between(&schema, &actual))
+    }
+
+    async fn table_columns(&self, table_name: &str) -> Result<Vec<(String, String)>> {
+        let columns = sqlx::query_as(
+            "SELECT c.column_name::text, format_type(a.atttypid, a.atttypmod) \
+             FROM information_schema.columns c \
+             JOIN pg_catalog.pg_attribute a \
+               ON a.attrelid = format('%I.%I', c.table_schema, c.table_name)::regclass \
+              AND a.attname = c.column_name \
+             WHERE c.table_schema = current_schema() AND c.table_name = $1 \
+             ORDER BY c.ordinal_position",
+        )
+        .bind(table_name)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(columns)
+    }
+
+    fn drift_between(&self, schema: &Schema, actual: &[(String, String)]) -> SchemaDrift {
+        let mut expected: Vec<&str> = self.config.managed_column_names();
+        expected.extend(schema.columns.iter().map(|c| c.name.as_str()));
+
+        let mut drift = SchemaDrift {
+            missing_columns: expected
+                .iter()
+                .filter(|name| !actual.iter().any(|(column, _)| column == *name))
+                .map(|name| name.to_string())
+                .collect(),
+            unexpected_columns: actual
+                .iter()
+                .filter(|(column, _)| !expected.contains(&column.as_str()))
+                .map(|(column, _)| column.clone())
+                .collect(),
+            type_mismatches: Vec::new(),
+        };
+
+        for col in &schema.columns {
+            let expected_type = col.column_type.base_sql_type();
+            if let Some((_, actual_type)) = actual.iter().find(|(column, _)| *column == col.name)
+                && !actual_type.eq_ignore_ascii_case(&expected_type)
+            {
+                drift.type_mismatches.push(ColumnTypeMismatch {
+                    column: col.name.clone(),
+                    expected: expected_type,
+                    actual: actual_type.clone(),
+                });
+            }
+        }
+
+        drift.missing_columns.sort_unstable();
+        drift.unexpected_columns.sort_unstable();
+        drift
+    }
+
+    /// Recreate a schema's missing data table from its stored metadata
+    ///
+    /// Regenerates the table, unique column indexes, default index, and
+    /// declared indexes from the schema's columns, indexes, storage parameters,
+    /// and tablespace, in one transaction. Returns `true` when the table was
+    /// recreated and `false` when it already exists with the expected columns.
+    /// A table whose columns differ from the metadata is left untouched and
+    /// reported as [`ObjectStoreError::Conflict`]. Rows lost with the original
+    /// table are not restored, and the default index is recreated without a
+    /// custom predicate since predicates are not kept in the metadata.
+    pub async fn repair_schema(&self, name: &str) -> Result<bool> {
+        let schema = self.require_schema(name).await?;
+
+        let existing = self.table_columns(&schema.table_name).await?;
+        if !existing.is_empty() {
+            let drift = self.drift_between(&schema, &existing);
+            if drift.missing_columns.is_empty() && drift.unexpected_columns.is_empty() {
+                return Ok(false);
+            }
+
+            return Err(ObjectStoreError::conflict(format!(
+                "Table '{}' for schema '{}' exists with a different structure \
+                 (missing columns: [{}], unexpected columns: [{}])",
+                schema.table_name,
+                name,
+                drift.missing_columns.join(", "),
+                drift.unexpected_columns.join(", ")
+            )));
+        }
+
+        let ddl = DdlGenerator::new(&self.config).with_tablespace(schema.tablespace.as_deref());
+        let mut statements = vec![ddl.generate_create_table_with_storage(
+            &schema.table_name,
+            &schema.columns,
+            schema.storage_params.as_ref().unwrap_or(&HashMap::new()),
+        )];
+        statements.extend(ddl.generate_unique_column_indexes(&schema.table_name, &schema.columns));
+        if schema.default_index {
+            statements.push(ddl.generate_default_index(&schema.table_name));
+        }
+        if let Some(indexes) = &schema.indexes {
+            for index in indexes {
+                statements.push(ddl.generate_create_index(&schema.table_name, index));
+            }
+        }
+
+        let mut tx = self.pool.begin().await?;
+        for statement in &statements {
+            sqlx::query(statement).execute(&mut *tx).await?;
+        }
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    /// Re-add schema columns missing from a data table
+    ///
+    /// Adds each column the metadata lists but the table lacks, as declared
+    /// (type, constraints, and default), and returns their names. Values lost
+    /// with a dropped column are not restored, and a NOT NULL column without a
+    /// default can't be re-added to a table with rows. A missing table is
+    /// recreated with [`repair_schema`](Self::repair_schema). Missing
+    /// auto-managed columns can't be re-added and are reported as
+    /// [`ObjectStoreError::Conflict`].
+    pub async fn repair_missing_columns(&self, schema_name: &str) -> Result<Vec<String>> {
+        let schema = self.require_schema(schema_name).await?;
+        let actual = self.table_columns(&schema.table_name).await?;
+        self.add_missing_columns(&schema, &actual).await
+    }
+
+    async fn add_missing_columns(
+        &self,
+        schema: &Schema,
+        actual: &[(String, String)],
+    ) -> Result<Vec<String>> {
+        if actual.is_empty() {
+            self.repair_schema(&schema.name).await?;
+            return Ok(schema.columns.iter().map(|c| c.name.clone()).collect());
+        }
+
+        let drift = self.drift_between(schema, actual);
+        let managed = self.config.managed_column_names();
+        if let Some(column) = drift
+            .missing_columns
+            .iter()
+            .find(|c| managed.contains(&c.as_str()))
+        {
+            return Err(ObjectStoreError::conflict(format!(
+                "Table '{}' for schema '{}' is missing the auto-managed column '{}'",
+                schema.table_name, schema.name, column
+            )));
+        }
+        if drift.missing_columns.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let present: Vec<ColumnDefinition> = schema
+            .columns
+            .iter()
+            .filter(|c| !drift.missing_columns.contains(&c.name))
+            .cloned()
+            .collect();
+        let ddl = DdlGenerator::new(&self.config).with_tablespace(schema.tablespace.as_deref());
+        let statements = ddl.generate_alter_table(&schema.table_name, &present, &schema.columns);
+
+        let mut tx = self.pool.begin().await?;
+        for statement in &statements {
+            sqlx::query(statement).execute(&mut *tx).await?;
+        }
+        tx.commit().await?;
+
+        Ok(drift.missing_columns)
+    }
+
+    /// Apply [`StoreConfig::drift_check`] before inserting into a schema's table
+    async fn check_drift_before_insert(&self, schema: &Schema) -> Result<()> {
+        if self.config.drift_check == DriftCheck::Off {
+            return Ok(());
+        }
+
+        let actual = self.table_columns(&schema.table_name).await?;
+        let drift = self.drift_between(schema, &actual);
+        if drift.missing_columns.is_empty() {
+            return Ok(());
+        }
+
+        match self.config.drift_check {
+            DriftCheck::Repair => self.add_missing_columns(schema, &actual).await.map(|_| ()),
+            _ => Err(ObjectStoreError::conflict(format!(
+                "Schema drift detected for '{}': table '{}' is missing columns [{}]; \
+                 see schema_drift and repair_missing_columns",
+                schema.name,
+                schema.table_name,
+                drift.missing_columns.join(", ")
+            ))),
+        }
+    }
+
+    /// Load schema metadata, optionally including soft-deleted schemas
+    async fn fetch_schemas(&self, include_deleted: bool) -> Result<Vec<Schema>> {
         let metadata_table = quote_identifier(&self.config.metadata_table);
 
-        let select_sql = if self.config.soft_delete {
+        let select_sql = if self.config.soft_delete_schemas() && !include_deleted {
             format!(
                 r#"
-                SELECT id, created_at, updated_at, name, description, table_name, columns, indexes
+                SELECT id, created_at, updated_at, name, description, table_name, columns, indexes, default_index, storage_params, tablespace
                 FROM {}
                 WHERE deleted = FALSE
                 ORDER BY created_at DESC
@@ -315,7 +815,7 @@ impl ObjectStore {
         } else {
             format!(
                 r#"
-                SELECT id, created_at, updated_at, name, description, table_name, columns, indexes
+                SELECT id, created_at, updated_at, name, description, table_name, columns, indexes, default_index, storage_params, tablespace
                 FROM {}
                 ORDER BY created_at DESC
                 "#,
@@ -328,14 +828,168 @@ impl ObjectStore {
         rows.iter().map(|row| self.row_to_schema(row)).collect()
     }
 
+    /// Describe a schema's columns for introspection
+    ///
+    /// Augments each column with its resolved SQL type, constraints, and whether
+    /// it is covered by a declared index. Assembled from the stored metadata.
+    pub async fn describe_schema(&self, name: &str) -> Result<SchemaDescription> {
+        let schema = self
+            .get_schema(name)
+            .await?
+            .ok_or_else(|| ObjectStoreError::schema_not_found(name))?;
+        Ok(schema.describe())
+    }
+
+    /// Get an active schema for an instance operation
+    ///
+    /// Distinguishes a soft-deleted schema ([`ObjectStoreError::SchemaDeleted`])
+    /// from one that never existed ([`ObjectStoreError::SchemaNotFound`]).
+    async fn require_schema(&self, name: &str) -> Result<Schema> {
+        if let Some(schema) = self.get_schema(name).await? {
+            return Ok(schema);
+        }
+
+        if self.config.soft_delete_schemas() {
+            let sql = format!(
+                "SELECT EXISTS(SELECT 1 FROM {} WHERE name = $1 AND deleted = TRUE)",
+                quote_identifier(&self.config.metadata_table)
+            );
+            let deleted: bool = sqlx::query_scalar(&sql)
+                .bind(name)
+                .fetch_one(&self.pool)
+                .await?;
+            if deleted {
+                return Err(ObjectStoreError::schema_deleted(name));
+            }
+        }
+
+        Err(ObjectStoreError::schema_not_found(name))
+    }
+
+    /// Create a schema, or bring an existing one in line with the definition
+    ///
+    /// Creates the schema if no active schema has `request.name`. Otherwise the
+    /// stored schema is migrated to the definition with
+    /// [`apply_migration`](Self::apply_migration); a matching definition is a
+    /// no-op. The table name, storage parameters, and tablespace of an existing
+    /// schema cannot be changed this way (a differing value is a validation
+    /// error), and the default index settings only apply on creation.
+    pub async fn ensure_schema(&self, request: CreateSchemaRequest) -> Result<Schema> {
+        match self.get_schema(&request.name).await? {
+            Some(existing) => self.migrate_schema(existing, request).await,
+            None => self.create_schema(request).await,
+        }
+    }
+
+    /// Migrate an existing schema to a desired definition
+    ///
+    /// Runs the statements from [`DdlGenerator::plan_migration`] (index drops,
+    /// column and constraint changes, index creation) and stores the new
+    /// description, columns, and indexes, all in one transaction: either the
+    /// whole migration is applied or none of it. A definition that already
+    /// matches is a no-op. The definition is checked like
+    /// [`create_schema`](Self::create_schema) requests, and must keep the
+    /// schema's table name, storage parameters, and tablespace.
+    pub async fn apply_migration(&self, desired: CreateSchemaRequest) -> Result<Schema> {
+        let current = self
+            .get_schema(&desired.name)
+            .await?
+            .ok_or_else(|| ObjectStoreError::schema_not_found(&desired.name))?;
+        self.migrate_schema(current, desired).await
+    }
+
+    async fn migrate_schema(
+        &self,
+        current: Schema,
+        desired: CreateSchemaRequest,
+    ) -> Result<Schema> {
+        if current.table_name != desired.table_name {
+            return Err(ObjectStoreError::validation(format!(
+                "Schema '{}' uses table '{}', not '{}'",
+                desired.name, current.table_name, desired.table_name
+            )));
+        }
+
+        desired.validate(&self.config)?;
+
+        if current.storage_params.clone().unwrap_or_default()
+            != desired.storage_params.clone().unwrap_or_default()
+        {
+            return Err(ObjectStoreError::validation(format!(
+                "Schema '{}' storage parameters can't be changed by a migration",
+                desired.name
+            )));
+        }
+        if current.tablespace != desired.tablespace {
+            return Err(ObjectStoreError::validation(format!(
+                "Schema '{}' tablespace can't be changed by a migration",
+                desired.name
+            )));
+        }
+
+        let statements = DdlGenerator::new(&self.config)
+            .with_tablespace(current.tablespace.as_deref())
+            .plan_migration(&current, &desired);
+        let indexes_match = current.indexes.as_deref().unwrap_or_default()
+            == desired.indexes.as_deref().unwrap_or_default();
+        if statements.is_empty()
+            && indexes_match
+            && current.columns == desired.columns
+            && current.description == desired.description
+        {
+            return Ok(current);
+        }
+
+        let where_clause = if self.config.soft_delete_schemas() {
+            "name = $1 AND deleted = FALSE"
+        } else {
+            "name = $1"
+        };
+        let update_sql = format!(
+            r#"
+            UPDATE {}
+            SET updated_at = NOW(), description = $2, columns = $3, indexes = $4
+            WHERE {}
+            RETURNING id, created_at, updated_at, name, description, table_name, columns, indexes, default_index, storage_params, tablespace
+            "#,
+            quote_identifier(&self.config.metadata_table),
+            where_clause
+        );
+
+        let mut tx = self.pool.begin().await?;
+        for statement in &statements {
+            sqlx::query(statement).execute(&mut *tx).await?;
+        }
+        let row = sqlx::query(&update_sql)
+            .bind(&current.name)
+            .bind(&desired.description)
+            .bind(serde_json::to_value(&desired.columns)?)
+            .bind(
+                desired
+                    .indexes
+                    .as_ref()
+                    .map(serde_json::to_value)
+                    .transpose()?,
+            )
+            .fetch_one(&mut *tx)
+            .await?;
+        let schema = self.row_to_schema(&row)?;
+        tx.commit().await?;
+
+        Ok(schema)
+    }
+
     /// Update a schema
     ///
     /// This will update schema metadata and alter the table if columns changed.
+    /// Dropping a column that is still referenced by a declared index is rejected;
+    /// remove the index in the same request (via `indexes`) to drop the column.
     pub async fn update_schema(&self, name: &str, request: UpdateSchemaRequest) -> Result<Schema> {
         let existing = self
             .get_schema(name)
             .await?
             .ok_or_else(|| ObjectStoreError::schema_not_found(name))?;
+        self.check_schema_update(&existing, &request)?;
 
         let metadata_table = quote_identifier(&self.config.metadata_table);
 
@@ -347,9 +1001,13 @@ impl ObjectStore {
             set_clauses.push(format!("name = ${}", param_idx));
             param_idx += 1;
         }
-        if request.description.is_some() {
-            set_clauses.push(format!("description = ${}", param_idx));
-            param_idx += 1;
+        match request.description {
+            FieldUpdate::Set(_) => {
+                set_clauses.push(format!("description = ${}", param_idx));
+                param_idx += 1;
+            }
+            FieldUpdate::Clear => set_clauses.push("description = NULL".to_string()),
+            FieldUpdate::Unchanged => {}
         }
         if request.columns.is_some() {
             set_clauses.push(format!("columns = ${}", param_idx));
@@ -359,7 +1017,7 @@ impl ObjectStore {
             set_clauses.push(format!("indexes = ${}", param_idx));
         }
 
-        let where_clause = if self.config.soft_delete {
+        let where_clause = if self.config.soft_delete_schemas() {
             "name = $1 AND deleted = FALSE"
         } else {
             "name = $1"
@@ -370,7 +1028,7 @@ impl ObjectStore {
             UPDATE {}
             SET {}
             WHERE {}
-            RETURNING id, created_at, updated_at, name, description, table_name, columns, indexes
+            RETURNING id, created_at, updated_at, name, description, table_name, columns, indexes, default_index, storage_params, tablespace
             "#,
             metadata_table,
             set_clauses.join(", "),
@@ -382,7 +1040,7 @@ impl ObjectStore {
         if let Some(ref new_name) = request.name {
             query = query.bind(new_name);
         }
-        if let Some(ref description) = request.description {
+        if let FieldUpdate::Set(ref description) = request.description {
             query = query.bind(description);
         }
         if let Some(ref columns) = request.columns {
@@ -399,7 +1057,8 @@ impl ObjectStore {
 
         // Alter table if columns changed
         if let Some(new_columns) = &request.columns {
-            let ddl = DdlGenerator::new(&self.config);
+            let ddl =
+                DdlGenerator::new(&self.config).with_tablespace(existing.tablespace.as_deref());
             let alter_statements =
                 ddl.generate_alter_table(&existing.table_name, &existing.columns, new_columns);
 
@@ -411,41 +1070,182 @@ impl ObjectStore {
         Ok(schema)
     }
 
-    /// Delete a schema
+    /// Preview [`update_schema`](Self::update_schema) without applying it
     ///
-    /// If soft_delete is enabled, marks the schema as deleted.
-    /// Otherwise, drops the table and removes the metadata.
-    pub async fn delete_schema(&self, name: &str) -> Result<()> {
-        let schema = self
+    /// Runs the same checks and returns the ALTER TABLE statements the update
+    /// would execute, plus warnings for changes that lose data or may fail on
+    /// the stored rows: dropping a column that holds values, changing a
+    /// column's type, and making a column with NULLs NOT NULL. Neither the
+    /// table nor the metadata is touched.
+    pub async fn preview_update_schema(
+        &self,
+        name: &str,
+        request: &UpdateSchemaRequest,
+    ) -> Result<SchemaUpdatePreview> {
+        let existing = self
             .get_schema(name)
             .await?
             .ok_or_else(|| ObjectStoreError::schema_not_found(name))?;
+        self.check_schema_update(&existing, request)?;
 
-        let metadata_table = quote_identifier(&self.config.metadata_table);
+        let Some(new_columns) = &request.columns else {
+            return Ok(SchemaUpdatePreview::default());
+        };
 
-        if self.config.soft_delete {
-            let update_sql = format!(
-                "UPDATE {} SET deleted = TRUE, updated_at = NOW() WHERE name = $1 AND deleted = FALSE",
-                metadata_table
-            );
-            sqlx::query(&update_sql)
-                .bind(name)
-                .execute(&self.pool)
-                .await?;
-        } else {
-            // Hard delete: drop table and remove metadata
-            let ddl = DdlGenerator::new(&self.config);
-            let drop_sql = ddl.generate_drop_table(&schema.table_name);
-            sqlx::query(&drop_sql).execute(&self.pool).await?;
+        let ddl = DdlGenerator::new(&self.config).with_tablespace(existing.tablespace.as_deref());
+        let statements =
+            ddl.generate_alter_table(&existing.table_name, &existing.columns, new_columns);
+
+        let table = quote_identifier(&existing.table_name);
+        let mut warnings = Vec::new();
+        for old_col in &existing.columns {
+            let column = quote_identifier(&old_col.name);
+            let Some(new_col) = new_columns.iter().find(|c| c.name == old_col.name) else {
+                let sql = format!("SELECT COUNT({}) FROM {}", column, table);
+                let (values,): (i64,) = sqlx::query_as(&sql).fetch_one(&self.pool).await?;
+                if values > 0 {
+                    warnings.push(format!(
+                        "Dropping column '{}' discards {} stored value(s)",
+                        old_col.name, values
+                    ));
+                }
+                continue;
+            };
 
-            let delete_sql = format!("DELETE FROM {} WHERE name = $1", metadata_table);
-            sqlx::query(&delete_sql)
-                .bind(name)
-                .execute(&self.pool)
-                .await?;
-        }
+            let old_type = old_col.column_type.base_sql_type();
+            let new_type = new_col.column_type.base_sql_type();
+            if old_type != new_type {
+                warnings.push(format!(
+                    "Changing column '{}' from {} to {} fails if a stored value doesn't convert",
+                    old_col.name, old_type, new_type
+                ));
+            }
 
-        Ok(())
+            if old_col.nullable && !new_col.nullable {
+                let sql = format!("SELECT COUNT(*) FROM {} WHERE {} IS NULL", table, column);
+                let (nulls,): (i64,) = sqlx::query_as(&sql).fetch_one(&self.pool).await?;
+                if nulls > 0 {
+                    warnings.push(format!(
+                        "Column '{}' has {} NULL value(s); making it NOT NULL will fail",
+                        old_col.name, nulls
+                    ));
+                }
+            }
+        }
+
+        Ok(SchemaUpdatePreview {
+            statements,
+            warnings,
+        })
+    }
+
+    /// Column checks for schema updates and migrations: name length and default
+    fn check_new_column(col: &ColumnDefinition) -> Result<()> {
+        validate_identifier_length(&col.name).map_err(ObjectStoreError::validation)?;
+        if let Some(default) = &col.default_value {
+            validate_default_value(default, &col.column_type).map_err(|e| {
+                ObjectStoreError::validation(format!("Column '{}': {}", col.name, e))
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Checks shared by [`update_schema`](Self::update_schema) and its preview
+    fn check_schema_update(&self, existing: &Schema, request: &UpdateSchemaRequest) -> Result<()> {
+        self.config.check_schema_limits(
+            request.columns.as_ref().unwrap_or(&existing.columns).len(),
+            request
+                .indexes
+                .as_ref()
+                .or(existing.indexes.as_ref())
+                .map_or(0, Vec::len),
+        )?;
+
+        if let Some(new_columns) = &request.columns {
+            for col in new_columns {
+                Self::check_new_column(col)?;
+            }
+        }
+
+        // Ensure no declared index would be left pointing at a dropped column
+        if let Some(new_columns) = &request.columns {
+            let indexes = request.indexes.as_ref().or(existing.indexes.as_ref());
+            for dropped in existing
+                .columns
+                .iter()
+                .filter(|old| !new_columns.iter().any(|new| new.name == old.name))
+            {
+                if let Some(index) = indexes
+                    .into_iter()
+                    .flatten()
+                    .find(|index| index.columns.contains(&dropped.name))
+                {
+                    return Err(ObjectStoreError::validation(format!(
+                        "Cannot drop column '{}': it is referenced by index '{}'",
+                        dropped.name, index.name
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Delete a schema
+    ///
+    /// If schemas are soft-deleted (see [`StoreConfig::soft_delete_schemas`]),
+    /// marks the schema as deleted, and its instances too when instances are
+    /// soft-deleted and [`StoreConfig::cascade_schema_delete`] is set. Instance
+    /// operations on the schema then fail with [`ObjectStoreError::SchemaDeleted`].
+    /// Otherwise, drops the table and removes the metadata.
+    pub async fn delete_schema(&self, name: &str) -> Result<()> {
+        let schema = self
+            .get_schema(name)
+            .await?
+            .ok_or_else(|| ObjectStoreError::schema_not_found(name))?;
+
+        let metadata_table = quote_identifier(&self.config.metadata_table);
+
+        if self.config.soft_delete_schemas() {
+            let update_sql = format!(
+                "UPDATE {} SET deleted = TRUE, updated_at = NOW() WHERE name = $1 AND deleted = FALSE",
+                metadata_table
+            );
+            let mut tx = self.pool.begin().await?;
+            sqlx::query(&update_sql)
+                .bind(name)
+                .execute(&mut *tx)
+                .await?;
+
+            if self.config.soft_delete && self.config.cascade_schema_delete {
+                let set_clause = if self.config.auto_columns.updated_at {
+                    "deleted = TRUE, updated_at = NOW()"
+                } else {
+                    "deleted = TRUE"
+                };
+                let cascade_sql = format!(
+                    "UPDATE {} SET {} WHERE deleted = FALSE",
+                    quote_identifier(&schema.table_name),
+                    set_clause
+                );
+                sqlx::query(&cascade_sql).execute(&mut *tx).await?;
+            }
+
+            tx.commit().await?;
+        } else {
+            // Hard delete: drop table and remove metadata
+            let ddl = DdlGenerator::new(&self.config);
+            let drop_sql = ddl.generate_drop_table(&schema.table_name);
+            sqlx::query(&drop_sql).execute(&self.pool).await?;
+
+            let delete_sql = format!("DELETE FROM {} WHERE name = $1", metadata_table);
+            sqlx::query(&delete_sql)
+                .bind(name)
+                .execute(&self.pool)
+                .await?;
+        }
+
+        Ok(())
     }
 
     // =========================================================================
@@ -458,16 +1258,231 @@ impl ObjectStore {
         schema_name: &str,
         properties: serde_json::Value,
     ) -> Result<String> {
-        let schema = self
-            .get_schema(schema_name)
+        let instance_id = self.config.id_generator.generate();
+        self.insert_instance(&self.pool, schema_name, instance_id, properties)
+            .await
+    }
+
+    /// Create a new instance and return it as stored
+    ///
+    /// The instance is read back with `RETURNING`, so it includes the values
+    /// of omitted columns that have a default, including defaults evaluated by
+    /// the database such as `NOW()`, as well as the generated timestamps.
+    pub async fn create_instance_returning(
+        &self,
+        schema_name: &str,
+        properties: serde_json::Value,
+    ) -> Result<Instance> {
+        let schema = self.require_schema(schema_name).await?;
+        let instance_id = self.config.id_generator.generate();
+
+        let returning = QueryBuilder::new(&schema, &self.config, &FilterRequest::default())
+            .select_columns()
+            .join(", ");
+        let row = self
+            .insert_instance_row(
+                &self.pool,
+                &schema,
+                &instance_id,
+                &properties,
+                Some(&returning),
+            )
             .await?
-            .ok_or_else(|| ObjectStoreError::schema_not_found(schema_name))?;
+            .ok_or_else(|| ObjectStoreError::database("INSERT ... RETURNING returned no row"))?;
+
+        self.row_to_instance(&row, &schema)
+    }
+
+    /// Create an instance unless one with the same natural key already exists
+    ///
+    /// `key_columns` name the schema columns that identify the instance; their
+    /// values are taken from `properties` and must be present and non-null.
+    /// Returns the existing instance with `false` when a row with the same key
+    /// values is found, and the newly created one with `true` otherwise, so
+    /// retried requests do not insert duplicates. Existing rows are never
+    /// modified. When two callers race, a unique constraint or index over the
+    /// key columns turns the loser's insert into a lookup of the winner's row.
+    pub async fn create_instance_idempotent(
+        &self,
+        schema_name: &str,
+        properties: serde_json::Value,
+        key_columns: &[&str],
+    ) -> Result<(Instance, bool)> {
+        let schema = self.require_schema(schema_name).await?;
+
+        if key_columns.is_empty() {
+            return Err(ObjectStoreError::validation(
+                "At least one idempotency key column is required",
+            ));
+        }
+        let mut key_conditions = Vec::with_capacity(key_columns.len());
+        for column in key_columns {
+            let Some(col) = schema.columns.iter().find(|c| c.name == *column) else {
+                return Err(ObjectStoreError::validation(format!(
+                    "Column '{}' does not exist in schema '{}'",
+                    column, schema_name
+                )));
+            };
+            match properties.get(column) {
+                Some(value) if !value.is_null() => {
+                    // Match the stored form, which has the column's normalization applied
+                    let value = col.normalize_value(value).into_owned();
+                    key_conditions.push(Condition::eq(*column, value));
+                }
+                _ => {
+                    return Err(ObjectStoreError::validation(format!(
+                        "Idempotency key column '{}' must have a value",
+                        column
+                    )));
+                }
+            }
+        }
+        let lookup = FilterRequest::new()
+            .with_condition(Condition::and(key_conditions))
+            .with_pagination(0, 1)
+            .with_unique_sort();
+
+        let (existing, _) = self
+            .filter_instances_internal(&schema, lookup.clone())
+            .await?;
+        if let Some(instance) = existing.into_iter().next() {
+            return Ok((instance, false));
+        }
+
+        let instance_id = self.config.id_generator.generate();
+        let returning = QueryBuilder::new(&schema, &self.config, &FilterRequest::default())
+            .select_columns()
+            .join(", ");
+        match self
+            .insert_instance_row(
+                &self.pool,
+                &schema,
+                &instance_id,
+                &properties,
+                Some(&returning),
+            )
+            .await
+        {
+            Ok(row) => {
+                let row = row.ok_or_else(|| {
+                    ObjectStoreError::database("INSERT ... RETURNING returned no row")
+                })?;
+                Ok((self.row_to_instance(&row, &schema)?, true))
+            }
+            Err(ObjectStoreError::Sql(sqlx::Error::Database(db_err)))
+                if db_err.is_unique_violation() =>
+            {
+                // A concurrent call inserted the same key first
+                let (existing, _) = self.filter_instances_internal(&schema, lookup).await?;
+                existing
+                    .into_iter()
+                    .next()
+                    .map(|instance| (instance, false))
+                    .ok_or(ObjectStoreError::Sql(sqlx::Error::Database(db_err)))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Create a new instance on a caller-owned connection or transaction
+    ///
+    /// Pass `&mut *tx` to make the insert part of the caller's transaction;
+    /// it is committed or rolled back together with the caller's own writes.
+    /// The schema itself is still looked up through the store's pool.
+    pub async fn create_instance_tx(
+        &self,
+        conn: &mut PgConnection,
+        schema_name: &str,
+        properties: serde_json::Value,
+    ) -> Result<String> {
+        let instance_id = self.config.id_generator.generate();
+        self.insert_instance(conn, schema_name, instance_id, properties)
+            .await
+    }
+
+    /// Create a new instance with a client-provided id
+    ///
+    /// Lets idempotent clients choose the id upstream. Requires the auto `id`
+    /// column; the id must be 1-255 characters without whitespace or control
+    /// characters. Inserting an id that already exists returns
+    /// [`ObjectStoreError::Conflict`].
+    pub async fn create_instance_with_id(
+        &self,
+        schema_name: &str,
+        id: &str,
+        properties: serde_json::Value,
+    ) -> Result<String> {
+        if !self.config.auto_columns.id {
+            return Err(ObjectStoreError::validation(
+                "Client-provided ids require the auto id column",
+            ));
+        }
+        if id.is_empty() || id.chars().count() > 255 {
+            return Err(ObjectStoreError::validation(
+                "Instance id must be between 1 and 255 characters",
+            ));
+        }
+        if id.chars().any(|c| c.is_whitespace() || c.is_control()) {
+            return Err(ObjectStoreError::validation(
+                "Instance id must not contain whitespace or control characters",
+            ));
+        }
+
+        match self
+            .insert_instance(&self.pool, schema_name, id.to_string(), properties)
+            .await
+        {
+            Err(ObjectStoreError::Sql(sqlx::Error::Database(db_err)))
+                if db_err.is_unique_violation() =>
+            {
+                if db_err.constraint().is_some_and(|c| c.ends_with("_pkey")) {
+                    Err(ObjectStoreError::conflict(format!(
+                        "Instance with id '{}' already exists",
+                        id
+                    )))
+                } else {
+                    Err(ObjectStoreError::conflict(db_err.message().to_string()))
+                }
+            }
+            result => result,
+        }
+    }
+
+    /// Validate properties and insert a single row with the given id
+    async fn insert_instance<'e, E>(
+        &self,
+        executor: E,
+        schema_name: &str,
+        instance_id: String,
+        properties: serde_json::Value,
+    ) -> Result<String>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Postgres>,
+    {
+        let schema = self.require_schema(schema_name).await?;
+        self.insert_instance_row(executor, &schema, &instance_id, &properties, None)
+            .await?;
+        Ok(instance_id)
+    }
 
+    /// Validate properties and insert a single row, optionally returning
+    /// the `returning` columns of the inserted row
+    async fn insert_instance_row<'e, E>(
+        &self,
+        executor: E,
+        schema: &Schema,
+        instance_id: &str,
+        properties: &serde_json::Value,
+        returning: Option<&str>,
+    ) -> Result<Option<sqlx::postgres::PgRow>>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Postgres>,
+    {
         let properties_obj = properties
             .as_object()
             .ok_or_else(|| ObjectStoreError::validation("Properties must be a JSON object"))?;
 
-        let instance_id = uuid::Uuid::new_v4().to_string();
+        self.check_drift_before_insert(schema).await?;
 
         // Build column names and placeholders
         let mut column_names = Vec::new();
@@ -485,7 +1500,7 @@ impl ObjectStore {
         for col in &schema.columns {
             if let Some(value) = properties_obj.get(&col.name) {
                 // Validate type
-                if let Err(e) = col.column_type.validate_value(value) {
+                if let Err(e) = col.validate_value(value) {
                     return Err(ObjectStoreError::validation(format!(
                         "Invalid value for column '{}': {}",
                         col.name, e
@@ -500,7 +1515,7 @@ impl ObjectStore {
                 }
 
                 column_names.push(quote_identifier(&col.name));
-                placeholders.push(format!("${}", param_idx));
+                placeholders.push(col.column_type.placeholder(param_idx));
                 param_idx += 1;
             } else if !col.nullable && col.default_value.is_none() {
                 return Err(ObjectStoreError::validation(format!(
@@ -510,42 +1525,62 @@ impl ObjectStore {
             }
         }
 
-        let insert_sql = format!(
+        let mut insert_sql = format!(
             "INSERT INTO {} ({}) VALUES ({})",
             quote_identifier(&schema.table_name),
             column_names.join(", "),
             placeholders.join(", ")
         );
+        if let Some(returning) = returning {
+            insert_sql.push_str(&format!(" RETURNING {}", returning));
+        }
 
         // Build query with type-aware bindings
         let mut query = sqlx::query(&insert_sql);
 
         if self.config.auto_columns.id {
-            query = query.bind(&instance_id);
+            query = query.bind(instance_id);
         }
 
         for col in &schema.columns {
             if let Some(value) = properties_obj.get(&col.name) {
-                query = Self::bind_value(query, &col.column_type, &col.name, value)?;
+                query = Self::bind_value(query, col, value)?;
             }
         }
 
-        query.execute(&self.pool).await?;
-
-        Ok(instance_id)
+        if returning.is_some() {
+            Ok(query.fetch_optional(executor).await?)
+        } else {
+            query.execute(executor).await?;
+            Ok(None)
+        }
     }
 
     /// Get instance by ID
+    ///
+    /// A missing schema is an error ([`ObjectStoreError::SchemaNotFound`] or
+    /// [`ObjectStoreError::SchemaDeleted`]); a missing instance is `Ok(None)`.
+    /// Use [`require_instance`](Self::require_instance) to treat a missing
+    /// instance as an error too.
     pub async fn get_instance(
         &self,
         schema_name: &str,
         instance_id: &str,
     ) -> Result<Option<Instance>> {
-        let schema = self
-            .get_schema(schema_name)
-            .await?
-            .ok_or_else(|| ObjectStoreError::schema_not_found(schema_name))?;
+        let schema = self.require_schema(schema_name).await?;
+
+        let select_sql = self.instance_by_id_sql(&schema);
+        let row = sqlx::query(&select_sql)
+            .bind(instance_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.map(|row| self.row_to_instance(&row, &schema))
+            .transpose()
+    }
 
+    /// SELECT for a single live instance by id (`$1`)
+    fn instance_by_id_sql(&self, schema: &Schema) -> String {
         // Build column list
         let mut select_columns = Vec::new();
 
@@ -560,7 +1595,7 @@ impl ObjectStore {
         }
 
         for col in &schema.columns {
-            select_columns.push(quote_identifier(&col.name));
+            select_columns.push(column_select("", col, &col.name));
         }
 
         let where_clause = if self.config.soft_delete {
@@ -569,11 +1604,56 @@ impl ObjectStore {
             "id = $1"
         };
 
-        let select_sql = format!(
+        format!(
             "SELECT {} FROM {} WHERE {}",
             select_columns.join(", "),
             quote_identifier(&schema.table_name),
             where_clause
+        )
+    }
+
+    /// Get instance by ID, failing with [`ObjectStoreError::InstanceNotFound`]
+    /// if it does not exist
+    pub async fn require_instance(&self, schema_name: &str, instance_id: &str) -> Result<Instance> {
+        self.get_instance(schema_name, instance_id)
+            .await?
+            .ok_or_else(|| ObjectStoreError::instance_not_found(instance_id))
+    }
+
+    /// Get a single column value of an instance without loading the whole row
+    ///
+    /// Returns `Ok(None)` if the instance does not exist (or is soft-deleted)
+    /// and `Some(Value::Null)` if it exists with a NULL value. The column must
+    /// be defined in the schema.
+    pub async fn get_field(
+        &self,
+        schema_name: &str,
+        instance_id: &str,
+        column: &str,
+    ) -> Result<Option<serde_json::Value>> {
+        let schema = self.require_schema(schema_name).await?;
+
+        let col = schema
+            .columns
+            .iter()
+            .find(|c| c.name == column)
+            .ok_or_else(|| {
+                ObjectStoreError::validation(format!(
+                    "Column '{}' does not exist in schema '{}'",
+                    column, schema.name
+                ))
+            })?;
+
+        let where_clause = if self.config.soft_delete {
+            "id = $1 AND deleted = FALSE"
+        } else {
+            "id = $1"
+        };
+        let select_sql = format!(
+            "SELECT {} FROM {} WHERE {}",
+            column_select("", col, &col.name),
+            quote_identifier(&schema.table_name),
+            where_clause
         );
 
         let row = sqlx::query(&select_sql)
@@ -581,15 +1661,17 @@ impl ObjectStore {
             .fetch_optional(&self.pool)
             .await?;
 
-        Ok(row.map(|row| self.row_to_instance(&row, &schema)))
+        row.map(|row| {
+            Ok(self
+                .extract_column_value(&row, col)?
+                .unwrap_or(serde_json::Value::Null))
+        })
+        .transpose()
     }
 
     /// Query instances using simple filters
     pub async fn query_instances(&self, filter: SimpleFilter) -> Result<(Vec<Instance>, i64)> {
-        let schema = self
-            .get_schema(&filter.schema_name)
-            .await?
-            .ok_or_else(|| ObjectStoreError::schema_not_found(&filter.schema_name))?;
+        let schema = self.require_schema(&filter.schema_name).await?;
 
         let filter_request = filter.to_filter_request();
         self.filter_instances_internal(&schema, filter_request)
@@ -597,72 +1679,466 @@ impl ObjectStore {
     }
 
     /// Filter instances with condition
+    ///
+    /// A `limit` of 0 returns only the total count, without fetching rows.
     pub async fn filter_instances(
         &self,
         schema_name: &str,
         filter: FilterRequest,
     ) -> Result<(Vec<Instance>, i64)> {
-        let schema = self
-            .get_schema(schema_name)
-            .await?
-            .ok_or_else(|| ObjectStoreError::schema_not_found(schema_name))?;
+        let schema = self.require_schema(schema_name).await?;
 
         self.filter_instances_internal(&schema, filter).await
     }
 
-    /// Check if an instance exists matching the filters
-    pub async fn instance_exists(&self, filter: SimpleFilter) -> Result<Option<Instance>> {
-        let mut filter = filter;
-        filter.limit = 1;
-        let (instances, _) = self.query_instances(filter).await?;
-        Ok(instances.into_iter().next())
+    /// Filter instances, returning each as a flat JSON object
+    ///
+    /// Same as [`filter_instances`](Self::filter_instances), with every
+    /// instance converted by [`Instance::to_flat_json`].
+    pub async fn filter_instances_flat(
+        &self,
+        schema_name: &str,
+        filter: FilterRequest,
+    ) -> Result<(Vec<serde_json::Value>, i64)> {
+        let (instances, total) = self.filter_instances(schema_name, filter).await?;
+        let flat = instances.iter().map(Instance::to_flat_json).collect();
+        Ok((flat, total))
     }
 
-    /// Update an instance
-    pub async fn update_instance(
+    /// Fetch the first page of matching instances with page metadata
+    ///
+    /// `sort` lists `(field, order)` pairs, e.g. `("created_at", "desc")`. The
+    /// returned [`Page`] carries the total count, page count, and whether more
+    /// pages follow.
+    pub async fn first_page(
         &self,
         schema_name: &str,
-        instance_id: &str,
-        properties: serde_json::Value,
-    ) -> Result<()> {
-        let schema = self
-            .get_schema(schema_name)
-            .await?
-            .ok_or_else(|| ObjectStoreError::schema_not_found(schema_name))?;
+        condition: Option<Condition>,
+        sort: &[(&str, &str)],
+        page_size: i64,
+    ) -> Result<Page<Instance>> {
+        if page_size <= 0 {
+            return Err(ObjectStoreError::validation(
+                "Page size must be greater than zero",
+            ));
+        }
 
-        let properties_obj = properties
-            .as_object()
-            .ok_or_else(|| ObjectStoreError::validation("Properties must be a JSON object"))?;
+        let schema = self.require_schema(schema_name).await?;
 
-        let mut set_clauses = Vec::new();
-        let mut param_idx = 2; // $1 = instance_id
+        let (sort_by, sort_order) = if sort.is_empty() {
+            (None, None)
+        } else {
+            (
+                Some(sort.iter().map(|(field, _)| field.to_string()).collect()),
+                Some(sort.iter().map(|(_, order)| order.to_string()).collect()),
+            )
+        };
+        let filter = FilterRequest {
+            offset: 0,
+            limit: page_size,
+            condition,
+            sort_by,
+            sort_order,
+            computed: Vec::new(),
+            unique_sort: false,
+        };
 
-        if self.config.auto_columns.updated_at {
-            set_clauses.push("updated_at = NOW()".to_string());
-        }
+        let (items, total) = self.filter_instances_internal(&schema, filter).await?;
 
-        for col in &schema.columns {
-            if let Some(value) = properties_obj.get(&col.name) {
-                // Validate type
-                if let Err(e) = col.column_type.validate_value(value) {
-                    return Err(ObjectStoreError::validation(format!(
-                        "Invalid value for column '{}': {}",
-                        col.name, e
-                    )));
-                }
+        Ok(Page::new(items, total, page_size, 0))
+    }
 
-                set_clauses.push(format!("{} = ${}", quote_identifier(&col.name), param_idx));
-                param_idx += 1;
-            }
+    /// Fetch a page of instances using keyset (cursor) pagination
+    ///
+    /// Rows are ordered by `(created_at, id)` and the page starts just after
+    /// `after_cursor`, or at the first row when it is `None`. Unlike OFFSET
+    /// pagination, later pages cost no more than the first. The filter's
+    /// condition, computed fields and `limit` (the page size) apply; its sort
+    /// and offset are ignored, and no total is counted.
+    ///
+    /// Returns the page and the cursor for the next one, which is `None` once
+    /// no rows follow. Requires the auto-managed `id` and `created_at` columns.
+    pub async fn query_instances_after(
+        &self,
+        schema_name: &str,
+        filter: FilterRequest,
+        after_cursor: Option<Cursor>,
+    ) -> Result<(Vec<Instance>, Option<Cursor>)> {
+        if !self.config.auto_columns.id || !self.config.auto_columns.created_at {
+            return Err(ObjectStoreError::validation(
+                "Cursor pagination requires the auto-managed id and created_at columns",
+            ));
+        }
+        if filter.limit <= 0 {
+            return Err(ObjectStoreError::validation(
+                "Page size must be greater than zero",
+            ));
         }
 
-        if set_clauses.is_empty() || (set_clauses.len() == 1 && self.config.auto_columns.updated_at)
-        {
-            return Ok(()); // Nothing to update
+        let schema = self.require_schema(schema_name).await?;
+        let select_query = QueryBuilder::new(&schema, &self.config, &filter)
+            .build_select_after(after_cursor.as_ref())?;
+
+        let mut query = sqlx::query(&select_query.sql);
+        for param in &select_query.params {
+            query = query.bind(param);
         }
+        let mut rows = query.fetch_all(&self.pool).await?;
+
+        // The query fetches one extra row to tell whether another page follows
+        let has_more = rows.len() as i64 > filter.limit;
+        rows.truncate(filter.limit as usize);
+
+        let next_cursor = match rows.last() {
+            Some(last) if has_more => Some(Cursor::new(
+                last.try_get("created_at")?,
+                last.try_get::<String, _>("id")?,
+            )),
+            _ => None,
+        };
 
-        let where_clause = if self.config.soft_delete {
-            "id = $1 AND deleted = FALSE"
+        let instances = rows
+            .iter()
+            .map(|row| self.row_to_filtered_instance(row, &schema, &filter))
+            .collect::<Result<Vec<Instance>>>()?;
+
+        Ok((instances, next_cursor))
+    }
+
+    /// Check if an instance exists matching the filters
+    ///
+    /// Returns the first matching instance, or `Ok(None)` if nothing matches.
+    /// A missing schema is reported as an error, as in
+    /// [`get_instance`](Self::get_instance).
+    pub async fn instance_exists(&self, filter: SimpleFilter) -> Result<Option<Instance>> {
+        let mut filter = filter;
+        filter.limit = 1;
+        let (instances, _) = self.query_instances(filter).await?;
+        Ok(instances.into_iter().next())
+    }
+
+    /// Query instances joined with a related schema through a foreign key
+    ///
+    /// `fk_column` must be a column of `base_schema` declared with
+    /// [`ColumnDefinition::references`] pointing at the related schema's table.
+    /// Only rows with a matching related row are returned (INNER JOIN).
+    /// The requested `related_columns` are placed in each instance's `properties`
+    /// under a nested object keyed by the related schema name, so a base column
+    /// with that name is rejected.
+    pub async fn join_query(
+        &self,
+        base_schema: &str,
+        fk_column: &str,
+        related_schema: &str,
+        related_columns: &[String],
+    ) -> Result<Vec<Instance>> {
+        let base = self.require_schema(base_schema).await?;
+        let related = self.require_schema(related_schema).await?;
+
+        if !self.config.auto_columns.id {
+            return Err(ObjectStoreError::validation(
+                "Joins require the auto-managed id column",
+            ));
+        }
+
+        let fk = base
+            .columns
+            .iter()
+            .find(|c| c.name == fk_column)
+            .ok_or_else(|| {
+                ObjectStoreError::validation(format!(
+                    "Column '{}' does not exist in schema '{}'",
+                    fk_column, base.name
+                ))
+            })?;
+
+        if fk.references.as_deref() != Some(related.table_name.as_str()) {
+            return Err(ObjectStoreError::validation(format!(
+                "Column '{}' does not reference schema '{}'",
+                fk_column, related.name
+            )));
+        }
+
+        if base.columns.iter().any(|c| c.name == related.name) {
+            return Err(ObjectStoreError::validation(format!(
+                "Column '{}' of schema '{}' would be overwritten by the joined '{}' fields",
+                related.name, base.name, related.name
+            )));
+        }
+
+        // Alias joined columns so they can't collide with base columns in the row
+        let mut joined_columns = Vec::with_capacity(related_columns.len());
+        for name in related_columns {
+            let col = related
+                .columns
+                .iter()
+                .find(|c| &c.name == name)
+                .ok_or_else(|| {
+                    ObjectStoreError::validation(format!(
+                        "Column '{}' does not exist in schema '{}'",
+                        name, related.name
+                    ))
+                })?;
+            let aliased = ColumnDefinition {
+                name: format!("__joined_{}", col.name),
+                ..col.clone()
+            };
+            joined_columns.push((col, aliased));
+        }
+
+        let mut select_columns = vec!["b.id".to_string()];
+        if self.config.auto_columns.created_at {
+            select_columns.push("b.created_at".to_string());
+        }
+        if self.config.auto_columns.updated_at {
+            select_columns.push("b.updated_at".to_string());
+        }
+        for col in &base.columns {
+            select_columns.push(column_select("b.", col, &col.name));
+        }
+        for (col, aliased) in &joined_columns {
+            select_columns.push(column_select("r.", col, &aliased.name));
+        }
+
+        let where_clause = if self.config.soft_delete {
+            " WHERE b.deleted = FALSE AND r.deleted = FALSE"
+        } else {
+            ""
+        };
+        let order_clause = if self.config.auto_columns.created_at {
+            " ORDER BY b.created_at ASC"
+        } else {
+            ""
+        };
+
+        let select_sql = format!(
+            "SELECT {} FROM {} b INNER JOIN {} r ON b.{} = r.id{}{}",
+            select_columns.join(", "),
+            quote_identifier(&base.table_name),
+            quote_identifier(&related.table_name),
+            quote_identifier(fk_column),
+            where_clause,
+            order_clause
+        );
+
+        let rows = sqlx::query(&select_sql).fetch_all(&self.pool).await?;
+
+        rows.iter()
+            .map(|row| {
+                let mut instance = self.row_to_instance(row, &base)?;
+                let mut joined = serde_json::Map::new();
+                for (col, aliased) in &joined_columns {
+                    if let Some(value) = self.extract_column_value(row, aliased)? {
+                        joined.insert(col.name.clone(), value);
+                    }
+                }
+                if let Some(properties) = instance.properties.as_object_mut() {
+                    properties.insert(related.name.clone(), serde_json::Value::Object(joined));
+                }
+                Ok(instance)
+            })
+            .collect()
+    }
+
+    /// Update an instance
+    pub async fn update_instance(
+        &self,
+        schema_name: &str,
+        instance_id: &str,
+        properties: serde_json::Value,
+    ) -> Result<()> {
+        let schema = self.require_schema(schema_name).await?;
+        let mut tx = self.pool.begin().await?;
+        self.update_instance_with(&mut tx, &schema, instance_id, &properties)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Update an instance on a caller-owned connection or transaction
+    ///
+    /// See [`create_instance_tx`](Self::create_instance_tx).
+    pub async fn update_instance_tx(
+        &self,
+        conn: &mut PgConnection,
+        schema_name: &str,
+        instance_id: &str,
+        properties: serde_json::Value,
+    ) -> Result<()> {
+        let schema = self.require_schema(schema_name).await?;
+        self.update_instance_with(conn, &schema, instance_id, &properties)
+            .await
+    }
+
+    /// Update an instance only if it still has the given [`Instance::etag`]
+    ///
+    /// The current row is locked and re-read in the same transaction as the
+    /// update, so a concurrent change between the check and the write is not
+    /// possible. Returns [`ObjectStoreError::Conflict`] when the etag is stale.
+    pub async fn update_instance_if_match(
+        &self,
+        schema_name: &str,
+        instance_id: &str,
+        properties: serde_json::Value,
+        etag: &str,
+    ) -> Result<()> {
+        let schema = self.require_schema(schema_name).await?;
+
+        let mut tx = self.pool.begin().await?;
+
+        let select_sql = format!("{} FOR UPDATE", self.instance_by_id_sql(&schema));
+        let current = sqlx::query(&select_sql)
+            .bind(instance_id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .map(|row| self.row_to_instance(&row, &schema))
+            .transpose()?
+            .ok_or_else(|| ObjectStoreError::instance_not_found(instance_id))?;
+
+        if current.etag() != etag {
+            return Err(ObjectStoreError::conflict(format!(
+                "Instance '{}' has been modified (etag mismatch)",
+                instance_id
+            )));
+        }
+
+        self.update_instance_with(&mut tx, &schema, instance_id, &properties)
+            .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Update an instance and report which columns actually changed
+    ///
+    /// The current row is locked and compared with `properties`; only columns
+    /// whose value differs are written, and their names are returned in schema
+    /// order. An empty list means nothing changed and the row (including
+    /// `updated_at`) was left untouched.
+    pub async fn update_instance_diff(
+        &self,
+        schema_name: &str,
+        instance_id: &str,
+        properties: serde_json::Value,
+    ) -> Result<Vec<String>> {
+        let schema = self.require_schema(schema_name).await?;
+        let properties_obj = properties
+            .as_object()
+            .ok_or_else(|| ObjectStoreError::validation("Properties must be a JSON object"))?;
+
+        let mut tx = self.pool.begin().await?;
+
+        let select_sql = format!("{} FOR UPDATE", self.instance_by_id_sql(&schema));
+        let current = sqlx::query(&select_sql)
+            .bind(instance_id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .map(|row| self.row_to_instance(&row, &schema))
+            .transpose()?;
+
+        let Some(current) = current else {
+            if self.config.soft_delete {
+                let probe_sql = format!(
+                    "SELECT deleted FROM {} WHERE id = $1",
+                    quote_identifier(&schema.table_name)
+                );
+                let deleted: Option<bool> = sqlx::query_scalar(&probe_sql)
+                    .bind(instance_id)
+                    .fetch_optional(&mut *tx)
+                    .await?;
+                if deleted == Some(true) {
+                    return Err(ObjectStoreError::instance_deleted(instance_id));
+                }
+            }
+            return Err(ObjectStoreError::instance_not_found(instance_id));
+        };
+
+        let mut changed = serde_json::Map::new();
+        let mut changed_columns = Vec::new();
+        for col in &schema.columns {
+            let Some(value) = properties_obj.get(&col.name) else {
+                continue;
+            };
+            let value = col.normalize_value(value);
+            let stored = current.properties.get(&col.name);
+            if !Self::stored_value_matches(&col.column_type, stored, &value) {
+                changed.insert(col.name.clone(), value.into_owned());
+                changed_columns.push(col.name.clone());
+            }
+        }
+
+        if changed_columns.is_empty() {
+            return Ok(changed_columns);
+        }
+
+        self.update_instance_with(
+            &mut tx,
+            &schema,
+            instance_id,
+            &serde_json::Value::Object(changed),
+        )
+        .await?;
+
+        tx.commit().await?;
+        Ok(changed_columns)
+    }
+
+    /// Apply a partial update to one instance on the given connection
+    ///
+    /// When no row is updated, the row is probed on the same connection to
+    /// report [`ObjectStoreError::InstanceDeleted`] for soft-deleted instances
+    /// and [`ObjectStoreError::InstanceNotFound`] for missing ones.
+    async fn update_instance_with(
+        &self,
+        conn: &mut PgConnection,
+        schema: &Schema,
+        instance_id: &str,
+        properties: &serde_json::Value,
+    ) -> Result<()> {
+        let properties_obj = properties
+            .as_object()
+            .ok_or_else(|| ObjectStoreError::validation("Properties must be a JSON object"))?;
+
+        let mut set_clauses = Vec::new();
+        let mut param_idx = 2; // $1 = instance_id
+
+        if self.config.auto_columns.updated_at {
+            set_clauses.push("updated_at = NOW()".to_string());
+        }
+
+        for col in &schema.columns {
+            if let Some(value) = properties_obj.get(&col.name) {
+                if col.immutable {
+                    return Err(ObjectStoreError::validation(format!(
+                        "Column '{}' is immutable and cannot be updated",
+                        col.name
+                    )));
+                }
+
+                // Validate type
+                if let Err(e) = col.validate_value(value) {
+                    return Err(ObjectStoreError::validation(format!(
+                        "Invalid value for column '{}': {}",
+                        col.name, e
+                    )));
+                }
+
+                set_clauses.push(format!(
+                    "{} = {}",
+                    quote_identifier(&col.name),
+                    col.column_type.placeholder(param_idx)
+                ));
+                param_idx += 1;
+            }
+        }
+
+        if set_clauses.is_empty() || (set_clauses.len() == 1 && self.config.auto_columns.updated_at)
+        {
+            return Ok(()); // Nothing to update
+        }
+
+        let where_clause = if self.config.soft_delete {
+            "id = $1 AND deleted = FALSE"
         } else {
             "id = $1"
         };
@@ -678,14 +2154,31 @@ impl ObjectStore {
 
         for col in &schema.columns {
             if let Some(value) = properties_obj.get(&col.name) {
-                query = Self::bind_value(query, &col.column_type, &col.name, value)?;
+                query = Self::bind_value(query, col, value)?;
             }
         }
 
-        let result = query.execute(&self.pool).await?;
+        let result = query.execute(&mut *conn).await?;
 
         if result.rows_affected() == 0 {
-            return Err(ObjectStoreError::instance_not_found(instance_id));
+            if !self.config.soft_delete {
+                return Err(ObjectStoreError::instance_not_found(instance_id));
+            }
+
+            let probe_sql = format!(
+                "SELECT deleted FROM {} WHERE id = $1",
+                quote_identifier(&schema.table_name)
+            );
+            let deleted: Option<bool> = sqlx::query_scalar(&probe_sql)
+                .bind(instance_id)
+                .fetch_optional(&mut *conn)
+                .await?;
+
+            return match deleted {
+                Some(true) => Err(ObjectStoreError::instance_deleted(instance_id)),
+                Some(false) => Ok(()),
+                None => Err(ObjectStoreError::instance_not_found(instance_id)),
+            };
         }
 
         Ok(())
@@ -696,10 +2189,7 @@ impl ObjectStore {
     /// If soft_delete is enabled, marks the instance as deleted.
     /// Otherwise, removes the row from the table.
     pub async fn delete_instance(&self, schema_name: &str, instance_id: &str) -> Result<()> {
-        let schema = self
-            .get_schema(schema_name)
-            .await?
-            .ok_or_else(|| ObjectStoreError::schema_not_found(schema_name))?;
+        let schema = self.require_schema(schema_name).await?;
 
         let result = if self.config.soft_delete {
             let update_set = if self.config.auto_columns.updated_at {
@@ -737,6 +2227,122 @@ impl ObjectStore {
         Ok(())
     }
 
+    /// Delete an instance together with every instance that references it
+    ///
+    /// Follows [`ColumnDefinition::references`] across all schemas, collecting
+    /// referencing rows recursively, and deletes (or soft-deletes) them in one
+    /// transaction, children before parents. Rows already collected are not
+    /// visited again, so cyclic relationships terminate. Rows that reference
+    /// each other directly cannot be hard-deleted; the foreign key violation
+    /// rolls the whole operation back.
+    ///
+    /// # Returns
+    /// Number of affected rows per schema name
+    pub async fn delete_instance_cascade(
+        &self,
+        schema_name: &str,
+        instance_id: &str,
+    ) -> Result<HashMap<String, i64>> {
+        if !self.config.auto_columns.id {
+            return Err(ObjectStoreError::validation(
+                "Cascade delete requires the id auto column",
+            ));
+        }
+
+        let root = self.require_schema(schema_name).await?;
+        let schemas = self.list_schemas().await?;
+        let live_filter = if self.config.soft_delete {
+            " AND deleted = FALSE"
+        } else {
+            ""
+        };
+
+        let mut tx = self.pool.begin().await?;
+
+        let root_sql = format!(
+            "SELECT id FROM {} WHERE id = $1{} FOR UPDATE",
+            quote_identifier(&root.table_name),
+            live_filter
+        );
+        let found: Option<String> = sqlx::query_scalar(&root_sql)
+            .bind(instance_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+        if found.is_none() {
+            return Err(ObjectStoreError::instance_not_found(instance_id));
+        }
+
+        // Breadth-first walk; each batch holds rows referencing an earlier batch
+        let mut visited: HashMap<&str, HashSet<String>> = HashMap::new();
+        visited
+            .entry(root.table_name.as_str())
+            .or_default()
+            .insert(instance_id.to_string());
+        let mut batches: Vec<(&Schema, Vec<String>)> = vec![(&root, vec![instance_id.to_string()])];
+        let mut next = 0;
+
+        while next < batches.len() {
+            let (parent, parent_ids) = (batches[next].0, batches[next].1.clone());
+            next += 1;
+
+            for child in &schemas {
+                for col in child
+                    .columns
+                    .iter()
+                    .filter(|c| c.references.as_deref() == Some(parent.table_name.as_str()))
+                {
+                    let select_sql = format!(
+                        "SELECT id FROM {} WHERE {} = ANY($1){} FOR UPDATE",
+                        quote_identifier(&child.table_name),
+                        quote_identifier(&col.name),
+                        live_filter
+                    );
+                    let child_ids: Vec<String> = sqlx::query_scalar(&select_sql)
+                        .bind(&parent_ids)
+                        .fetch_all(&mut *tx)
+                        .await?;
+
+                    let seen = visited.entry(child.table_name.as_str()).or_default();
+                    let new_ids: Vec<String> = child_ids
+                        .into_iter()
+                        .filter(|id| seen.insert(id.clone()))
+                        .collect();
+                    if !new_ids.is_empty() {
+                        batches.push((child, new_ids));
+                    }
+                }
+            }
+        }
+
+        let mut affected: HashMap<String, i64> = HashMap::new();
+        for (schema, ids) in batches.iter().rev() {
+            let delete_sql = if self.config.soft_delete {
+                let update_set = if self.config.auto_columns.updated_at {
+                    "deleted = TRUE, updated_at = NOW()"
+                } else {
+                    "deleted = TRUE"
+                };
+                format!(
+                    "UPDATE {} SET {} WHERE id = ANY($1) AND deleted = FALSE",
+                    quote_identifier(&schema.table_name),
+                    update_set
+                )
+            } else {
+                format!(
+                    "DELETE FROM {} WHERE id = ANY($1)",
+                    quote_identifier(&schema.table_name)
+                )
+            };
+
+            let result = sqlx::query(&delete_sql).bind(ids).execute(&mut *tx).await?;
+            *affected.entry(schema.name.clone()).or_default() += result.rows_affected() as i64;
+        }
+
+        tx.commit().await?;
+
+        Ok(affected)
+    }
+
     // =========================================================================
     // Bulk Operations
     // =========================================================================
@@ -759,11 +2365,63 @@ impl ObjectStore {
         properties: serde_json::Value,
         condition: Condition,
     ) -> Result<i64> {
-        let schema = self
-            .get_schema(schema_name)
-            .await?
-            .ok_or_else(|| ObjectStoreError::schema_not_found(schema_name))?;
+        let schema = self.require_schema(schema_name).await?;
+
+        let Some(update) = self.bulk_update(&schema, &properties, &condition, None)? else {
+            return Ok(0); // Nothing to update
+        };
+
+        // Start transaction
+        let mut tx = self.pool.begin().await?;
+
+        let result = Self::bind_bulk_update(&update)?.execute(&mut *tx).await?;
+        tx.commit().await?;
 
+        Ok(result.rows_affected() as i64)
+    }
+
+    /// Update instances matching a condition and return them as stored
+    ///
+    /// Same as [`update_instances`](Self::update_instances), with every
+    /// updated row read back through `RETURNING`, so the instances carry the
+    /// new values and `updated_at`. All matched rows are materialized in
+    /// memory; use `update_instances` when only the count is needed. Returns
+    /// an empty list when `properties` names no schema column.
+    pub async fn update_instances_returning(
+        &self,
+        schema_name: &str,
+        properties: serde_json::Value,
+        condition: Condition,
+    ) -> Result<Vec<Instance>> {
+        let schema = self.require_schema(schema_name).await?;
+
+        let returning = QueryBuilder::new(&schema, &self.config, &FilterRequest::default())
+            .select_columns()
+            .join(", ");
+        let Some(update) = self.bulk_update(&schema, &properties, &condition, Some(&returning))?
+        else {
+            return Ok(Vec::new());
+        };
+
+        let mut tx = self.pool.begin().await?;
+        let rows = Self::bind_bulk_update(&update)?.fetch_all(&mut *tx).await?;
+        tx.commit().await?;
+
+        rows.iter()
+            .map(|row| self.row_to_instance(row, &schema))
+            .collect()
+    }
+
+    /// Validate properties and build the UPDATE for a condition
+    ///
+    /// Returns `None` when `properties` names no schema column.
+    fn bulk_update<'a>(
+        &self,
+        schema: &'a Schema,
+        properties: &'a serde_json::Value,
+        condition: &Condition,
+        returning: Option<&str>,
+    ) -> Result<Option<BulkUpdate<'a>>> {
         let properties_obj = properties
             .as_object()
             .ok_or_else(|| ObjectStoreError::validation("Properties must be a JSON object"))?;
@@ -779,28 +2437,45 @@ impl ObjectStore {
 
         for col in &schema.columns {
             if let Some(value) = properties_obj.get(&col.name) {
+                if col.immutable {
+                    return Err(ObjectStoreError::validation(format!(
+                        "Column '{}' is immutable and cannot be updated",
+                        col.name
+                    )));
+                }
+
                 // Validate type
-                if let Err(e) = col.column_type.validate_value(value) {
+                if let Err(e) = col.validate_value(value) {
                     return Err(ObjectStoreError::validation(format!(
                         "Invalid value for column '{}': {}",
                         col.name, e
                     )));
                 }
 
-                set_clauses.push(format!("{} = ${}", quote_identifier(&col.name), param_idx));
+                if !col.nullable && value.is_null() {
+                    return Err(ObjectStoreError::validation(format!(
+                        "Column '{}' does not allow NULL values",
+                        col.name
+                    )));
+                }
+
+                set_clauses.push(format!(
+                    "{} = {}",
+                    quote_identifier(&col.name),
+                    col.column_type.placeholder(param_idx)
+                ));
                 set_values.push((col, value));
                 param_idx += 1;
             }
         }
 
-        if set_clauses.is_empty() || (set_clauses.len() == 1 && self.config.auto_columns.updated_at)
-        {
-            return Ok(0); // Nothing to update
+        if set_values.is_empty() {
+            return Ok(None);
         }
 
         // Build WHERE clause from condition
         let (where_clause, condition_params) =
-            build_condition_clause(&condition, &mut param_idx, &schema)
+            build_condition_clause(condition, &mut param_idx, schema)
                 .map_err(ObjectStoreError::InvalidCondition)?;
 
         let base_where = if self.config.soft_delete {
@@ -809,37 +2484,82 @@ impl ObjectStore {
             format!("({})", where_clause)
         };
 
-        let update_sql = format!(
+        let mut sql = format!(
             "UPDATE {} SET {} WHERE {}",
             quote_identifier(&schema.table_name),
             set_clauses.join(", "),
             base_where
         );
+        if let Some(returning) = returning {
+            sql.push_str(" RETURNING ");
+            sql.push_str(returning);
+        }
 
-        // Start transaction
-        let mut tx = self.pool.begin().await?;
-
-        // Build and execute query
-        let mut query = sqlx::query(&update_sql);
+        Ok(Some(BulkUpdate {
+            sql,
+            set_values,
+            condition_params,
+        }))
+    }
 
-        // Bind SET values
-        for (col, value) in &set_values {
-            query = Self::bind_value(query, &col.column_type, &col.name, value)?;
+    /// Bind a [`BulkUpdate`]'s SET values followed by its condition params
+    fn bind_bulk_update<'q>(
+        update: &'q BulkUpdate<'q>,
+    ) -> Result<sqlx::query::Query<'q, sqlx::Postgres, sqlx::postgres::PgArguments>> {
+        let mut query = sqlx::query(&update.sql);
+        for (col, value) in &update.set_values {
+            query = Self::bind_value(query, col, value)?;
         }
-
-        // Bind condition params
-        for param in &condition_params {
-            let param_str = match param {
-                serde_json::Value::String(s) => s.clone(),
-                other => other.to_string(),
-            };
-            query = query.bind(param_str);
+        for param in &update.condition_params {
+            query = query.bind(param);
         }
+        Ok(query)
+    }
 
-        let result = query.execute(&mut *tx).await?;
-        tx.commit().await?;
+    /// Set a single column to a value on every instance matching a condition
+    ///
+    /// Shorthand for [`update_instances`](Self::update_instances) with a
+    /// one-field property map. Unlike `update_instances`, an unknown column is
+    /// an error rather than being ignored.
+    ///
+    /// # Returns
+    /// Number of affected rows
+    pub async fn set_field_where(
+        &self,
+        schema_name: &str,
+        column: &str,
+        value: serde_json::Value,
+        condition: Condition,
+    ) -> Result<i64> {
+        let schema = self.require_schema(schema_name).await?;
 
-        Ok(result.rows_affected() as i64)
+        let col = schema
+            .columns
+            .iter()
+            .find(|c| c.name == column)
+            .ok_or_else(|| {
+                ObjectStoreError::validation(format!(
+                    "Column '{}' does not exist in schema '{}'",
+                    column, schema.name
+                ))
+            })?;
+
+        if let Err(e) = col.validate_value(&value) {
+            return Err(ObjectStoreError::validation(format!(
+                "Invalid value for column '{}': {}",
+                col.name, e
+            )));
+        }
+
+        let mut properties = serde_json::Map::new();
+        properties.insert(col.name.clone(), value);
+
+        self.update_instances(
+            schema_name,
+            serde_json::Value::Object(properties),
+            condition,
+        )
+        .await
     }
 
     /// Delete multiple instances matching a condition
@@ -857,72 +2577,144 @@ impl ObjectStore {
     /// # Returns
     /// Number of affected rows
     pub async fn delete_instances(&self, schema_name: &str, condition: Condition) -> Result<i64> {
-        let schema = self
-            .get_schema(schema_name)
-            .await?
-            .ok_or_else(|| ObjectStoreError::schema_not_found(schema_name))?;
+        let schema = self.require_schema(schema_name).await?;
+
+        let mut tx = self.pool.begin().await?;
+        let affected = self
+            .delete_instances_with(&mut tx, &schema, &condition)
+            .await?;
+        tx.commit().await?;
+
+        Ok(affected)
+    }
+
+    /// Delete instances matching a condition in several schemas atomically
+    ///
+    /// Each `(schema_name, condition)` pair is deleted like
+    /// [`delete_instances`](Self::delete_instances), all in one transaction:
+    /// if any schema is missing or any delete fails, nothing is deleted.
+    /// Returns the affected row count per schema name; a schema listed more
+    /// than once reports the sum of its deletes.
+    pub async fn delete_across(
+        &self,
+        schema_to_condition: Vec<(String, Condition)>,
+    ) -> Result<HashMap<String, i64>> {
+        let mut schemas = Vec::with_capacity(schema_to_condition.len());
+        for (schema_name, condition) in schema_to_condition {
+            let schema = self.require_schema(&schema_name).await?;
+            schemas.push((schema_name, schema, condition));
+        }
+
+        let mut tx = self.pool.begin().await?;
+        let mut affected: HashMap<String, i64> = HashMap::new();
+        for (schema_name, schema, condition) in &schemas {
+            let count = self
+                .delete_instances_with(&mut tx, schema, condition)
+                .await?;
+            *affected.entry(schema_name.clone()).or_insert(0) += count;
+        }
+        tx.commit().await?;
+
+        Ok(affected)
+    }
 
+    /// Delete (or soft-delete) the rows matching a condition on the given connection
+    async fn delete_instances_with(
+        &self,
+        conn: &mut PgConnection,
+        schema: &Schema,
+        condition: &Condition,
+    ) -> Result<i64> {
         // Build WHERE clause from condition
         let mut param_offset = 1i32;
         let (where_clause, condition_params) =
-            build_condition_clause(&condition, &mut param_offset, &schema)
+            build_condition_clause(condition, &mut param_offset, schema)
                 .map_err(ObjectStoreError::InvalidCondition)?;
 
-        let mut tx = self.pool.begin().await?;
-
-        let result = if self.config.soft_delete {
+        let delete_sql = if self.config.soft_delete {
             let update_set = if self.config.auto_columns.updated_at {
                 "deleted = TRUE, updated_at = NOW()"
             } else {
                 "deleted = TRUE"
             };
 
-            let base_where = format!("deleted = FALSE AND ({})", where_clause);
-
-            let delete_sql = format!(
-                "UPDATE {} SET {} WHERE {}",
+            format!(
+                "UPDATE {} SET {} WHERE deleted = FALSE AND ({})",
                 quote_identifier(&schema.table_name),
                 update_set,
-                base_where
-            );
-
-            let mut query = sqlx::query(&delete_sql);
-            for param in &condition_params {
-                let param_str = match param {
-                    serde_json::Value::String(s) => s.clone(),
-                    other => other.to_string(),
-                };
-                query = query.bind(param_str);
-            }
-            query.execute(&mut *tx).await?
+                where_clause
+            )
         } else {
-            let delete_sql = format!(
+            format!(
                 "DELETE FROM {} WHERE ({})",
                 quote_identifier(&schema.table_name),
                 where_clause
-            );
-
-            let mut query = sqlx::query(&delete_sql);
-            for param in &condition_params {
-                let param_str = match param {
-                    serde_json::Value::String(s) => s.clone(),
-                    other => other.to_string(),
-                };
-                query = query.bind(param_str);
-            }
-            query.execute(&mut *tx).await?
+            )
         };
 
-        tx.commit().await?;
+        let mut query = sqlx::query(&delete_sql);
+        for param in &condition_params {
+            query = query.bind(param);
+        }
+        let result = query.execute(conn).await?;
 
         Ok(result.rows_affected() as i64)
     }
 
+    /// Count active and soft-deleted instances in one query
+    ///
+    /// Returns `(active, deleted)`. Requires soft delete to be enabled.
+    pub async fn count_by_deleted(&self, schema_name: &str) -> Result<(i64, i64)> {
+        if !self.config.soft_delete {
+            return Err(ObjectStoreError::validation(
+                "Deleted counts require soft delete to be enabled",
+            ));
+        }
+
+        let schema = self.require_schema(schema_name).await?;
+
+        let sql = format!(
+            "SELECT COUNT(*) FILTER (WHERE deleted = FALSE), COUNT(*) FILTER (WHERE deleted = TRUE) FROM {}",
+            quote_identifier(&schema.table_name)
+        );
+        let counts: (i64, i64) = sqlx::query_as(&sql).fetch_one(&self.pool).await?;
+
+        Ok(counts)
+    }
+
+    /// Count the instances [`delete_instances`](Self::delete_instances) would remove
+    ///
+    /// Runs the same condition and soft-delete filter as a `SELECT COUNT(*)`
+    /// without modifying any rows.
+    pub async fn delete_instances_preview(
+        &self,
+        schema_name: &str,
+        condition: Condition,
+    ) -> Result<i64> {
+        let schema = self.require_schema(schema_name).await?;
+
+        let filter = FilterRequest::new().with_condition(condition);
+        let count_query = QueryBuilder::new(&schema, &self.config, &filter).build_count()?;
+
+        let mut query = sqlx::query_as::<_, (i64,)>(&count_query.sql);
+        for param in &count_query.params {
+            query = query.bind(param);
+        }
+        let (count,) = query.fetch_one(&self.pool).await?;
+
+        Ok(count)
+    }
+
     /// Create multiple instances in a single transaction
     ///
     /// All instances are validated before any are inserted.
     /// If validation fails for any instance, no instances are created.
     ///
+    /// When the auto timestamp columns are enabled, an instance may carry
+    /// explicit `createdAt`/`updatedAt` values (RFC3339) to preserve original
+    /// timestamps on import. Missing values fall back to `NOW()`; a missing
+    /// `updatedAt` falls back to the explicit `createdAt` if one was given.
+    ///
     /// # Arguments
     /// * `schema_name` - Name of the schema
     /// * `instances` - Vector of JSON objects to insert
@@ -938,117 +2730,279 @@ impl ObjectStore {
             return Ok(0);
         }
 
-        let schema = self
-            .get_schema(schema_name)
-            .await?
-            .ok_or_else(|| ObjectStoreError::schema_not_found(schema_name))?;
+        let schema = self.require_schema(schema_name).await?;
 
         // Pre-validate all instances and generate IDs
-        let mut validated_instances: Vec<(String, serde_json::Map<String, serde_json::Value>)> =
-            Vec::with_capacity(instances.len());
+        let mut validated_instances: Vec<ValidatedInstance> = Vec::with_capacity(instances.len());
+        for (idx, instance) in instances.iter().enumerate() {
+            validated_instances.push(self.validate_bulk_instance(&schema, idx, instance)?);
+        }
+
+        let mut tx = self.pool.begin().await?;
+        let mut total_affected: i64 = 0;
+
+        // Process in chunks
+        for chunk in validated_instances.chunks(Self::bulk_chunk_size(&schema)) {
+            total_affected += self.insert_validated(&mut tx, &schema, chunk).await? as i64;
+        }
+
+        tx.commit().await?;
 
+        Ok(total_affected)
+    }
+
+    /// Replace every instance of a schema with the given rows
+    ///
+    /// For full-refresh syncs: in one transaction, all existing rows are
+    /// removed (hard-deleted, including soft-deleted ones, regardless of the
+    /// soft-delete setting) and the new rows are bulk-inserted as in
+    /// [`create_instances`](Self::create_instances). All rows are validated
+    /// before anything is deleted; on any failure the table is left unchanged.
+    ///
+    /// # Returns
+    /// Number of inserted rows
+    pub async fn replace_instances(
+        &self,
+        schema_name: &str,
+        instances: Vec<serde_json::Value>,
+    ) -> Result<i64> {
+        let schema = self.require_schema(schema_name).await?;
+
+        let mut validated_instances: Vec<ValidatedInstance> = Vec::with_capacity(instances.len());
         for (idx, instance) in instances.iter().enumerate() {
-            let properties_obj = instance.as_object().ok_or_else(|| {
-                ObjectStoreError::validation(format!(
-                    "Instance at index {} must be a JSON object",
-                    idx
-                ))
-            })?;
+            validated_instances.push(self.validate_bulk_instance(&schema, idx, instance)?);
+        }
 
-            // Validate each column
-            for col in &schema.columns {
-                if let Some(value) = properties_obj.get(&col.name) {
-                    if let Err(e) = col.column_type.validate_value(value) {
-                        return Err(ObjectStoreError::validation(format!(
+        let mut tx = self.pool.begin().await?;
+
+        let delete_sql = format!("DELETE FROM {}", quote_identifier(&schema.table_name));
+        sqlx::query(&delete_sql).execute(&mut *tx).await?;
+
+        let mut total_affected: i64 = 0;
+        for chunk in validated_instances.chunks(Self::bulk_chunk_size(&schema)) {
+            total_affected += self.insert_validated(&mut tx, &schema, chunk).await? as i64;
+        }
+
+        tx.commit().await?;
+
+        Ok(total_affected)
+    }
+
+    /// Rows per multi-row INSERT, keeping under PostgreSQL's ~32k parameter limit
+    fn bulk_chunk_size(schema: &Schema) -> usize {
+        let params_per_row = 3 + schema.columns.len(); // id + timestamps + columns
+        (32000 / params_per_row).max(1) // At least 1 row per chunk
+    }
+
+    /// Create multiple instances, keeping the valid ones
+    ///
+    /// Best-effort counterpart to `create_instances` for ingestion pipelines.
+    /// Each instance is validated and inserted on its own savepoint inside one
+    /// transaction: an invalid instance or a failed insert (e.g. a unique
+    /// violation) is reported in `BulkCreateResult::failed` with its index and
+    /// error, and the remaining instances are still inserted.
+    ///
+    /// # Arguments
+    /// * `schema_name` - Name of the schema
+    /// * `instances` - Vector of JSON objects to insert
+    ///
+    /// # Returns
+    /// IDs of the created instances, in input order, and the failures
+    pub async fn create_instances_best_effort(
+        &self,
+        schema_name: &str,
+        instances: Vec<serde_json::Value>,
+    ) -> Result<BulkCreateResult> {
+        let mut result = BulkCreateResult::default();
+        if instances.is_empty() {
+            return Ok(result);
+        }
+
+        let schema = self.require_schema(schema_name).await?;
+        let mut tx = self.pool.begin().await?;
+
+        for (idx, instance) in instances.iter().enumerate() {
+            let validated = match self.validate_bulk_instance(&schema, idx, instance) {
+                Ok(validated) => validated,
+                Err(e) => {
+                    result.failed.push((idx, e.to_string()));
+                    continue;
+                }
+            };
+
+            sqlx::query("SAVEPOINT bulk_row").execute(&mut *tx).await?;
+            match self
+                .insert_validated(&mut tx, &schema, std::slice::from_ref(&validated))
+                .await
+            {
+                Ok(_) => {
+                    sqlx::query("RELEASE SAVEPOINT bulk_row")
+                        .execute(&mut *tx)
+                        .await?;
+                    result.inserted.push(validated.id);
+                }
+                Err(e) => {
+                    sqlx::query("ROLLBACK TO SAVEPOINT bulk_row")
+                        .execute(&mut *tx)
+                        .await?;
+                    result.failed.push((idx, e.to_string()));
+                }
+            }
+        }
+
+        tx.commit().await?;
+
+        Ok(result)
+    }
+
+    /// Validate one instance of a bulk insert and assign its ID
+    fn validate_bulk_instance(
+        &self,
+        schema: &Schema,
+        idx: usize,
+        instance: &serde_json::Value,
+    ) -> Result<ValidatedInstance> {
+        let properties_obj = instance.as_object().ok_or_else(|| {
+            ObjectStoreError::instance_validation(
+                idx,
+                None,
+                format!("Instance at index {} must be a JSON object", idx),
+            )
+        })?;
+
+        // Validate each column
+        for col in &schema.columns {
+            if let Some(value) = properties_obj.get(&col.name) {
+                if let Err(e) = col.validate_value(value) {
+                    return Err(ObjectStoreError::instance_validation(
+                        idx,
+                        Some(&col.name),
+                        format!(
                             "Instance at index {}: Invalid value for column '{}': {}",
                             idx, col.name, e
-                        )));
-                    }
+                        ),
+                    ));
+                }
 
-                    if !col.nullable && value.is_null() {
-                        return Err(ObjectStoreError::validation(format!(
+                if !col.nullable && value.is_null() {
+                    return Err(ObjectStoreError::instance_validation(
+                        idx,
+                        Some(&col.name),
+                        format!(
                             "Instance at index {}: Column '{}' does not allow NULL values",
                             idx, col.name
-                        )));
-                    }
-                } else if !col.nullable && col.default_value.is_none() {
-                    return Err(ObjectStoreError::validation(format!(
+                        ),
+                    ));
+                }
+            } else if !col.nullable && col.default_value.is_none() {
+                return Err(ObjectStoreError::instance_validation(
+                    idx,
+                    Some(&col.name),
+                    format!(
                         "Instance at index {}: Required column '{}' is missing",
                         idx, col.name
-                    )));
-                }
+                    ),
+                ));
             }
-
-            let instance_id = uuid::Uuid::new_v4().to_string();
-            validated_instances.push((instance_id, properties_obj.clone()));
         }
 
-        // Calculate chunk size (PostgreSQL limit ~32k params)
-        let params_per_row = 1 + schema.columns.len(); // id + columns
-        let chunk_size = 32000 / params_per_row.max(1);
-        let chunk_size = chunk_size.max(1); // At least 1 row per chunk
+        let created_at = if self.config.auto_columns.created_at {
+            Self::parse_timestamp_override(properties_obj, "createdAt", idx)?
+        } else {
+            None
+        };
+        let updated_at = if self.config.auto_columns.updated_at {
+            Self::parse_timestamp_override(properties_obj, "updatedAt", idx)?.or(created_at)
+        } else {
+            None
+        };
 
-        let mut tx = self.pool.begin().await?;
-        let mut total_affected: i64 = 0;
+        Ok(ValidatedInstance {
+            id: self.config.id_generator.generate(),
+            properties: properties_obj.clone(),
+            created_at,
+            updated_at,
+        })
+    }
 
+    /// Insert validated instances with a single multi-row INSERT
+    async fn insert_validated(
+        &self,
+        conn: &mut PgConnection,
+        schema: &Schema,
+        rows: &[ValidatedInstance],
+    ) -> Result<u64> {
         // Build column names list
         let mut column_names = Vec::new();
         if self.config.auto_columns.id {
             column_names.push("id".to_string());
         }
+        if self.config.auto_columns.created_at {
+            column_names.push("created_at".to_string());
+        }
+        if self.config.auto_columns.updated_at {
+            column_names.push("updated_at".to_string());
+        }
         for col in &schema.columns {
             column_names.push(quote_identifier(&col.name));
         }
 
-        // Process in chunks
-        for chunk in validated_instances.chunks(chunk_size) {
-            let mut placeholders = Vec::new();
-            let mut param_idx = 1;
-
-            for _ in chunk {
-                let mut row_placeholders = Vec::new();
-                if self.config.auto_columns.id {
-                    row_placeholders.push(format!("${}", param_idx));
-                    param_idx += 1;
-                }
-                for _ in &schema.columns {
-                    row_placeholders.push(format!("${}", param_idx));
-                    param_idx += 1;
-                }
-                placeholders.push(format!("({})", row_placeholders.join(", ")));
+        let mut placeholders = Vec::new();
+        let mut param_idx = 1;
+
+        for _ in rows {
+            let mut row_placeholders = Vec::new();
+            if self.config.auto_columns.id {
+                row_placeholders.push(format!("${}", param_idx));
+                param_idx += 1;
             }
+            if self.config.auto_columns.created_at {
+                row_placeholders.push(format!("COALESCE(${}::timestamptz, NOW())", param_idx));
+                param_idx += 1;
+            }
+            if self.config.auto_columns.updated_at {
+                row_placeholders.push(format!("COALESCE(${}::timestamptz, NOW())", param_idx));
+                param_idx += 1;
+            }
+            for col in &schema.columns {
+                row_placeholders.push(col.column_type.placeholder(param_idx));
+                param_idx += 1;
+            }
+            placeholders.push(format!("({})", row_placeholders.join(", ")));
+        }
 
-            let insert_sql = format!(
-                "INSERT INTO {} ({}) VALUES {}",
-                quote_identifier(&schema.table_name),
-                column_names.join(", "),
-                placeholders.join(", ")
-            );
+        let insert_sql = format!(
+            "INSERT INTO {} ({}) VALUES {}",
+            quote_identifier(&schema.table_name),
+            column_names.join(", "),
+            placeholders.join(", ")
+        );
 
-            let mut query = sqlx::query(&insert_sql);
+        let mut query = sqlx::query(&insert_sql);
 
-            // Bind values for each row in chunk
-            for (instance_id, properties_obj) in chunk {
-                if self.config.auto_columns.id {
-                    query = query.bind(instance_id);
-                }
-                for col in &schema.columns {
-                    if let Some(value) = properties_obj.get(&col.name) {
-                        query = Self::bind_value(query, &col.column_type, &col.name, value)?;
-                    } else {
-                        // Bind NULL for missing optional columns
-                        query = query.bind(None::<String>);
-                    }
+        // Bind values for each row
+        for instance in rows {
+            if self.config.auto_columns.id {
+                query = query.bind(&instance.id);
+            }
+            if self.config.auto_columns.created_at {
+                query = query.bind(instance.created_at);
+            }
+            if self.config.auto_columns.updated_at {
+                query = query.bind(instance.updated_at);
+            }
+            for col in &schema.columns {
+                if let Some(value) = instance.properties.get(&col.name) {
+                    query = Self::bind_value(query, col, value)?;
+                } else {
+                    // Bind NULL for missing optional columns
+                    query = query.bind(None::<String>);
                 }
             }
-
-            let result = query.execute(&mut *tx).await?;
-            total_affected += result.rows_affected() as i64;
         }
 
-        tx.commit().await?;
-
-        Ok(total_affected)
+        let result = query.execute(&mut *conn).await?;
+        Ok(result.rows_affected())
     }
 
     /// Insert or update multiple instances based on conflict columns
@@ -1056,6 +3010,11 @@ impl ObjectStore {
     /// Uses PostgreSQL's ON CONFLICT ... DO UPDATE syntax.
     /// All operations happen in a single transaction.
     ///
+    /// Conflict columns select the row to update and are never updated
+    /// themselves, so every instance must supply a non-null value for each of
+    /// them. With `id` as a conflict column, the instance's `id` property is
+    /// used as the row id.
+    ///
     /// # Arguments
     /// * `schema_name` - Name of the schema
     /// * `instances` - Vector of JSON objects to upsert
@@ -1073,22 +3032,144 @@ impl ObjectStore {
             return Ok(0);
         }
 
+        let schema = self.require_schema(schema_name).await?;
+        let validated_instances = self.validate_upsert(&schema, &instances, &conflict_columns)?;
+
+        // Calculate chunk size
+        let params_per_row = 1 + schema.columns.len();
+        let chunk_size = 32000 / params_per_row.max(1);
+        let chunk_size = chunk_size.max(1);
+
+        let guarded = !Self::upsert_immutable_columns(&schema, &conflict_columns).is_empty();
+
+        let mut tx = self.pool.begin().await?;
+        let mut total_affected: i64 = 0;
+
+        for chunk in validated_instances.chunks(chunk_size) {
+            let upsert_sql = self.upsert_sql(&schema, &conflict_columns, chunk.len(), None);
+            let query = self.bind_upsert_rows(sqlx::query(&upsert_sql), &schema, chunk)?;
+
+            let result = query.execute(&mut *tx).await?;
+            if guarded && result.rows_affected() < chunk.len() as u64 {
+                // Rows skipped by the immutable-column guard; nothing is committed
+                return Err(Self::immutable_upsert_error(&schema, &conflict_columns));
+            }
+            total_affected += result.rows_affected() as i64;
+        }
+
+        tx.commit().await?;
+
+        Ok(total_affected)
+    }
+
+    /// Start a streaming upsert into a schema
+    ///
+    /// For input that arrives in pieces (e.g. from a message consumer), where
+    /// [`upsert_instances`](Self::upsert_instances) would need the whole
+    /// dataset in memory. Batches are validated as they are
+    /// [`push`](UpsertStream::push)ed and written in chunks of the stream's
+    /// flush size, all within one transaction that
+    /// [`finish`](UpsertStream::finish) commits. Dropping the stream, or an
+    /// error from `push`, leaves nothing committed.
+    ///
+    /// # Arguments
+    /// * `schema_name` - Name of the schema
+    /// * `conflict_columns` - Columns that define uniqueness for conflict detection
+    pub async fn upsert_stream(
+        &self,
+        schema_name: &str,
+        conflict_columns: Vec<String>,
+    ) -> Result<UpsertStream<'_>> {
+        let schema = self.require_schema(schema_name).await?;
+        // Checks the conflict columns before any rows arrive
+        self.validate_upsert(&schema, &[], &conflict_columns)?;
+
+        let max_chunk_size = (32000 / (1 + schema.columns.len())).max(1);
+        let guarded = !Self::upsert_immutable_columns(&schema, &conflict_columns).is_empty();
+        let tx = self.pool.begin().await?;
+
+        Ok(UpsertStream {
+            store: self,
+            schema,
+            conflict_columns,
+            guarded,
+            max_chunk_size,
+            flush_size: max_chunk_size,
+            buffer: Vec::new(),
+            tx,
+            total_affected: 0,
+        })
+    }
+
+    /// Insert or update a single instance based on conflict columns
+    ///
+    /// Convenience wrapper over [`upsert_instances`](Self::upsert_instances)
+    /// for one row. Returns the inserted or updated instance as stored.
+    pub async fn upsert_instance(
+        &self,
+        schema_name: &str,
+        properties: serde_json::Value,
+        conflict_columns: Vec<String>,
+    ) -> Result<Instance> {
+        let schema = self.require_schema(schema_name).await?;
+        let validated = self.validate_upsert(
+            &schema,
+            std::slice::from_ref(&properties),
+            &conflict_columns,
+        )?;
+
+        let filter = FilterRequest::default();
+        let returning = QueryBuilder::new(&schema, &self.config, &filter)
+            .select_columns()
+            .join(", ");
+        let upsert_sql = self.upsert_sql(&schema, &conflict_columns, 1, Some(&returning));
+        let query = self.bind_upsert_rows(sqlx::query(&upsert_sql), &schema, &validated)?;
+
+        let row = query
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| Self::immutable_upsert_error(&schema, &conflict_columns))?;
+        self.row_to_instance(&row, &schema)
+    }
+
+    /// Immutable columns an upsert could overwrite (conflict columns never change)
+    fn upsert_immutable_columns<'s>(
+        schema: &'s Schema,
+        conflict_columns: &[String],
+    ) -> Vec<&'s str> {
+        schema
+            .columns
+            .iter()
+            .filter(|col| col.immutable && !conflict_columns.contains(&col.name))
+            .map(|col| col.name.as_str())
+            .collect()
+    }
+
+    fn immutable_upsert_error(schema: &Schema, conflict_columns: &[String]) -> ObjectStoreError {
+        ObjectStoreError::validation(format!(
+            "Upsert would change immutable column(s): {}",
+            Self::upsert_immutable_columns(schema, conflict_columns).join(", ")
+        ))
+    }
+
+    /// Validate upsert input and assign ids for rows that end up inserted
+    fn validate_upsert(
+        &self,
+        schema: &Schema,
+        instances: &[serde_json::Value],
+        conflict_columns: &[String],
+    ) -> Result<Vec<(String, serde_json::Map<String, serde_json::Value>)>> {
         if conflict_columns.is_empty() {
             return Err(ObjectStoreError::validation(
                 "At least one conflict column must be specified",
             ));
         }
 
-        let schema = self
-            .get_schema(schema_name)
-            .await?
-            .ok_or_else(|| ObjectStoreError::schema_not_found(schema_name))?;
-
         // Validate conflict columns exist
         let schema_column_names: std::collections::HashSet<_> =
             schema.columns.iter().map(|c| c.name.as_str()).collect();
 
-        for col_name in &conflict_columns {
+        for col_name in conflict_columns {
             if col_name != "id" && !schema_column_names.contains(col_name.as_str()) {
                 return Err(ObjectStoreError::validation(format!(
                     "Conflict column '{}' does not exist in schema",
@@ -1103,28 +3184,78 @@ impl ObjectStore {
 
         for (idx, instance) in instances.iter().enumerate() {
             let properties_obj = instance.as_object().ok_or_else(|| {
-                ObjectStoreError::validation(format!(
-                    "Instance at index {} must be a JSON object",
-                    idx
-                ))
+                ObjectStoreError::instance_validation(
+                    idx,
+                    None,
+                    format!("Instance at index {} must be a JSON object", idx),
+                )
             })?;
 
             // Validate each column
             for col in &schema.columns {
                 if let Some(value) = properties_obj.get(&col.name)
-                    && let Err(e) = col.column_type.validate_value(value)
+                    && let Err(e) = col.validate_value(value)
                 {
-                    return Err(ObjectStoreError::validation(format!(
-                        "Instance at index {}: Invalid value for column '{}': {}",
-                        idx, col.name, e
-                    )));
+                    return Err(ObjectStoreError::instance_validation(
+                        idx,
+                        Some(&col.name),
+                        format!(
+                            "Instance at index {}: Invalid value for column '{}': {}",
+                            idx, col.name, e
+                        ),
+                    ));
+                }
+            }
+
+            // Conflict columns only locate the row; they are never in the
+            // update list, so a missing value would silently insert a new row
+            for col_name in conflict_columns {
+                if properties_obj.get(col_name).is_none_or(|v| v.is_null()) {
+                    return Err(ObjectStoreError::instance_validation(
+                        idx,
+                        Some(col_name),
+                        format!(
+                            "Instance at index {}: conflict column '{}' must have a value; \
+                             conflict columns select the row to update and are never updated themselves",
+                            idx, col_name
+                        ),
+                    ));
                 }
             }
 
-            let instance_id = uuid::Uuid::new_v4().to_string();
+            // Conflicting on id matches the caller's id instead of a fresh one
+            let instance_id = if conflict_columns.iter().any(|c| c == "id") {
+                properties_obj
+                    .get("id")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string)
+                    .ok_or_else(|| {
+                        ObjectStoreError::instance_validation(
+                            idx,
+                            Some("id"),
+                            format!("Instance at index {}: 'id' must be a string", idx),
+                        )
+                    })?
+            } else {
+                self.config.id_generator.generate()
+            };
             validated_instances.push((instance_id, properties_obj.clone()));
         }
 
+        Ok(validated_instances)
+    }
+
+    /// INSERT ... ON CONFLICT statement for `rows` rows
+    ///
+    /// With `returning`, a conflict always takes the DO UPDATE path (a no-op
+    /// assignment when every column is a conflict column) so the row comes back.
+    fn upsert_sql(
+        &self,
+        schema: &Schema,
+        conflict_columns: &[String],
+        rows: usize,
+        returning: Option<&str>,
+    ) -> String {
         // Build column names list
         let mut column_names = Vec::new();
         if self.config.auto_columns.id {
@@ -1158,73 +3289,97 @@ impl ObjectStore {
             update_sets.push("updated_at = NOW()".to_string());
         }
 
-        // Calculate chunk size
-        let params_per_row = 1 + schema.columns.len();
-        let chunk_size = 32000 / params_per_row.max(1);
-        let chunk_size = chunk_size.max(1);
+        if update_sets.is_empty() && returning.is_some() {
+            update_sets.push(format!(
+                "{} = EXCLUDED.{}",
+                conflict_cols[0], conflict_cols[0]
+            ));
+        }
 
-        let mut tx = self.pool.begin().await?;
-        let mut total_affected: i64 = 0;
+        let mut placeholders = Vec::with_capacity(rows);
+        let mut param_idx = 1;
 
-        for chunk in validated_instances.chunks(chunk_size) {
-            let mut placeholders = Vec::new();
-            let mut param_idx = 1;
-
-            for _ in chunk {
-                let mut row_placeholders = Vec::new();
-                if self.config.auto_columns.id {
-                    row_placeholders.push(format!("${}", param_idx));
-                    param_idx += 1;
-                }
-                for _ in &schema.columns {
-                    row_placeholders.push(format!("${}", param_idx));
-                    param_idx += 1;
-                }
-                placeholders.push(format!("({})", row_placeholders.join(", ")));
+        for _ in 0..rows {
+            let mut row_placeholders = Vec::new();
+            if self.config.auto_columns.id {
+                row_placeholders.push(format!("${}", param_idx));
+                param_idx += 1;
+            }
+            for col in &schema.columns {
+                row_placeholders.push(col.column_type.placeholder(param_idx));
+                param_idx += 1;
             }
+            placeholders.push(format!("({})", row_placeholders.join(", ")));
+        }
 
-            let upsert_sql = if update_sets.is_empty() {
-                // If no columns to update (all columns are conflict columns), use DO NOTHING
-                format!(
-                    "INSERT INTO {} ({}) VALUES {} ON CONFLICT ({}) DO NOTHING",
-                    quote_identifier(&schema.table_name),
-                    column_names.join(", "),
-                    placeholders.join(", "),
-                    conflict_cols.join(", ")
-                )
-            } else {
+        // An existing row is only updated while its immutable columns keep
+        // their values; skipped rows are reported by the callers
+        let immutable_guard: Vec<String> = Self::upsert_immutable_columns(schema, conflict_columns)
+            .into_iter()
+            .map(|name| {
                 format!(
-                    "INSERT INTO {} ({}) VALUES {} ON CONFLICT ({}) DO UPDATE SET {}",
+                    "{}.{} IS NOT DISTINCT FROM EXCLUDED.{}",
                     quote_identifier(&schema.table_name),
-                    column_names.join(", "),
-                    placeholders.join(", "),
-                    conflict_cols.join(", "),
-                    update_sets.join(", ")
+                    quote_identifier(name),
+                    quote_identifier(name)
                 )
-            };
+            })
+            .collect();
 
-            let mut query = sqlx::query(&upsert_sql);
+        let mut upsert_sql = if update_sets.is_empty() {
+            // If no columns to update (all columns are conflict columns), use DO NOTHING
+            format!(
+                "INSERT INTO {} ({}) VALUES {} ON CONFLICT ({}) DO NOTHING",
+                quote_identifier(&schema.table_name),
+                column_names.join(", "),
+                placeholders.join(", "),
+                conflict_cols.join(", ")
+            )
+        } else {
+            format!(
+                "INSERT INTO {} ({}) VALUES {} ON CONFLICT ({}) DO UPDATE SET {}",
+                quote_identifier(&schema.table_name),
+                column_names.join(", "),
+                placeholders.join(", "),
+                conflict_cols.join(", "),
+                update_sets.join(", ")
+            )
+        };
 
-            for (instance_id, properties_obj) in chunk {
-                if self.config.auto_columns.id {
-                    query = query.bind(instance_id);
-                }
-                for col in &schema.columns {
-                    if let Some(value) = properties_obj.get(&col.name) {
-                        query = Self::bind_value(query, &col.column_type, &col.name, value)?;
-                    } else {
-                        query = query.bind(None::<String>);
-                    }
-                }
-            }
+        if !update_sets.is_empty() && !immutable_guard.is_empty() {
+            upsert_sql.push_str(" WHERE ");
+            upsert_sql.push_str(&immutable_guard.join(" AND "));
+        }
 
-            let result = query.execute(&mut *tx).await?;
-            total_affected += result.rows_affected() as i64;
+        if let Some(returning) = returning {
+            upsert_sql.push_str(" RETURNING ");
+            upsert_sql.push_str(returning);
         }
 
-        tx.commit().await?;
+        upsert_sql
+    }
 
-        Ok(total_affected)
+    /// Bind validated upsert rows in the order [`upsert_sql`](Self::upsert_sql) expects
+    fn bind_upsert_rows<'q>(
+        &self,
+        mut query: sqlx::query::Query<'q, sqlx::Postgres, sqlx::postgres::PgArguments>,
+        schema: &Schema,
+        rows: &'q [(String, serde_json::Map<String, serde_json::Value>)],
+    ) -> Result<sqlx::query::Query<'q, sqlx::Postgres, sqlx::postgres::PgArguments>> {
+        for (instance_id, properties_obj) in rows {
+            if self.config.auto_columns.id {
+                query = query.bind(instance_id);
+            }
+            for col in &schema.columns {
+                if let Some(value) = properties_obj.get(&col.name) {
+                    query = Self::bind_value(query, col, value)?;
+                } else {
+                    query = query.bind(None::<String>);
+                }
+            }
+        }
+
+        Ok(query)
     }
 
     // =========================================================================
@@ -1240,6 +3395,8 @@ impl ObjectStore {
         let table_name: String = row.try_get("table_name")?;
         let columns: serde_json::Value = row.try_get("columns")?;
         let indexes: Option<serde_json::Value> = row.try_get("indexes")?;
+        let default_index: bool = row.try_get("default_index")?;
+        let storage_params: Option<serde_json::Value> = row.try_get("storage_params")?;
 
         Ok(Schema {
             id,
@@ -1250,6 +3407,9 @@ impl ObjectStore {
             table_name,
             columns: serde_json::from_value(columns).unwrap_or_default(),
             indexes: indexes.and_then(|v| serde_json::from_value(v).ok()),
+            default_index,
+            storage_params: storage_params.and_then(|v| serde_json::from_value(v).ok()),
+            tablespace: row.try_get("tablespace")?,
         })
     }
 
@@ -1258,95 +3418,58 @@ impl ObjectStore {
         schema: &Schema,
         filter: FilterRequest,
     ) -> Result<(Vec<Instance>, i64)> {
-        // Build column list
-        let mut select_columns = Vec::new();
-
-        if self.config.auto_columns.id {
-            select_columns.push("id".to_string());
-        }
-        if self.config.auto_columns.created_at {
-            select_columns.push("created_at".to_string());
-        }
-        if self.config.auto_columns.updated_at {
-            select_columns.push("updated_at".to_string());
-        }
-
-        for col in &schema.columns {
-            select_columns.push(quote_identifier(&col.name));
-        }
-
-        // Build WHERE clause from condition
-        let (where_clause, params) = if let Some(condition) = filter.condition {
-            let mut param_offset = 1;
-            build_condition_clause(&condition, &mut param_offset, schema)
-                .map_err(ObjectStoreError::InvalidCondition)?
-        } else {
-            ("TRUE".to_string(), Vec::new())
-        };
-
-        // Build ORDER BY clause
-        let order_by_clause = build_order_by_clause(&filter.sort_by, &filter.sort_order, schema)
-            .map_err(ObjectStoreError::validation)?;
-
-        let base_where = if self.config.soft_delete {
-            format!("deleted = FALSE AND ({})", where_clause)
-        } else {
-            format!("({})", where_clause)
-        };
-
-        // Count query
-        let count_query = format!(
-            "SELECT COUNT(*) FROM {} WHERE {}",
-            quote_identifier(&schema.table_name),
-            base_where
-        );
-
-        // Select query
-        let select_query = format!(
-            "SELECT {} FROM {} WHERE {} ORDER BY {} LIMIT ${} OFFSET ${}",
-            select_columns.join(", "),
-            quote_identifier(&schema.table_name),
-            base_where,
-            order_by_clause,
-            params.len() + 1,
-            params.len() + 2
-        );
+        let builder = QueryBuilder::new(schema, &self.config, &filter);
+        let count_query = builder.build_count()?;
+        let select_query = builder.build_select()?;
 
         // Execute count query
-        let mut count_query_builder = sqlx::query_as::<_, (i64,)>(&count_query);
-        for param in &params {
-            let param_str = match param {
-                serde_json::Value::String(s) => s.clone(),
-                other => other.to_string(),
-            };
-            count_query_builder = count_query_builder.bind(param_str);
+        let mut count_query_builder = sqlx::query_as::<_, (i64,)>(&count_query.sql);
+        for param in &count_query.params {
+            count_query_builder = count_query_builder.bind(param);
         }
         let (total_count,) = count_query_builder.fetch_one(&self.pool).await?;
 
+        // A zero limit asks for the total only
+        if filter.limit == 0 {
+            return Ok((Vec::new(), total_count));
+        }
+
         // Execute select query
-        let mut select_query_builder = sqlx::query(&select_query);
-        for param in &params {
-            let param_str = match param {
-                serde_json::Value::String(s) => s.clone(),
-                other => other.to_string(),
-            };
-            select_query_builder = select_query_builder.bind(param_str);
+        let mut select_query_builder = sqlx::query(&select_query.sql);
+        for param in &select_query.params {
+            select_query_builder = select_query_builder.bind(param);
         }
-        let rows = select_query_builder
-            .bind(filter.limit)
-            .bind(filter.offset)
-            .fetch_all(&self.pool)
-            .await?;
+        let rows = select_query_builder.fetch_all(&self.pool).await?;
 
-        let instances: Vec<Instance> = rows
+        let instances = rows
             .iter()
-            .map(|row| self.row_to_instance(row, schema))
-            .collect();
+            .map(|row| self.row_to_filtered_instance(row, schema, &filter))
+            .collect::<Result<Vec<Instance>>>()?;
 
         Ok((instances, total_count))
     }
 
-    fn row_to_instance(&self, row: &sqlx::postgres::PgRow, schema: &Schema) -> Instance {
+    /// Map a filtered row, including the filter's computed fields
+    fn row_to_filtered_instance(
+        &self,
+        row: &sqlx::postgres::PgRow,
+        schema: &Schema,
+        filter: &FilterRequest,
+    ) -> Result<Instance> {
+        let mut instance = self.row_to_instance(row, schema)?;
+        if let Some(properties) = instance.properties.as_object_mut() {
+            for field in &filter.computed {
+                let value: Option<serde_json::Value> = row.try_get(field.alias.as_str())?;
+                properties.insert(
+                    field.alias.clone(),
+                    value.unwrap_or(serde_json::Value::Null),
+                );
+            }
+        }
+        Ok(instance)
+    }
+
+    fn row_to_instance(&self, row: &sqlx::postgres::PgRow, schema: &Schema) -> Result<Instance> {
         let id: String = if self.config.auto_columns.id {
             row.try_get("id").unwrap_or_default()
         } else {
@@ -1372,31 +3495,59 @@ impl ObjectStore {
         // Build properties from columns
         let mut properties = serde_json::Map::new();
         for col in &schema.columns {
-            if let Some(value) = Self::extract_column_value(row, col) {
+            if let Some(value) = self.extract_column_value(row, col)? {
                 properties.insert(col.name.clone(), value);
             }
         }
 
-        Instance {
+        Ok(Instance {
             id,
             created_at,
             updated_at,
             schema_id: Some(schema.id.clone()),
             schema_name: Some(schema.name.clone()),
             properties: serde_json::Value::Object(properties),
-        }
+        })
     }
 
+    /// Read a column from a row as JSON
+    ///
+    /// Enum values no longer in the column's enum set are handled according
+    /// to [`StoreConfig::unknown_enum_values`].
     fn extract_column_value(
+        &self,
         row: &sqlx::postgres::PgRow,
         col: &ColumnDefinition,
-    ) -> Option<serde_json::Value> {
-        match &col.column_type {
-            ColumnType::String | ColumnType::Enum { .. } => row
+    ) -> Result<Option<serde_json::Value>> {
+        let value = match &col.column_type {
+            ColumnType::String => row
                 .try_get::<Option<String>, _>(col.name.as_str())
                 .ok()
                 .flatten()
                 .map(serde_json::Value::String),
+            ColumnType::Enum { values } => {
+                let Some(value) = row
+                    .try_get::<Option<String>, _>(col.name.as_str())
+                    .ok()
+                    .flatten()
+                else {
+                    return Ok(None);
+                };
+                if values.contains(&value) {
+                    Some(serde_json::Value::String(value))
+                } else {
+                    match self.config.unknown_enum_values {
+                        UnknownEnumValues::PassThrough => Some(serde_json::Value::String(value)),
+                        UnknownEnumValues::Null => None,
+                        UnknownEnumValues::Error => {
+                            return Err(ObjectStoreError::database(format!(
+                                "Column '{}' holds '{}', which is not in its enum values {:?}",
+                                col.name, value, values
+                            )));
+                        }
+                    }
+                }
+            }
             ColumnType::Integer => row
                 .try_get::<Option<i64>, _>(col.name.as_str())
                 .ok()
@@ -1416,35 +3567,125 @@ impl ObjectStore {
                 .ok()
                 .flatten()
                 .map(serde_json::Value::Bool),
-            ColumnType::Timestamp => row
+            ColumnType::Timestamp { with_tz: true } => row
                 .try_get::<Option<chrono::DateTime<chrono::Utc>>, _>(col.name.as_str())
                 .ok()
                 .flatten()
                 .map(|v| serde_json::Value::String(v.to_rfc3339())),
+            ColumnType::Timestamp { with_tz: false } => row
+                .try_get::<Option<chrono::NaiveDateTime>, _>(col.name.as_str())
+                .ok()
+                .flatten()
+                .map(|v| serde_json::Value::String(v.format("%Y-%m-%dT%H:%M:%S%.f").to_string())),
             ColumnType::Json => row
                 .try_get::<Option<serde_json::Value>, _>(col.name.as_str())
                 .ok()
                 .flatten(),
+            // Selected through ST_AsGeoJSON, so it arrives as JSONB
+            #[cfg(feature = "postgis")]
+            ColumnType::Point => row
+                .try_get::<Option<serde_json::Value>, _>(col.name.as_str())
+                .ok()
+                .flatten(),
+        };
+        Ok(value)
+    }
+
+    /// Whether an incoming property value equals the value read back from a row
+    ///
+    /// Numbers compare numerically and timestamps by instant, so `10` matches a
+    /// stored `10.0` and `+02:00` offsets match the UTC value read back.
+    fn stored_value_matches(
+        column_type: &ColumnType,
+        stored: Option<&serde_json::Value>,
+        incoming: &serde_json::Value,
+    ) -> bool {
+        let stored = stored.unwrap_or(&serde_json::Value::Null);
+        match column_type {
+            ColumnType::Integer | ColumnType::Decimal { .. } => {
+                let as_f64 = |value: &serde_json::Value| match value {
+                    serde_json::Value::Number(n) => n.as_f64(),
+                    serde_json::Value::String(s) => s.parse::<f64>().ok(),
+                    _ => None,
+                };
+                match (as_f64(stored), as_f64(incoming)) {
+                    (Some(a), Some(b)) => a == b,
+                    _ => stored == incoming,
+                }
+            }
+            ColumnType::Timestamp { with_tz: true } => {
+                let parse = |value: &serde_json::Value| {
+                    value
+                        .as_str()
+                        .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+                };
+                match (parse(stored), parse(incoming)) {
+                    (Some(a), Some(b)) => a == b,
+                    _ => stored == incoming,
+                }
+            }
+            ColumnType::Timestamp { with_tz: false } => {
+                let parse =
+                    |value: &serde_json::Value| value.as_str().and_then(parse_naive_timestamp);
+                match (parse(stored), parse(incoming)) {
+                    (Some(a), Some(b)) => a == b,
+                    _ => stored == incoming,
+                }
+            }
+            _ => stored == incoming,
         }
     }
 
+    /// Parse an optional explicit timestamp (RFC3339) from a bulk-insert instance
+    fn parse_timestamp_override(
+        properties: &serde_json::Map<String, serde_json::Value>,
+        key: &str,
+        idx: usize,
+    ) -> Result<Option<chrono::DateTime<chrono::Utc>>> {
+        let Some(value) = properties.get(key) else {
+            return Ok(None);
+        };
+        if value.is_null() {
+            return Ok(None);
+        }
+
+        value
+            .as_str()
+            .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+            .map(|ts| Some(ts.with_timezone(&chrono::Utc)))
+            .ok_or_else(|| {
+                ObjectStoreError::instance_validation(
+                    idx,
+                    Some(key),
+                    format!(
+                        "Instance at index {}: '{}' must be an RFC3339 timestamp",
+                        idx, key
+                    ),
+                )
+            })
+    }
+
     fn bind_value<'q>(
         query: sqlx::query::Query<'q, sqlx::Postgres, sqlx::postgres::PgArguments>,
-        column_type: &ColumnType,
-        column_name: &str,
+        col: &ColumnDefinition,
         value: &'q serde_json::Value,
     ) -> Result<sqlx::query::Query<'q, sqlx::Postgres, sqlx::postgres::PgArguments>> {
-        Ok(match column_type {
+        let column_name = col.name.as_str();
+        Ok(match &col.column_type {
             ColumnType::String | ColumnType::Enum { .. } => {
                 if value.is_null() {
                     query.bind(None::<String>)
                 } else {
-                    query.bind(value.as_str().ok_or_else(|| {
+                    let s = value.as_str().ok_or_else(|| {
                         ObjectStoreError::validation(format!(
                             "Column '{}' expected string",
                             column_name
                         ))
-                    })?)
+                    })?;
+                    match &col.normalize {
+                        Some(normalization) => query.bind(normalization.apply(s)),
+                        None => query.bind(s),
+                    }
                 }
             }
             ColumnType::Integer => {
@@ -1455,10 +3696,15 @@ impl ObjectStore {
                         .as_i64()
                         .or_else(|| value.as_str().and_then(|s| s.parse::<i64>().ok()))
                         .ok_or_else(|| {
-                            ObjectStoreError::validation(format!(
-                                "Column '{}' expected integer",
-                                column_name
-                            ))
+                            ObjectStoreError::validation(
+                                col.column_type
+                                    .validate_value(value)
+                                    .err()
+                                    .map(|e| format!("Column '{}': {}", column_name, e))
+                                    .unwrap_or_else(|| {
+                                        format!("Column '{}' expected integer", column_name)
+                                    }),
+                            )
                         })?;
                     query.bind(int_val)
                 }
@@ -1503,7 +3749,24 @@ impl ObjectStore {
                     query.bind(bool_val)
                 }
             }
-            ColumnType::Timestamp => {
+            ColumnType::Timestamp { with_tz: false } => {
+                if value.is_null() {
+                    query.bind(None::<chrono::NaiveDateTime>)
+                } else {
+                    let timestamp =
+                        value
+                            .as_str()
+                            .and_then(parse_naive_timestamp)
+                            .ok_or_else(|| {
+                                ObjectStoreError::validation(format!(
+                                    "Column '{}' has invalid timestamp",
+                                    column_name
+                                ))
+                            })?;
+                    query.bind(timestamp)
+                }
+            }
+            ColumnType::Timestamp { with_tz: true } => {
                 if value.is_null() {
                     query.bind(None::<chrono::DateTime<chrono::Utc>>)
                 } else {
@@ -1525,6 +3788,99 @@ impl ObjectStore {
                 }
             }
             ColumnType::Json => query.bind(value),
+            #[cfg(feature = "postgis")]
+            ColumnType::Point => {
+                if value.is_null() {
+                    query.bind(None::<Vec<f64>>)
+                } else {
+                    let (lng, lat) = crate::types::parse_point(value).map_err(|e| {
+                        ObjectStoreError::validation(format!(
+                            "Column '{}' has invalid point: {}",
+                            column_name, e
+                        ))
+                    })?;
+                    query.bind(vec![lng, lat])
+                }
+            }
         })
     }
 }
+
+/// A bulk upsert fed incrementally, created by [`ObjectStore::upsert_stream`]
+///
+/// Rows are buffered until `flush_size` of them are pending, then written with
+/// one INSERT ... ON CONFLICT statement. Everything runs in one transaction.
+pub struct UpsertStream<'a> {
+    store: &'a ObjectStore,
+    schema: Schema,
+    conflict_columns: Vec<String>,
+    guarded: bool,
+    /// Largest chunk that fits PostgreSQL's bind parameter limit
+    max_chunk_size: usize,
+    flush_size: usize,
+    buffer: Vec<(String, serde_json::Map<String, serde_json::Value>)>,
+    tx: sqlx::Transaction<'static, sqlx::Postgres>,
+    total_affected: i64,
+}
+
+impl UpsertStream<'_> {
+    /// Set how many rows are written per statement
+    ///
+    /// Defaults to, and is capped at, the most rows one statement can bind.
+    pub fn flush_size(mut self, rows: usize) -> Self {
+        self.flush_size = rows.clamp(1, self.max_chunk_size);
+        self
+    }
+
+    /// Validate a batch and write every full chunk that is now pending
+    ///
+    /// Validation errors report the index within `batch`. A batch that fails
+    /// validation is not buffered; any other error aborts the transaction.
+    pub async fn push(&mut self, batch: Vec<serde_json::Value>) -> Result<()> {
+        let validated = self
+            .store
+            .validate_upsert(&self.schema, &batch, &self.conflict_columns)?;
+        self.buffer.extend(validated);
+
+        while self.buffer.len() >= self.flush_size {
+            let chunk: Vec<_> = self.buffer.drain(..self.flush_size).collect();
+            self.write_chunk(&chunk).await?;
+        }
+        Ok(())
+    }
+
+    /// Write the remaining rows and commit
+    ///
+    /// # Returns
+    /// Number of affected rows (inserts + updates) across all batches
+    pub async fn finish(mut self) -> Result<i64> {
+        let rest = std::mem::take(&mut self.buffer);
+        if !rest.is_empty() {
+            self.write_chunk(&rest).await?;
+        }
+        self.tx.commit().await?;
+        Ok(self.total_affected)
+    }
+
+    async fn write_chunk(
+        &mut self,
+        chunk: &[(String, serde_json::Map<String, serde_json::Value>)],
+    ) -> Result<()> {
+        let upsert_sql =
+            self.store
+                .upsert_sql(&self.schema, &self.conflict_columns, chunk.len(), None);
+        let query = self
+            .store
+            .bind_upsert_rows(sqlx::query(&upsert_sql), &self.schema, chunk)?;
+
+        let result = query.execute(&mut *self.tx).await?;
+        if self.guarded && result.rows_affected() < chunk.len() as u64 {
+            return Err(ObjectStore::immutable_upsert_error(
+                &self.schema,
+                &self.conflict_columns,
+            ));
+        }
+        self.total_affected += result.rows_affected() as i64;
+        Ok(())
+    }
+}
@@ -9,8 +9,12 @@ use serde::{Deserialize, Serialize};
 // ============================================================================
 
 /// Column type definition with validation and SQL mapping
+///
+/// Non-exhaustive: feature flags such as `postgis` add variants, so matches
+/// outside this crate need a wildcard arm.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "type", rename_all = "lowercase")]
+#[non_exhaustive]
 pub enum ColumnType {
     /// Text field (unlimited length, maps to TEXT)
     String,
@@ -31,8 +35,14 @@ pub enum ColumnType {
     /// Boolean field (maps to BOOLEAN)
     Boolean,
 
-    /// Timestamp field, always stored in UTC (maps to TIMESTAMP WITH TIME ZONE)
-    Timestamp,
+    /// Timestamp field (maps to TIMESTAMP WITH TIME ZONE, or TIMESTAMP WITHOUT
+    /// TIME ZONE when `with_tz` is false)
+    Timestamp {
+        /// Store an absolute instant in UTC (default: true); naive timestamps
+        /// keep the wall-clock value as given
+        #[serde(default = "default_with_tz")]
+        with_tz: bool,
+    },
 
     /// JSON field, stored as binary JSON (maps to JSONB)
     Json,
@@ -42,6 +52,12 @@ pub enum ColumnType {
         /// List of allowed string values
         values: Vec<String>,
     },
+
+    /// Geographic point in WGS 84 (maps to PostGIS `geography(Point,4326)`)
+    ///
+    /// Accepts `{"lat": .., "lng": ..}` or a GeoJSON Point; read back as GeoJSON.
+    #[cfg(feature = "postgis")]
+    Point,
 }
 
 fn default_precision() -> u8 {
@@ -52,14 +68,52 @@ fn default_scale() -> u8 {
     4
 }
 
+fn default_with_tz() -> bool {
+    true
+}
+
+/// Parse a value for a naive (`with_tz: false`) timestamp column
+///
+/// Accepts an ISO 8601 date-time without offset; RFC3339 values with an
+/// offset are converted to UTC first.
+pub(crate) fn parse_naive_timestamp(s: &str) -> Option<chrono::NaiveDateTime> {
+    s.parse::<chrono::NaiveDateTime>().ok().or_else(|| {
+        chrono::DateTime::parse_from_rfc3339(s)
+            .ok()
+            .map(|dt| dt.naive_utc())
+    })
+}
+
 impl ColumnType {
     /// Create a Decimal type with specified precision and scale
     pub fn decimal(precision: u8, scale: u8) -> Self {
         ColumnType::Decimal { precision, scale }
     }
 
+    /// Create a Timestamp type stored as TIMESTAMP WITH TIME ZONE
+    pub fn timestamp() -> Self {
+        ColumnType::Timestamp { with_tz: true }
+    }
+
+    /// Create a Timestamp type stored as TIMESTAMP WITHOUT TIME ZONE
+    pub fn naive_timestamp() -> Self {
+        ColumnType::Timestamp { with_tz: false }
+    }
+
     /// Convert column type to PostgreSQL type string
+    ///
+    /// Includes the inline CHECK constraint for enums; see
+    /// [`base_sql_type`](Self::base_sql_type) and
+    /// [`check_constraint`](Self::check_constraint) for the two parts.
     pub fn to_sql_type(&self, column_name: &str) -> String {
+        match self.check_constraint(column_name) {
+            Some(check) => format!("{} {}", self.base_sql_type(), check),
+            None => self.base_sql_type(),
+        }
+    }
+
+    /// PostgreSQL storage type, without any constraint
+    pub fn base_sql_type(&self) -> String {
         match self {
             ColumnType::String => "TEXT".to_string(),
             ColumnType::Integer => "BIGINT".to_string(),
@@ -67,20 +121,29 @@ impl ColumnType {
                 format!("NUMERIC({},{})", precision, scale)
             }
             ColumnType::Boolean => "BOOLEAN".to_string(),
-            ColumnType::Timestamp => "TIMESTAMP WITH TIME ZONE".to_string(),
+            ColumnType::Timestamp { with_tz: true } => "TIMESTAMP WITH TIME ZONE".to_string(),
+            ColumnType::Timestamp { with_tz: false } => "TIMESTAMP WITHOUT TIME ZONE".to_string(),
             ColumnType::Json => "JSONB".to_string(),
-            ColumnType::Enum { values } => {
-                // For enum, we use TEXT with CHECK constraint
-                format!(
-                    "TEXT CHECK ({} IN ({}))",
-                    column_name,
-                    values
-                        .iter()
-                        .map(|v| format!("'{}'", v.replace("'", "''")))
-                        .collect::<Vec<_>>()
-                        .join(", ")
-                )
-            }
+            #[cfg(feature = "postgis")]
+            ColumnType::Point => "geography(Point,4326)".to_string(),
+            // For enum, we use TEXT with CHECK constraint
+            ColumnType::Enum { .. } => "TEXT".to_string(),
+        }
+    }
+
+    /// CHECK constraint restricting the column's values, if the type needs one
+    pub fn check_constraint(&self, column_name: &str) -> Option<String> {
+        match self {
+            ColumnType::Enum { values } => Some(format!(
+                "CHECK ({} IN ({}))",
+                column_name,
+                values
+                    .iter()
+                    .map(|v| format!("'{}'", v.replace("'", "''")))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+            _ => None,
         }
     }
 
@@ -95,11 +158,24 @@ impl ColumnType {
         match (self, value) {
             (ColumnType::String, serde_json::Value::String(_)) => Ok(()),
             (ColumnType::Integer, serde_json::Value::Number(n)) if n.is_i64() => Ok(()),
+            // Numbers outside the BIGINT range (u64 or float-shaped)
+            (ColumnType::Integer, serde_json::Value::Number(n))
+                if n.is_u64()
+                    || n.as_f64()
+                        .is_some_and(|f| !(i64::MIN as f64..-(i64::MIN as f64)).contains(&f)) =>
+            {
+                Err(format!("Value {} out of range for integer column", n))
+            }
             // Allow string-to-integer coercion (common when importing from CSV)
-            (ColumnType::Integer, serde_json::Value::String(s)) => s
-                .parse::<i64>()
-                .map(|_| ())
-                .map_err(|_| format!("Cannot convert '{}' to integer", s)),
+            (ColumnType::Integer, serde_json::Value::String(s)) => {
+                s.parse::<i64>().map(|_| ()).map_err(|_| {
+                    if s.parse::<i128>().is_ok() {
+                        format!("Value '{}' out of range for integer column", s)
+                    } else {
+                        format!("Cannot convert '{}' to integer", s)
+                    }
+                })
+            }
             (ColumnType::Decimal { .. }, serde_json::Value::Number(_)) => Ok(()),
             // Allow string-to-decimal coercion (common when importing from CSV)
             (ColumnType::Decimal { .. }, serde_json::Value::String(s)) => s
@@ -114,13 +190,20 @@ impl ColumnType {
                     _ => Err(format!("Cannot convert '{}' to boolean", s)),
                 }
             }
-            (ColumnType::Timestamp, serde_json::Value::String(s)) => {
+            (ColumnType::Timestamp { with_tz: true }, serde_json::Value::String(s)) => {
                 // Validate ISO 8601 timestamp format
                 chrono::DateTime::parse_from_rfc3339(s)
                     .map(|_| ())
                     .map_err(|e| format!("Invalid timestamp format: {}", e))
             }
+            (ColumnType::Timestamp { with_tz: false }, serde_json::Value::String(s)) => {
+                parse_naive_timestamp(s)
+                    .map(|_| ())
+                    .ok_or_else(|| format!("Invalid timestamp format: '{}'", s))
+            }
             (ColumnType::Json, _) => Ok(()), // Any JSON value is valid
+            #[cfg(feature = "postgis")]
+            (ColumnType::Point, _) => parse_point(value).map(|_| ()),
             (ColumnType::Enum { values }, serde_json::Value::String(s)) => {
                 if values.contains(s) {
                     Ok(())
@@ -134,6 +217,69 @@ impl ColumnType {
             )),
         }
     }
+
+    /// SQL placeholder expression for binding a value of this type as `$idx`
+    ///
+    /// Points are bound as a `[lng, lat]` float array and built with `ST_MakePoint`.
+    pub fn placeholder(&self, idx: impl std::fmt::Display) -> String {
+        #[cfg(feature = "postgis")]
+        if matches!(self, ColumnType::Point) {
+            return format!(
+                "ST_SetSRID(ST_MakePoint((${0}::float8[])[1], (${0}::float8[])[2]), 4326)::geography",
+                idx
+            );
+        }
+        format!("${}", idx)
+    }
+
+    /// SQL expression for reading a column of this type back
+    ///
+    /// Points are returned as GeoJSON; other types are selected as is.
+    pub fn select_expression(&self, column_ref: &str) -> String {
+        #[cfg(feature = "postgis")]
+        if matches!(self, ColumnType::Point) {
+            return format!("ST_AsGeoJSON({})::jsonb", column_ref);
+        }
+        column_ref.to_string()
+    }
+}
+
+/// Parse a point value into `(lng, lat)`
+///
+/// Accepts `{"lat": .., "lng": ..}` or a GeoJSON Point
+/// (`{"type": "Point", "coordinates": [lng, lat]}`).
+#[cfg(feature = "postgis")]
+pub fn parse_point(value: &serde_json::Value) -> Result<(f64, f64), String> {
+    let obj = value
+        .as_object()
+        .ok_or_else(|| format!("Expected a point object, got {:?}", value))?;
+
+    let (lng, lat) = if let Some(coordinates) = obj.get("coordinates") {
+        if obj.get("type").and_then(|t| t.as_str()) != Some("Point") {
+            return Err("GeoJSON value must have type 'Point'".to_string());
+        }
+        match coordinates.as_array().map(Vec::as_slice) {
+            Some([lng, lat]) => (lng.as_f64(), lat.as_f64()),
+            _ => return Err("GeoJSON coordinates must be [lng, lat]".to_string()),
+        }
+    } else {
+        (
+            obj.get("lng").and_then(|v| v.as_f64()),
+            obj.get("lat").and_then(|v| v.as_f64()),
+        )
+    };
+
+    let (Some(lng), Some(lat)) = (lng, lat) else {
+        return Err("Point requires numeric 'lat' and 'lng'".to_string());
+    };
+    if !(-90.0..=90.0).contains(&lat) {
+        return Err(format!("Latitude {} out of range [-90, 90]", lat));
+    }
+    if !(-180.0..=180.0).contains(&lng) {
+        return Err(format!("Longitude {} out of range [-180, 180]", lng));
+    }
+
+    Ok((lng, lat))
 }
 
 fn default_nullable() -> bool {
@@ -162,6 +308,38 @@ pub struct ColumnDefinition {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "default")]
     pub default_value: Option<String>,
+
+    /// Table name this column references as a foreign key (targets its `id` column)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub references: Option<String>,
+
+    /// Canonical form string and enum values are converted to before storing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub normalize: Option<Normalization>,
+
+    /// Whether the value is fixed once the instance is created (default: false)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub immutable: bool,
+}
+
+/// Canonical form applied to string and enum values before they are stored
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Normalization {
+    /// Store values in lowercase
+    Lowercase,
+    /// Store values in uppercase
+    Uppercase,
+}
+
+impl Normalization {
+    /// Convert a string to this canonical form
+    pub fn apply(&self, value: &str) -> String {
+        match self {
+            Normalization::Lowercase => value.to_lowercase(),
+            Normalization::Uppercase => value.to_uppercase(),
+        }
+    }
 }
 
 impl ColumnDefinition {
@@ -173,6 +351,9 @@ impl ColumnDefinition {
             nullable: true,
             unique: false,
             default_value: None,
+            references: None,
+            normalize: None,
+            immutable: false,
         }
     }
 
@@ -193,6 +374,55 @@ impl ColumnDefinition {
         self.default_value = Some(value.into());
         self
     }
+
+    /// Declare the column as a foreign key to the `id` of another schema's table
+    pub fn references(mut self, table_name: impl Into<String>) -> Self {
+        self.references = Some(table_name.into());
+        self
+    }
+
+    /// Store string and enum values in a canonical form
+    ///
+    /// Values are converted before validation and binding, so `"Active"` is
+    /// checked against the enum values and persisted as `"active"` under
+    /// [`Normalization::Lowercase`]. Has no effect on other column types.
+    pub fn normalize(mut self, normalization: Normalization) -> Self {
+        self.normalize = Some(normalization);
+        self
+    }
+
+    /// Allow the column to be set on insert only
+    ///
+    /// Updates that include the column are rejected with a validation error,
+    /// and upserts fail if they would change its stored value.
+    pub fn immutable(mut self) -> Self {
+        self.immutable = true;
+        self
+    }
+
+    /// Apply the column's normalization to an incoming value
+    ///
+    /// Returns the value unchanged unless it is a string on a normalized
+    /// String or Enum column.
+    pub fn normalize_value<'v>(
+        &self,
+        value: &'v serde_json::Value,
+    ) -> std::borrow::Cow<'v, serde_json::Value> {
+        match (&self.normalize, &self.column_type, value) {
+            (
+                Some(normalization),
+                ColumnType::String | ColumnType::Enum { .. },
+                serde_json::Value::String(s),
+            ) => std::borrow::Cow::Owned(serde_json::Value::String(normalization.apply(s))),
+            _ => std::borrow::Cow::Borrowed(value),
+        }
+    }
+
+    /// Validate an incoming value after applying the column's normalization
+    pub fn validate_value(&self, value: &serde_json::Value) -> Result<(), String> {
+        self.column_type
+            .validate_value(&self.normalize_value(value))
+    }
 }
 
 /// Index definition for dynamic schema
@@ -270,11 +500,29 @@ mod tests {
     #[test]
     fn test_column_type_timestamp_sql() {
         assert_eq!(
-            ColumnType::Timestamp.to_sql_type("created_at"),
+            ColumnType::timestamp().to_sql_type("created_at"),
             "TIMESTAMP WITH TIME ZONE"
         );
     }
 
+    #[test]
+    fn test_column_type_naive_timestamp_sql() {
+        assert_eq!(
+            ColumnType::naive_timestamp().to_sql_type("logged_at"),
+            "TIMESTAMP WITHOUT TIME ZONE"
+        );
+    }
+
+    #[test]
+    fn test_column_type_timestamp_defaults_to_with_tz() {
+        let col: ColumnType = serde_json::from_str(r#"{"type":"timestamp"}"#).unwrap();
+        assert_eq!(col, ColumnType::timestamp());
+
+        let naive: ColumnType =
+            serde_json::from_str(r#"{"type":"timestamp","with_tz":false}"#).unwrap();
+        assert_eq!(naive, ColumnType::naive_timestamp());
+    }
+
     #[test]
     fn test_column_type_json_sql() {
         assert_eq!(ColumnType::Json.to_sql_type("metadata"), "JSONB");
@@ -296,6 +544,23 @@ mod tests {
         assert!(sql.contains("'done'"));
     }
 
+    #[test]
+    fn test_column_type_enum_sql_parts() {
+        let enum_type = ColumnType::Enum {
+            values: vec!["a".to_string(), "b".to_string()],
+        };
+        assert_eq!(enum_type.base_sql_type(), "TEXT");
+        assert_eq!(
+            enum_type.check_constraint("status").as_deref(),
+            Some("CHECK (status IN ('a', 'b'))")
+        );
+        assert_eq!(
+            enum_type.to_sql_type("status"),
+            "TEXT CHECK (status IN ('a', 'b'))"
+        );
+        assert_eq!(ColumnType::Integer.check_constraint("n"), None);
+    }
+
     #[test]
     fn test_column_type_enum_sql_escapes_quotes() {
         let enum_type = ColumnType::Enum {
@@ -398,6 +663,36 @@ mod tests {
         assert!(t.validate_value(&serde_json::json!("12.34")).is_err());
     }
 
+    #[test]
+    fn test_validate_integer_out_of_range() {
+        let t = ColumnType::Integer;
+        assert!(t.validate_value(&serde_json::json!(i64::MAX)).is_ok());
+        assert!(t.validate_value(&serde_json::json!(i64::MIN)).is_ok());
+
+        let err = t
+            .validate_value(&serde_json::json!(i64::MAX as u64 + 1))
+            .unwrap_err();
+        assert!(err.contains("out of range for integer column"));
+
+        let large: serde_json::Value = serde_json::from_str("99999999999999999999").unwrap();
+        let err = t.validate_value(&large).unwrap_err();
+        assert!(err.contains("out of range for integer column"));
+
+        let err = t
+            .validate_value(&serde_json::json!("9223372036854775808"))
+            .unwrap_err();
+        assert!(err.contains("out of range for integer column"));
+
+        // Fractional and in-range float-shaped numbers are still a type mismatch
+        let err = t.validate_value(&serde_json::json!(1.5)).unwrap_err();
+        assert!(err.contains("Type mismatch"));
+        let err = t.validate_value(&serde_json::json!(1.0)).unwrap_err();
+        assert!(!err.contains("out of range"), "{}", err);
+        assert!(err.contains("Type mismatch"));
+        let err = t.validate_value(&serde_json::json!(-3.0)).unwrap_err();
+        assert!(!err.contains("out of range"), "{}", err);
+    }
+
     #[test]
     fn test_validate_decimal_valid() {
         let t = ColumnType::Decimal {
@@ -446,7 +741,7 @@ mod tests {
 
     #[test]
     fn test_validate_timestamp_valid() {
-        let t = ColumnType::Timestamp;
+        let t = ColumnType::timestamp();
         assert!(
             t.validate_value(&serde_json::json!("2024-01-15T10:30:00Z"))
                 .is_ok()
@@ -459,12 +754,34 @@ mod tests {
 
     #[test]
     fn test_validate_timestamp_invalid() {
-        let t = ColumnType::Timestamp;
+        let t = ColumnType::timestamp();
         assert!(t.validate_value(&serde_json::json!("2024-01-15")).is_err());
         assert!(t.validate_value(&serde_json::json!("not a date")).is_err());
         assert!(t.validate_value(&serde_json::json!(123456789)).is_err());
     }
 
+    #[test]
+    fn test_validate_naive_timestamp() {
+        let t = ColumnType::naive_timestamp();
+        assert!(
+            t.validate_value(&serde_json::json!("2024-01-15T10:30:00"))
+                .is_ok()
+        );
+        assert!(
+            t.validate_value(&serde_json::json!("2024-01-15T10:30:00.250"))
+                .is_ok()
+        );
+        assert!(
+            t.validate_value(&serde_json::json!("2024-01-15T10:30:00Z"))
+                .is_ok()
+        );
+        assert!(t.validate_value(&serde_json::json!("2024-01-15")).is_err());
+        assert_eq!(
+            parse_naive_timestamp("2024-01-15T10:30:00+02:00").map(|dt| dt.to_string()),
+            Some("2024-01-15 08:30:00".to_string())
+        );
+    }
+
     #[test]
     fn test_validate_json_accepts_any() {
         let t = ColumnType::Json;
@@ -534,6 +851,7 @@ mod tests {
         assert!(col.nullable); // default
         assert!(!col.unique); // default
         assert!(col.default_value.is_none());
+        assert!(col.references.is_none());
     }
 
     #[test]
@@ -554,6 +872,33 @@ mod tests {
         assert_eq!(col.default_value, Some("'active'".to_string()));
     }
 
+    #[test]
+    fn test_column_definition_references() {
+        let col = ColumnDefinition::new("customer_id", ColumnType::String).references("customers");
+        assert_eq!(col.references, Some("customers".to_string()));
+
+        let json = serde_json::to_string(&col).unwrap();
+        assert!(json.contains("\"references\":\"customers\""));
+
+        let plain =
+            serde_json::to_string(&ColumnDefinition::new("name", ColumnType::String)).unwrap();
+        assert!(!plain.contains("references"));
+    }
+
+    #[test]
+    fn test_column_definition_immutable() {
+        let col = ColumnDefinition::new("account_id", ColumnType::String).immutable();
+        assert!(col.immutable);
+
+        let json = serde_json::to_string(&col).unwrap();
+        assert!(json.contains("\"immutable\":true"));
+
+        let plain: ColumnDefinition =
+            serde_json::from_str(r#"{"name": "name", "type": "string"}"#).unwrap();
+        assert!(!plain.immutable);
+        assert!(!serde_json::to_string(&plain).unwrap().contains("immutable"));
+    }
+
     #[test]
     fn test_column_definition_chained_builders() {
         let col = ColumnDefinition::new("sku", ColumnType::String)
@@ -567,6 +912,55 @@ mod tests {
         assert_eq!(col.default_value, Some("''".to_string()));
     }
 
+    #[test]
+    fn test_column_definition_normalize_enum() {
+        let col = ColumnDefinition::new(
+            "status",
+            ColumnType::Enum {
+                values: vec!["active".to_string(), "inactive".to_string()],
+            },
+        )
+        .normalize(Normalization::Lowercase);
+
+        let value = serde_json::json!("Active");
+        assert_eq!(
+            col.normalize_value(&value).into_owned(),
+            serde_json::json!("active")
+        );
+        assert!(col.validate_value(&value).is_ok());
+        assert!(col.column_type.validate_value(&value).is_err());
+        assert!(col.validate_value(&serde_json::json!("Archived")).is_err());
+    }
+
+    #[test]
+    fn test_column_definition_normalize_only_strings() {
+        let col =
+            ColumnDefinition::new("qty", ColumnType::Integer).normalize(Normalization::Uppercase);
+        let value = serde_json::json!(5);
+        assert_eq!(col.normalize_value(&value).into_owned(), value);
+
+        let col =
+            ColumnDefinition::new("code", ColumnType::String).normalize(Normalization::Uppercase);
+        assert_eq!(
+            col.normalize_value(&serde_json::json!("ab-1")).into_owned(),
+            serde_json::json!("AB-1")
+        );
+        assert_eq!(
+            col.normalize_value(&serde_json::Value::Null).into_owned(),
+            serde_json::Value::Null
+        );
+    }
+
+    #[test]
+    fn test_column_definition_normalize_serde() {
+        let json = r#"{"name": "status", "type": "string", "normalize": "lowercase"}"#;
+        let col: ColumnDefinition = serde_json::from_str(json).unwrap();
+        assert_eq!(col.normalize, Some(Normalization::Lowercase));
+
+        let plain = serde_json::to_value(ColumnDefinition::new("x", ColumnType::String)).unwrap();
+        assert!(plain.get("normalize").is_none());
+    }
+
     #[test]
     fn test_column_definition_serialization() {
         let col = ColumnDefinition::new(
@@ -640,4 +1034,65 @@ mod tests {
             _ => panic!("Expected Decimal"),
         }
     }
+
+    #[test]
+    fn test_placeholder_and_select_expression_defaults() {
+        assert_eq!(ColumnType::String.placeholder(3), "$3");
+        assert_eq!(ColumnType::Json.select_expression("\"data\""), "\"data\"");
+    }
+
+    // =========================================================================
+    // Point Tests (PostGIS)
+    // =========================================================================
+
+    #[cfg(feature = "postgis")]
+    #[test]
+    fn test_column_type_point_sql() {
+        assert_eq!(
+            ColumnType::Point.to_sql_type("location"),
+            "geography(Point,4326)"
+        );
+        assert!(ColumnType::Point.placeholder(2).contains("ST_MakePoint"));
+        assert_eq!(
+            ColumnType::Point.select_expression("\"location\""),
+            "ST_AsGeoJSON(\"location\")::jsonb"
+        );
+    }
+
+    #[cfg(feature = "postgis")]
+    #[test]
+    fn test_validate_point() {
+        let point = ColumnType::Point;
+        assert!(
+            point
+                .validate_value(&serde_json::json!({"lat": 52.52, "lng": 13.405}))
+                .is_ok()
+        );
+        assert!(
+            point
+                .validate_value(
+                    &serde_json::json!({"type": "Point", "coordinates": [13.405, 52.52]})
+                )
+                .is_ok()
+        );
+        assert!(
+            point
+                .validate_value(&serde_json::json!({"lat": 95.0, "lng": 0.0}))
+                .is_err()
+        );
+        assert!(
+            point
+                .validate_value(&serde_json::json!("52.52,13.405"))
+                .is_err()
+        );
+    }
+
+    #[cfg(feature = "postgis")]
+    #[test]
+    fn test_parse_point_order() {
+        let geojson = serde_json::json!({"type": "Point", "coordinates": [13.405, 52.52]});
+        let lat_lng = serde_json::json!({"lat": 52.52, "lng": 13.405});
+        assert_eq!(parse_point(&geojson).unwrap(), (13.405, 52.52));
+        assert_eq!(parse_point(&lat_lng).unwrap(), (13.405, 52.52));
+    }
 }
@@ -9,9 +9,10 @@
 //! ```
 
 use runtara_object_store::instance::Condition;
-use runtara_object_store::types::{ColumnDefinition, ColumnType, IndexDefinition};
+use runtara_object_store::types::{ColumnDefinition, ColumnType, IndexDefinition, Normalization};
 use runtara_object_store::{
-    CreateSchemaRequest, FilterRequest, ObjectStore, SimpleFilter, StoreConfig,
+    ColumnTypeMismatch, CreateSchemaRequest, Cursor, DriftCheck, FilterRequest, ObjectStore,
+    ObjectStoreError, SimpleFilter, StoreConfig, UnknownEnumValues, UpdateSchemaRequest,
 };
 
 /// Get a unique test prefix for this test run
@@ -83,6 +84,10 @@ async fn test_create_schema() {
             "name_idx",
             vec!["name".to_string()],
         )]),
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
     };
 
     let schema = store
@@ -98,6 +103,108 @@ async fn test_create_schema() {
     cleanup_test(&store, &prefix).await;
 }
 
+#[tokio::test]
+async fn test_create_schema_rejects_invalid_identifiers() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    // Reserved word as the table name
+    let result = store
+        .create_schema(CreateSchemaRequest::new(
+            "keyword",
+            "select",
+            vec![ColumnDefinition::new("name", ColumnType::String)],
+        ))
+        .await;
+    match result {
+        Err(ObjectStoreError::Validation(msg)) => {
+            assert!(msg.contains("reserved keyword"), "{}", msg)
+        }
+        other => panic!("Expected validation error, got {:?}", other),
+    }
+
+    // Auto-managed column name as a user column
+    let table_name = format!("{}_clash", prefix);
+    let result = store
+        .create_schema(CreateSchemaRequest::new(
+            "clash",
+            &table_name,
+            vec![ColumnDefinition::new("id", ColumnType::String)],
+        ))
+        .await;
+    match result {
+        Err(ObjectStoreError::Validation(msg)) => assert!(msg.contains("'id'"), "{}", msg),
+        other => panic!("Expected validation error, got {:?}", other),
+    }
+
+    // Nothing was created before validation failed
+    assert!(store.list_schemas().await.expect("Should list").is_empty());
+    let table: Option<String> = sqlx::query_scalar("SELECT to_regclass($1)::text")
+        .bind(&table_name)
+        .fetch_one(store.pool())
+        .await
+        .expect("Should look up table");
+    assert!(table.is_none());
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_create_schema_rejects_unsafe_defaults() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let table_name = format!("{}_defaults", prefix);
+    let result = store
+        .create_schema(CreateSchemaRequest::new(
+            "defaults",
+            &table_name,
+            vec![
+                ColumnDefinition::new("status", ColumnType::String)
+                    .default("'x'); DROP TABLE users;--"),
+            ],
+        ))
+        .await;
+    assert!(matches!(result, Err(ObjectStoreError::Validation(_))));
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "defaults",
+            &table_name,
+            vec![
+                ColumnDefinition::new("status", ColumnType::String).default("'active'"),
+                ColumnDefinition::new("seen_at", ColumnType::timestamp()).default("NOW()"),
+            ],
+        ))
+        .await
+        .expect("Should create schema with safe defaults");
+
+    let id = store
+        .create_instance("defaults", serde_json::json!({}))
+        .await
+        .expect("Should create instance");
+    let instance = store
+        .get_instance("defaults", &id)
+        .await
+        .expect("Should get instance")
+        .expect("Instance should exist");
+    assert_eq!(instance.properties["status"], "active");
+    assert!(instance.properties["seen_at"].is_string());
+
+    let update = UpdateSchemaRequest::new().with_columns(vec![
+        ColumnDefinition::new("status", ColumnType::String).default("current_user"),
+        ColumnDefinition::new("seen_at", ColumnType::timestamp()).default("NOW()"),
+    ]);
+    let result = store.update_schema("defaults", update).await;
+    assert!(matches!(result, Err(ObjectStoreError::Validation(_))));
+
+    cleanup_test(&store, &prefix).await;
+}
+
 #[tokio::test]
 async fn test_get_schema_by_name() {
     let Some((store, prefix)) = create_test_store().await else {
@@ -112,6 +219,10 @@ async fn test_get_schema_by_name() {
         table_name: table_name.clone(),
         columns: vec![ColumnDefinition::new("name", ColumnType::String)],
         indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
     };
 
     store
@@ -153,6 +264,10 @@ async fn test_get_schema_by_id() {
         table_name,
         columns: vec![ColumnDefinition::new("code", ColumnType::String)],
         indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
     };
 
     let schema = store
@@ -188,6 +303,10 @@ async fn test_list_schemas() {
             table_name: format!("{}_{}", prefix, i),
             columns: vec![ColumnDefinition::new("data", ColumnType::Json)],
             indexes: None,
+            default_index_predicate: None,
+            create_default_index: None,
+            storage_params: None,
+            tablespace: None,
         };
         store
             .create_schema(request)
@@ -203,256 +322,398 @@ async fn test_list_schemas() {
 }
 
 #[tokio::test]
-async fn test_delete_schema() {
+async fn test_reindex() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    let request = CreateSchemaRequest {
-        name: "to_delete".to_string(),
-        description: None,
-        table_name: format!("{}_delete", prefix),
-        columns: vec![ColumnDefinition::new("value", ColumnType::String)],
-        indexes: None,
-    };
-
     store
-        .create_schema(request)
+        .create_schema(
+            CreateSchemaRequest::new(
+                "events",
+                format!("{}_events", prefix),
+                vec![ColumnDefinition::new("kind", ColumnType::String)],
+            )
+            .with_indexes(vec![IndexDefinition::new(
+                "by_kind",
+                vec!["kind".to_string()],
+            )]),
+        )
         .await
         .expect("Should create schema");
 
-    // Delete the schema
+    let rows: Vec<_> = (0..500)
+        .map(|i| serde_json::json!({"kind": format!("kind_{}", i % 10)}))
+        .collect();
     store
-        .delete_schema("to_delete")
+        .create_instances("events", rows)
         .await
-        .expect("Should delete schema");
+        .expect("Should create instances");
 
-    // Should not be found anymore (soft delete by default)
-    let found = store
-        .get_schema("to_delete")
+    store
+        .reindex("events", false)
         .await
-        .expect("Should not error");
+        .expect("Should reindex");
+    store
+        .reindex("events", true)
+        .await
+        .expect("Should reindex concurrently");
 
-    assert!(found.is_none());
+    let (_, count) = store
+        .query_instances(SimpleFilter::new("events").filter("kind", "kind_3"))
+        .await
+        .expect("Should query after reindex");
+    assert_eq!(count, 50);
+
+    let err = store
+        .reindex("missing", false)
+        .await
+        .expect_err("Should reject unknown schema");
+    assert!(matches!(err, ObjectStoreError::SchemaNotFound(_)));
 
     cleanup_test(&store, &prefix).await;
 }
 
 #[tokio::test]
-async fn test_duplicate_schema_name_error() {
+async fn test_table_exists() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    let request = CreateSchemaRequest {
-        name: "unique_name".to_string(),
-        description: None,
-        table_name: format!("{}_unique1", prefix),
-        columns: vec![ColumnDefinition::new("x", ColumnType::String)],
-        indexes: None,
-    };
-
+    let table_name = format!("{}_probe", prefix);
     store
-        .create_schema(request)
+        .create_schema(CreateSchemaRequest::new(
+            "probe",
+            &table_name,
+            vec![ColumnDefinition::new("label", ColumnType::String)],
+        ))
         .await
         .expect("Should create schema");
 
-    // Try to create another with the same name
-    let request2 = CreateSchemaRequest {
-        name: "unique_name".to_string(), // Same name
-        description: None,
-        table_name: format!("{}_unique2", prefix), // Different table
-        columns: vec![ColumnDefinition::new("y", ColumnType::String)],
-        indexes: None,
-    };
+    assert!(
+        store
+            .table_exists("probe")
+            .await
+            .expect("Should check table")
+    );
 
-    let result = store.create_schema(request2).await;
-    assert!(result.is_err());
+    sqlx::query(&format!("DROP TABLE \"{}\"", table_name))
+        .execute(store.pool())
+        .await
+        .expect("Should drop table out of band");
+
+    assert!(
+        !store
+            .table_exists("probe")
+            .await
+            .expect("Should check table")
+    );
+
+    let err = store
+        .table_exists("missing")
+        .await
+        .expect_err("Should reject unknown schema");
+    assert!(matches!(err, ObjectStoreError::SchemaNotFound(_)));
 
     cleanup_test(&store, &prefix).await;
 }
 
-// ==================== Instance Tests ====================
-
 #[tokio::test]
-async fn test_create_and_get_instance() {
+async fn test_repair_schema_recreates_dropped_table() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    // Create schema
-    let request = CreateSchemaRequest {
-        name: "products".to_string(),
-        description: None,
-        table_name: format!("{}_products", prefix),
-        columns: vec![
-            ColumnDefinition::new("name", ColumnType::String).not_null(),
-            ColumnDefinition::new("price", ColumnType::decimal(10, 2)),
-            ColumnDefinition::new("in_stock", ColumnType::Boolean),
-        ],
-        indexes: None,
-    };
-
+    let table_name = format!("{}_tickets", prefix);
     store
-        .create_schema(request)
+        .create_schema(
+            CreateSchemaRequest::new(
+                "tickets",
+                &table_name,
+                vec![
+                    ColumnDefinition::new("code", ColumnType::String).unique(),
+                    ColumnDefinition::new("priority", ColumnType::Integer),
+                ],
+            )
+            .with_indexes(vec![IndexDefinition::new(
+                "by_priority",
+                vec!["priority".to_string()],
+            )]),
+        )
         .await
         .expect("Should create schema");
 
-    // Create instance
-    let id = store
-        .create_instance(
-            "products",
-            serde_json::json!({
-                "name": "Widget",
-                "price": 19.99,
-                "in_stock": true
-            }),
-        )
+    let recreated = store
+        .repair_schema("tickets")
         .await
-        .expect("Should create instance");
+        .expect("Should accept intact table");
+    assert!(!recreated);
 
-    // Get instance
-    let instance = store
-        .get_instance("products", &id)
+    sqlx::query(&format!("DROP TABLE \"{}\"", table_name))
+        .execute(store.pool())
         .await
-        .expect("Should not error")
-        .expect("Instance should exist");
+        .expect("Should drop table out of band");
 
-    assert_eq!(instance.id, id);
-    assert_eq!(instance.properties["name"], "Widget");
-    assert_eq!(instance.properties["price"], 19.99);
-    assert_eq!(instance.properties["in_stock"], true);
+    let recreated = store
+        .repair_schema("tickets")
+        .await
+        .expect("Should repair schema");
+    assert!(recreated);
+    assert!(store.table_exists("tickets").await.unwrap());
+
+    store
+        .create_instance("tickets", serde_json::json!({"code": "T-1", "priority": 2}))
+        .await
+        .expect("Should insert after repair");
+    let err = store
+        .create_instance("tickets", serde_json::json!({"code": "T-1", "priority": 3}))
+        .await
+        .expect_err("Unique index should be recreated");
+    assert!(matches!(
+        err,
+        ObjectStoreError::Sql(_) | ObjectStoreError::Conflict(_)
+    ));
+
+    sqlx::query(&format!(
+        "ALTER TABLE \"{}\" DROP COLUMN priority",
+        table_name
+    ))
+    .execute(store.pool())
+    .await
+    .expect("Should alter table out of band");
+    let err = store
+        .repair_schema("tickets")
+        .await
+        .expect_err("Should refuse a table with a different structure");
+    assert!(matches!(err, ObjectStoreError::Conflict(_)));
 
     cleanup_test(&store, &prefix).await;
 }
 
 #[tokio::test]
-async fn test_update_instance() {
+async fn test_schema_drift_detects_out_of_band_changes() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    // Create schema
-    let request = CreateSchemaRequest {
-        name: "items".to_string(),
-        description: None,
-        table_name: format!("{}_items", prefix),
-        columns: vec![
-            ColumnDefinition::new("name", ColumnType::String).not_null(),
-            ColumnDefinition::new("count", ColumnType::Integer),
-        ],
-        indexes: None,
+    let table_name = format!("{}_drift", prefix);
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "drift",
+            &table_name,
+            vec![
+                ColumnDefinition::new("code", ColumnType::String),
+                ColumnDefinition::new("price", ColumnType::decimal(10, 2)),
+                ColumnDefinition::new("seen_at", ColumnType::timestamp()),
+            ],
+        ))
+        .await
+        .expect("Should create schema");
+
+    let drift = store.schema_drift("drift").await.expect("Should diff");
+    assert!(drift.is_empty(), "Unexpected drift: {:?}", drift);
+
+    let columns = store
+        .actual_columns("drift")
+        .await
+        .expect("Should read columns");
+    assert!(columns.contains(&("price".to_string(), "numeric(10,2)".to_string())));
+    assert!(columns.contains(&(
+        "seen_at".to_string(),
+        "timestamp with time zone".to_string()
+    )));
+
+    for statement in [
+        "ADD COLUMN extra TEXT",
+        "DROP COLUMN seen_at",
+        "ALTER COLUMN price TYPE TEXT",
+    ] {
+        sqlx::query(&format!("ALTER TABLE \"{}\" {}", table_name, statement))
+            .execute(store.pool())
+            .await
+            .expect("Should alter table out of band");
+    }
+
+    let columns = store
+        .actual_columns("drift")
+        .await
+        .expect("Should read columns");
+    assert!(columns.contains(&("extra".to_string(), "text".to_string())));
+    assert!(!columns.iter().any(|(name, _)| name == "seen_at"));
+
+    let drift = store.schema_drift("drift").await.expect("Should diff");
+    assert_eq!(drift.missing_columns, ["seen_at"]);
+    assert_eq!(drift.unexpected_columns, ["extra"]);
+    assert_eq!(
+        drift.type_mismatches,
+        [ColumnTypeMismatch {
+            column: "price".to_string(),
+            expected: "NUMERIC(10,2)".to_string(),
+            actual: "text".to_string(),
+        }]
+    );
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_drift_check_before_insert() {
+    let Some(db_url) = get_database_url() else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let prefix = test_prefix();
+    let metadata_table = format!("{}__schema", prefix);
+    let open_store = |mode: DriftCheck| {
+        ObjectStore::new(
+            StoreConfig::builder(&db_url)
+                .metadata_table(&metadata_table)
+                .drift_check(mode)
+                .build(),
+        )
     };
+    let store = open_store(DriftCheck::Error)
+        .await
+        .expect("Should create store");
 
+    let table_name = format!("{}_drifted", prefix);
     store
-        .create_schema(request)
+        .create_schema(CreateSchemaRequest::new(
+            "drifted",
+            &table_name,
+            vec![
+                ColumnDefinition::new("title", ColumnType::String),
+                ColumnDefinition::new("score", ColumnType::Integer),
+            ],
+        ))
         .await
         .expect("Should create schema");
 
-    // Create instance
-    let id = store
+    store
         .create_instance(
-            "items",
-            serde_json::json!({
-                "name": "Original",
-                "count": 10
-            }),
+            "drifted",
+            serde_json::json!({"title": "before", "score": 1}),
         )
         .await
-        .expect("Should create instance");
+        .expect("Should insert before drift");
 
-    // Update instance
-    store
-        .update_instance(
-            "items",
-            &id,
-            serde_json::json!({
-                "name": "Updated",
-                "count": 20
-            }),
-        )
+    sqlx::query(&format!("ALTER TABLE \"{}\" DROP COLUMN score", table_name))
+        .execute(store.pool())
         .await
-        .expect("Should update instance");
+        .expect("Should drop column out of band");
 
-    // Verify update
+    let err = store
+        .create_instance("drifted", serde_json::json!({"title": "after", "score": 2}))
+        .await
+        .expect_err("Insert into drifted table should fail");
+    match err {
+        ObjectStoreError::Conflict(message) => {
+            assert!(message.contains("Schema drift detected"), "{}", message);
+            assert!(message.contains("[score]"), "{}", message);
+        }
+        other => panic!("Expected drift conflict, got {:?}", other),
+    }
+
+    let repairing = open_store(DriftCheck::Repair)
+        .await
+        .expect("Should create store");
+    let id = repairing
+        .create_instance("drifted", serde_json::json!({"title": "after", "score": 2}))
+        .await
+        .expect("Should repair and insert");
+
+    let drift = store.schema_drift("drifted").await.expect("Should diff");
+    assert!(drift.is_empty(), "Unexpected drift: {:?}", drift);
     let instance = store
-        .get_instance("items", &id)
+        .get_instance("drifted", &id)
         .await
-        .expect("Should not error")
+        .expect("Should get instance")
         .expect("Instance should exist");
-
-    assert_eq!(instance.properties["name"], "Updated");
-    assert_eq!(instance.properties["count"], 20);
+    assert_eq!(instance.properties["score"], 2);
 
     cleanup_test(&store, &prefix).await;
 }
 
 #[tokio::test]
-async fn test_delete_instance() {
+async fn test_for_each_schema_visits_all_tables() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    // Create schema
-    let request = CreateSchemaRequest {
-        name: "temp".to_string(),
-        description: None,
-        table_name: format!("{}_temp", prefix),
-        columns: vec![ColumnDefinition::new("value", ColumnType::String)],
-        indexes: None,
-    };
-
+    for i in 1..=3 {
+        store
+            .create_schema(CreateSchemaRequest::new(
+                format!("maint_{}", i),
+                format!("{}_maint_{}", prefix, i),
+                vec![ColumnDefinition::new("data", ColumnType::Json)],
+            ))
+            .await
+            .expect("Should create schema");
+    }
     store
-        .create_schema(request)
+        .delete_schema("maint_3")
         .await
-        .expect("Should create schema");
+        .expect("Should delete schema");
 
-    // Create instance
-    let id = store
-        .create_instance("temp", serde_json::json!({"value": "test"}))
+    let listed = store.list_schemas().await.expect("Should list schemas");
+
+    let pool = store.pool().clone();
+    let mut analyzed = Vec::new();
+    let visited = store
+        .for_each_schema(false, |schema| {
+            analyzed.push(schema.name.clone());
+            let pool = pool.clone();
+            async move {
+                sqlx::query(&format!("ANALYZE \"{}\"", schema.table_name))
+                    .execute(&pool)
+                    .await?;
+                Ok::<(), ObjectStoreError>(())
+            }
+        })
         .await
-        .expect("Should create instance");
+        .expect("Should visit schemas");
 
-    // Delete instance
-    store
-        .delete_instance("temp", &id)
-        .await
-        .expect("Should delete instance");
+    assert_eq!(visited, listed.len());
+    assert_eq!(analyzed.len(), 2);
+    assert!(!analyzed.contains(&"maint_3".to_string()));
 
-    // Should not be found
-    let found = store
-        .get_instance("temp", &id)
+    // Soft-deleted schemas are included on request
+    let visited_all = store
+        .for_each_schema(true, |_| async { Ok::<(), ObjectStoreError>(()) })
         .await
-        .expect("Should not error");
-
-    assert!(found.is_none());
+        .expect("Should visit schemas");
+    assert_eq!(visited_all, 3);
 
+    // Drop the soft-deleted table, which cleanup_test does not see
+    let _ = sqlx::query(&format!("DROP TABLE IF EXISTS \"{}_maint_3\"", prefix))
+        .execute(store.pool())
+        .await;
     cleanup_test(&store, &prefix).await;
 }
 
 #[tokio::test]
-async fn test_query_instances_simple() {
+async fn test_delete_schema() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    // Create schema
     let request = CreateSchemaRequest {
-        name: "products".to_string(),
+        name: "to_delete".to_string(),
         description: None,
-        table_name: format!("{}_products", prefix),
-        columns: vec![
-            ColumnDefinition::new("name", ColumnType::String).not_null(),
-            ColumnDefinition::new("category", ColumnType::String),
-            ColumnDefinition::new("price", ColumnType::decimal(10, 2)),
-        ],
+        table_name: format!("{}_delete", prefix),
+        columns: vec![ColumnDefinition::new("value", ColumnType::String)],
         indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
     };
 
     store
@@ -460,285 +721,3931 @@ async fn test_query_instances_simple() {
         .await
         .expect("Should create schema");
 
-    // Create multiple instances
-    for i in 1..=5 {
-        store
-            .create_instance(
-                "products",
-                serde_json::json!({
-                    "name": format!("Product {}", i),
-                    "category": if i % 2 == 0 { "even" } else { "odd" },
-                    "price": i as f64 * 10.0
-                }),
-            )
-            .await
-            .expect("Should create instance");
-    }
+    // Delete the schema
+    store
+        .delete_schema("to_delete")
+        .await
+        .expect("Should delete schema");
 
-    // Query all
-    let filter = SimpleFilter::new("products".to_string());
-    let (instances, count) = store
-        .query_instances(filter)
+    // Should not be found anymore (soft delete by default)
+    let found = store
+        .get_schema("to_delete")
         .await
-        .expect("Should query instances");
+        .expect("Should not error");
 
-    assert_eq!(count, 5);
-    assert_eq!(instances.len(), 5);
+    assert!(found.is_none());
 
-    // Query with limit
-    let filter = SimpleFilter::new("products".to_string()).with_limit(2);
-    let (instances, count) = store
-        .query_instances(filter)
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_create_instance_in_deleted_schema() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let request = CreateSchemaRequest::new(
+        "retired",
+        format!("{}_retired", prefix),
+        vec![ColumnDefinition::new("value", ColumnType::String)],
+    );
+
+    store
+        .create_schema(request)
         .await
-        .expect("Should query instances");
+        .expect("Should create schema");
+    store
+        .delete_schema("retired")
+        .await
+        .expect("Should delete schema");
 
-    assert_eq!(count, 5); // Total count still 5
-    assert_eq!(instances.len(), 2); // But only 2 returned
+    let result = store
+        .create_instance("retired", serde_json::json!({ "value": "x" }))
+        .await;
+    assert!(matches!(result, Err(ObjectStoreError::SchemaDeleted(ref name)) if name == "retired"));
+
+    // Schemas that never existed are still reported as not found
+    let result = store
+        .create_instance("never_existed", serde_json::json!({ "value": "x" }))
+        .await;
+    assert!(matches!(result, Err(ObjectStoreError::SchemaNotFound(_))));
 
     cleanup_test(&store, &prefix).await;
 }
 
 #[tokio::test]
-async fn test_filter_instances_with_condition() {
+async fn test_update_schema_description_tri_state() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    // Create schema
-    let request = CreateSchemaRequest {
-        name: "users".to_string(),
-        description: None,
-        table_name: format!("{}_users", prefix),
-        columns: vec![
-            ColumnDefinition::new("name", ColumnType::String).not_null(),
-            ColumnDefinition::new("age", ColumnType::Integer),
-            ColumnDefinition::new("active", ColumnType::Boolean),
-        ],
-        indexes: None,
-    };
+    let request = CreateSchemaRequest::new(
+        "described",
+        format!("{}_described", prefix),
+        vec![ColumnDefinition::new("x", ColumnType::String)],
+    )
+    .with_description("Original description");
 
     store
         .create_schema(request)
         .await
         .expect("Should create schema");
 
-    // Create users
-    let users = vec![
-        ("Alice", 25, true),
-        ("Bob", 30, true),
-        ("Charlie", 35, false),
-        ("Diana", 28, true),
-    ];
+    // Leaving the description unchanged keeps the stored value
+    let updated = store
+        .update_schema("described", UpdateSchemaRequest::new())
+        .await
+        .expect("Should update schema");
+    assert_eq!(
+        updated.description,
+        Some("Original description".to_string())
+    );
+
+    // Clearing the description nulls it out
+    let updated = store
+        .update_schema("described", UpdateSchemaRequest::new().clear_description())
+        .await
+        .expect("Should update schema");
+    assert!(updated.description.is_none());
 
-    for (name, age, active) in users {
-        store
-            .create_instance(
-                "users",
-                serde_json::json!({
-                    "name": name,
-                    "age": age,
-                    "active": active
-                }),
-            )
-            .await
-            .expect("Should create instance");
-    }
+    let fetched = store
+        .get_schema("described")
+        .await
+        .expect("Should get schema")
+        .expect("Schema should exist");
+    assert!(fetched.description.is_none());
 
-    // Filter by active = true
-    let condition = Condition {
-        op: "EQ".to_string(),
-        arguments: Some(vec![serde_json::json!("active"), serde_json::json!(true)]),
-    };
+    cleanup_test(&store, &prefix).await;
+}
 
-    let filter = FilterRequest {
-        condition: Some(condition),
-        sort_by: None,
-        sort_order: None,
-        limit: 100,
-        offset: 0,
+#[tokio::test]
+async fn test_get_schemas_batch() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
     };
 
-    let (instances, count) = store
-        .filter_instances("users", filter)
+    for name in ["alpha", "beta", "gamma"] {
+        store
+            .create_schema(CreateSchemaRequest::new(
+                name,
+                format!("{}_{}", prefix, name),
+                vec![ColumnDefinition::new("label", ColumnType::String)],
+            ))
+            .await
+            .expect("Should create schema");
+    }
+    store
+        .delete_schema("gamma")
         .await
-        .expect("Should filter instances");
+        .expect("Should delete schema");
 
-    assert_eq!(count, 3); // Alice, Bob, Diana
-    assert_eq!(instances.len(), 3);
+    let names: Vec<String> = ["alpha", "beta", "gamma", "missing"]
+        .iter()
+        .map(|n| n.to_string())
+        .collect();
+    let schemas = store.get_schemas(&names).await.expect("Should get schemas");
+
+    assert_eq!(schemas.len(), 2);
+    assert_eq!(schemas["alpha"].table_name, format!("{}_alpha", prefix));
+    assert!(schemas.contains_key("beta"));
+    assert!(!schemas.contains_key("gamma"));
+    assert!(!schemas.contains_key("missing"));
+
+    assert!(store.get_schemas(&[]).await.unwrap().is_empty());
 
     cleanup_test(&store, &prefix).await;
 }
 
 #[tokio::test]
-async fn test_instance_exists() {
+async fn test_get_schema_version() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    // Create schema
-    let request = CreateSchemaRequest {
-        name: "flags".to_string(),
-        description: None,
-        table_name: format!("{}_flags", prefix),
-        columns: vec![
-            ColumnDefinition::new("key", ColumnType::String)
-                .unique()
-                .not_null(),
-            ColumnDefinition::new("enabled", ColumnType::Boolean),
-        ],
-        indexes: None,
-    };
+    assert!(
+        store
+            .get_schema_version("versioned")
+            .await
+            .expect("Should query version")
+            .is_none()
+    );
 
-    store
-        .create_schema(request)
+    let schema = store
+        .create_schema(CreateSchemaRequest::new(
+            "versioned",
+            format!("{}_versioned", prefix),
+            vec![ColumnDefinition::new("x", ColumnType::String)],
+        ))
         .await
         .expect("Should create schema");
 
-    store
-        .create_instance(
-            "flags",
-            serde_json::json!({
-                "key": "feature_x",
-                "enabled": true
-            }),
+    let version = store
+        .get_schema_version("versioned")
+        .await
+        .expect("Should query version")
+        .expect("Schema should exist");
+    assert_eq!(version, schema.updated_at);
+
+    let updated = store
+        .update_schema(
+            "versioned",
+            UpdateSchemaRequest::new().with_columns(vec![
+                ColumnDefinition::new("x", ColumnType::String),
+                ColumnDefinition::new("y", ColumnType::Integer),
+            ]),
         )
         .await
-        .expect("Should create instance");
+        .expect("Should update schema");
 
-    // Check exists
-    let filter = SimpleFilter::new("flags".to_string());
-    let exists = store
-        .instance_exists(filter)
+    let new_version = store
+        .get_schema_version("versioned")
         .await
-        .expect("Should check existence");
-
-    assert!(exists.is_some());
+        .expect("Should query version")
+        .expect("Schema should exist");
+    assert_ne!(new_version, version);
+    assert_eq!(new_version, updated.updated_at);
 
     cleanup_test(&store, &prefix).await;
 }
 
-// ==================== Validation Tests ====================
-
 #[tokio::test]
-async fn test_type_validation() {
+async fn test_update_schema_rejects_dropping_indexed_column() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    // Create schema with strict types
-    let request = CreateSchemaRequest {
-        name: "typed".to_string(),
-        description: None,
-        table_name: format!("{}_typed", prefix),
-        columns: vec![
-            ColumnDefinition::new("count", ColumnType::Integer).not_null(),
-            ColumnDefinition::new("price", ColumnType::decimal(10, 2)),
+    let request = CreateSchemaRequest::new(
+        "indexed",
+        format!("{}_indexed", prefix),
+        vec![
+            ColumnDefinition::new("code", ColumnType::String),
+            ColumnDefinition::new("label", ColumnType::String),
         ],
-        indexes: None,
-    };
+    )
+    .with_indexes(vec![IndexDefinition::new(
+        "code_idx",
+        vec!["code".to_string()],
+    )]);
 
     store
         .create_schema(request)
         .await
         .expect("Should create schema");
 
-    // Valid types
+    // Dropping the indexed column while the index is still declared fails
     let result = store
-        .create_instance(
-            "typed",
-            serde_json::json!({
-                "count": 42,
-                "price": 19.99
-            }),
+        .update_schema(
+            "indexed",
+            UpdateSchemaRequest::new()
+                .with_columns(vec![ColumnDefinition::new("label", ColumnType::String)]),
         )
         .await;
+    match result {
+        Err(ObjectStoreError::Validation(msg)) => {
+            assert!(msg.contains("code"));
+            assert!(msg.contains("code_idx"));
+        }
+        other => panic!("Expected validation error, got {:?}", other),
+    }
 
-    assert!(result.is_ok());
-
-    // Invalid types - string for integer (should fail validation)
-    let result = store
-        .create_instance(
-            "typed",
-            serde_json::json!({
-                "count": "not a number",
-                "price": 9.99
-            }),
+    // Metadata is untouched
+    let schema = store
+        .get_schema("indexed")
+        .await
+        .expect("Should get schema")
+        .expect("Schema should exist");
+    assert_eq!(schema.columns.len(), 2);
+    assert_eq!(schema.indexes.as_ref().map(Vec::len), Some(1));
+
+    // Removing the index in the same request allows the drop
+    let updated = store
+        .update_schema(
+            "indexed",
+            UpdateSchemaRequest::new()
+                .with_columns(vec![ColumnDefinition::new("label", ColumnType::String)])
+                .with_indexes(vec![]),
         )
-        .await;
-
-    assert!(result.is_err());
+        .await
+        .expect("Should drop column together with its index");
+    assert_eq!(updated.columns.len(), 1);
+    assert_eq!(updated.indexes.as_ref().map(Vec::len), Some(0));
 
     cleanup_test(&store, &prefix).await;
 }
 
 #[tokio::test]
-async fn test_required_column_validation() {
+async fn test_preview_update_schema() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    // Create schema with required column
-    let request = CreateSchemaRequest {
-        name: "required".to_string(),
-        description: None,
-        table_name: format!("{}_required", prefix),
-        columns: vec![
-            ColumnDefinition::new("name", ColumnType::String).not_null(),
-            ColumnDefinition::new("optional", ColumnType::String),
-        ],
-        indexes: None,
-    };
-
+    let table_name = format!("{}_preview", prefix);
     store
-        .create_schema(request)
+        .create_schema(CreateSchemaRequest::new(
+            "preview",
+            &table_name,
+            vec![
+                ColumnDefinition::new("code", ColumnType::String),
+                ColumnDefinition::new("label", ColumnType::String),
+                ColumnDefinition::new("qty", ColumnType::Integer),
+            ],
+        ))
         .await
         .expect("Should create schema");
 
-    // Missing required column
-    let result = store
+    store
         .create_instance(
-            "required",
-            serde_json::json!({
-                "optional": "value"
-            }),
+            "preview",
+            serde_json::json!({"code": "a", "label": "first", "qty": 1}),
         )
-        .await;
+        .await
+        .expect("Should create instance");
+    store
+        .create_instance("preview", serde_json::json!({"qty": 2}))
+        .await
+        .expect("Should create instance");
 
-    assert!(result.is_err());
+    let request = UpdateSchemaRequest::new().with_columns(vec![
+        ColumnDefinition::new("code", ColumnType::String).not_null(),
+        ColumnDefinition::new("qty", ColumnType::String),
+        ColumnDefinition::new("note", ColumnType::String),
+    ]);
+    let preview = store
+        .preview_update_schema("preview", &request)
+        .await
+        .expect("Should preview update");
+
+    let table = format!("\"{}\"", table_name);
+    assert_eq!(preview.statements.len(), 4);
+    assert!(
+        preview.statements[0].starts_with(&format!("ALTER TABLE {} ADD COLUMN \"note\"", table))
+    );
+    assert_eq!(
+        preview.statements[1],
+        format!("ALTER TABLE {} DROP COLUMN \"label\"", table)
+    );
+    assert_eq!(
+        preview.statements[2],
+        format!("ALTER TABLE {} ALTER COLUMN \"code\" SET NOT NULL", table)
+    );
+    assert_eq!(
+        preview.statements[3],
+        format!("ALTER TABLE {} ALTER COLUMN \"qty\" TYPE TEXT", table)
+    );
+
+    assert_eq!(preview.warnings.len(), 3);
+    assert!(preview.warnings[0].contains("'code' has 1 NULL value"));
+    assert!(preview.warnings[1].contains("'label' discards 1 stored value"));
+    assert!(preview.warnings[2].contains("Changing column 'qty'"));
+
+    // Neither the metadata nor the table changed
+    let schema = store
+        .get_schema("preview")
+        .await
+        .expect("Should get schema")
+        .expect("Schema should exist");
+    let names: Vec<_> = schema.columns.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["code", "label", "qty"]);
+    assert!(schema.columns[0].nullable);
+    let instances = store
+        .query_instances(SimpleFilter::new("preview"))
+        .await
+        .expect("Should query")
+        .0;
+    assert!(instances.iter().any(|i| i.properties["label"] == "first"));
+
+    // Requests update_schema would reject are rejected by the preview too
+    let too_long = UpdateSchemaRequest::new().with_columns(vec![ColumnDefinition::new(
+        "c".repeat(64),
+        ColumnType::String,
+    )]);
+    let result = store.preview_update_schema("preview", &too_long).await;
+    assert!(matches!(result, Err(ObjectStoreError::Validation(_))));
 
     cleanup_test(&store, &prefix).await;
 }
 
-// ==================== Configuration Tests ====================
-
 #[tokio::test]
-async fn test_store_without_soft_delete() {
-    let Some(db_url) = get_database_url() else {
+async fn test_duplicate_schema_name_error() {
+    let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    let prefix = test_prefix();
-    let metadata_table = format!("{}__schema", prefix);
-
-    let config = StoreConfig::builder(&db_url)
-        .metadata_table(&metadata_table)
+    let request = CreateSchemaRequest {
+        name: "unique_name".to_string(),
+        description: None,
+        table_name: format!("{}_unique1", prefix),
+        columns: vec![ColumnDefinition::new("x", ColumnType::String)],
+        indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
+    };
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    // Try to create another with the same name
+    let request2 = CreateSchemaRequest {
+        name: "unique_name".to_string(), // Same name
+        description: None,
+        table_name: format!("{}_unique2", prefix), // Different table
+        columns: vec![ColumnDefinition::new("y", ColumnType::String)],
+        indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
+    };
+
+    let result = store.create_schema(request2).await;
+    assert!(result.is_err());
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_create_schema_with_default_index_predicate() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let table_name = format!("{}_open_orders", prefix);
+    let request = CreateSchemaRequest::new(
+        "open_orders",
+        &table_name,
+        vec![ColumnDefinition::new("status", ColumnType::String)],
+    )
+    .with_default_index_predicate("status = 'open'");
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    let indexdef: (String,) =
+        sqlx::query_as("SELECT indexdef FROM pg_indexes WHERE tablename = $1 AND indexname = $2")
+            .bind(&table_name)
+            .bind(format!("idx_{}_default", table_name))
+            .fetch_one(store.pool())
+            .await
+            .expect("Default index should exist");
+    assert!(indexdef.0.contains("deleted = false"));
+    assert!(indexdef.0.contains("'open'"));
+
+    // Predicates referencing unknown columns are rejected before any DDL runs
+    let bad_request = CreateSchemaRequest::new(
+        "bad_orders",
+        format!("{}_bad_orders", prefix),
+        vec![ColumnDefinition::new("status", ColumnType::String)],
+    )
+    .with_default_index_predicate("owner = 'me'");
+
+    let result = store.create_schema(bad_request).await;
+    assert!(matches!(result, Err(ObjectStoreError::Validation(_))));
+    assert!(store.get_schema("bad_orders").await.unwrap().is_none());
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_create_schema_without_default_index() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let indexed_table = format!("{}_indexed", prefix);
+    let lookup_table = format!("{}_lookup", prefix);
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "indexed",
+            &indexed_table,
+            vec![ColumnDefinition::new("code", ColumnType::String)],
+        ))
+        .await
+        .expect("Should create schema");
+    let lookup = store
+        .create_schema(
+            CreateSchemaRequest::new(
+                "lookup",
+                &lookup_table,
+                vec![ColumnDefinition::new("code", ColumnType::String)],
+            )
+            .with_default_index(false),
+        )
+        .await
+        .expect("Should create schema without default index");
+    assert!(!lookup.default_index);
+
+    for (table, expected) in [(&indexed_table, 1), (&lookup_table, 0)] {
+        let (count,): (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM pg_indexes WHERE tablename = $1 AND indexname = $2",
+        )
+        .bind(table)
+        .bind(format!("idx_{}_default", table))
+        .fetch_one(store.pool())
+        .await
+        .expect("Should query pg_indexes");
+        assert_eq!(count, expected);
+    }
+
+    // The choice is persisted in the metadata
+    let lookup = store
+        .get_schema("lookup")
+        .await
+        .expect("Should get schema")
+        .expect("Schema should exist");
+    assert!(!lookup.default_index);
+    let indexed = store
+        .get_schema("indexed")
+        .await
+        .expect("Should get schema")
+        .expect("Schema should exist");
+    assert!(indexed.default_index);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_create_schema_with_storage_params() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let table = format!("{}_counters", prefix);
+    store
+        .create_schema(
+            CreateSchemaRequest::new(
+                "counters",
+                &table,
+                vec![ColumnDefinition::new("hits", ColumnType::Integer)],
+            )
+            .with_storage_param("fillfactor", "70")
+            .with_storage_param("autovacuum_enabled", "true"),
+        )
+        .await
+        .expect("Should create schema with storage params");
+
+    let (reloptions,): (Vec<String>,) =
+        sqlx::query_as("SELECT reloptions FROM pg_class WHERE relname = $1")
+            .bind(&table)
+            .fetch_one(store.pool())
+            .await
+            .expect("Should query pg_class");
+    assert!(reloptions.contains(&"fillfactor=70".to_string()));
+    assert!(reloptions.contains(&"autovacuum_enabled=true".to_string()));
+
+    // The parameters are persisted in the metadata
+    let counters = store
+        .get_schema("counters")
+        .await
+        .expect("Should get schema")
+        .expect("Schema should exist");
+    let params = counters
+        .storage_params
+        .expect("Should persist storage params");
+    assert_eq!(params.get("fillfactor").map(String::as_str), Some("70"));
+
+    // Parameters outside the whitelist are rejected before anything is created
+    let err = store
+        .create_schema(
+            CreateSchemaRequest::new(
+                "rejected",
+                format!("{}_rejected", prefix),
+                vec![ColumnDefinition::new("hits", ColumnType::Integer)],
+            )
+            .with_storage_param("toast_tuple_target", "128"),
+        )
+        .await
+        .expect_err("Should reject storage param");
+    assert!(err.to_string().contains("toast_tuple_target"));
+    assert!(store.get_schema("rejected").await.unwrap().is_none());
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_create_schema_in_tablespace() {
+    // Needs a non-default tablespace created by the test environment
+    let Ok(tablespace) = std::env::var("TEST_TABLESPACE") else {
+        eprintln!("Skipping test: TEST_TABLESPACE not set");
+        return;
+    };
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let table = format!("{}_hot_rows", prefix);
+    store
+        .create_schema(
+            CreateSchemaRequest::new(
+                "hot_rows",
+                &table,
+                vec![ColumnDefinition::new("hits", ColumnType::Integer)],
+            )
+            .with_tablespace(&tablespace),
+        )
+        .await
+        .expect("Should create schema in tablespace");
+
+    let (table_space,): (Option<String>,) =
+        sqlx::query_as("SELECT tablespace FROM pg_tables WHERE tablename = $1")
+            .bind(&table)
+            .fetch_one(store.pool())
+            .await
+            .expect("Should query pg_tables");
+    assert_eq!(table_space.as_deref(), Some(tablespace.as_str()));
+
+    let (index_space,): (Option<String>,) =
+        sqlx::query_as("SELECT tablespace FROM pg_indexes WHERE indexname = $1")
+            .bind(format!("idx_{}_default", table))
+            .fetch_one(store.pool())
+            .await
+            .expect("Should query pg_indexes");
+    assert_eq!(index_space.as_deref(), Some(tablespace.as_str()));
+
+    let schema = store
+        .get_schema("hot_rows")
+        .await
+        .expect("Should get schema")
+        .expect("Schema should exist");
+    assert_eq!(schema.tablespace.as_deref(), Some(tablespace.as_str()));
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_create_schema_rejects_overlong_identifiers() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let long_table = format!("{}_{}", prefix, "t".repeat(64));
+    let result = store
+        .create_schema(CreateSchemaRequest::new(
+            "long_table",
+            long_table,
+            vec![ColumnDefinition::new("name", ColumnType::String)],
+        ))
+        .await;
+    assert!(matches!(result, Err(ObjectStoreError::Validation(_))));
+
+    let result = store
+        .create_schema(CreateSchemaRequest::new(
+            "long_column",
+            format!("{}_long_column", prefix),
+            vec![ColumnDefinition::new("c".repeat(64), ColumnType::String)],
+        ))
+        .await;
+    assert!(matches!(result, Err(ObjectStoreError::Validation(_))));
+
+    // Generated index names longer than the limit are shortened, not rejected
+    let table_name = format!("{}_{}", prefix, "w".repeat(40));
+    store
+        .create_schema(
+            CreateSchemaRequest::new(
+                "wide_index",
+                &table_name,
+                vec![ColumnDefinition::new("status", ColumnType::String)],
+            )
+            .with_indexes(vec![IndexDefinition::new(
+                "status_lookup_index",
+                vec!["status".to_string()],
+            )]),
+        )
+        .await
+        .expect("Should create schema with long generated index name");
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_ensure_schema_create_alter_and_noop() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let table_name = format!("{}_settings", prefix);
+    let definition = CreateSchemaRequest::new(
+        "settings",
+        &table_name,
+        vec![ColumnDefinition::new("key", ColumnType::String).not_null()],
+    )
+    .with_description("App settings");
+
+    // Create path
+    let created = store
+        .ensure_schema(definition.clone())
+        .await
+        .expect("Should create schema");
+    assert_eq!(created.columns.len(), 1);
+
+    // No-op path: nothing is rewritten
+    let unchanged = store
+        .ensure_schema(definition.clone())
+        .await
+        .expect("Should accept matching definition");
+    assert_eq!(unchanged.id, created.id);
+    assert_eq!(unchanged.updated_at, created.updated_at);
+
+    // Alter path: the new column is added to the table
+    let mut extended = definition.clone();
+    extended
+        .columns
+        .push(ColumnDefinition::new("value", ColumnType::String));
+    let altered = store
+        .ensure_schema(extended)
+        .await
+        .expect("Should alter schema");
+    assert_eq!(altered.id, created.id);
+    assert_eq!(altered.columns.len(), 2);
+
+    store
+        .create_instance(
+            "settings",
+            serde_json::json!({"key": "theme", "value": "dark"}),
+        )
+        .await
+        .expect("Should insert into the added column");
+
+    // Definitions create_schema would reject are rejected before any DDL
+    for column in [
+        ColumnDefinition::new("id", ColumnType::String),
+        ColumnDefinition::new("Value2", ColumnType::String),
+        ColumnDefinition::new("key", ColumnType::String),
+        ColumnDefinition::new("mode", ColumnType::Enum { values: vec![] }),
+    ] {
+        let mut invalid = definition.clone();
+        invalid.columns.push(column);
+        let result = store.ensure_schema(invalid).await;
+        assert!(matches!(result, Err(ObjectStoreError::Validation(_))));
+    }
+
+    // Table settings that only apply on creation are not silently ignored
+    let result = store
+        .ensure_schema(definition.clone().with_storage_param("fillfactor", "70"))
+        .await;
+    assert!(matches!(result, Err(ObjectStoreError::Validation(_))));
+
+    let schema = store
+        .get_schema("settings")
+        .await
+        .expect("Should get schema")
+        .expect("Schema should exist");
+    assert_eq!(schema.columns.len(), 2);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_apply_migration() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let table = format!("{}_ledger", prefix);
+    let original = CreateSchemaRequest::new(
+        "ledger",
+        &table,
+        vec![
+            ColumnDefinition::new("code", ColumnType::String),
+            ColumnDefinition::new("amount", ColumnType::Integer),
+        ],
+    )
+    .with_indexes(vec![IndexDefinition::new(
+        "by_code",
+        vec!["code".to_string()],
+    )]);
+    store
+        .create_schema(original.clone())
+        .await
+        .expect("Should create schema");
+    store
+        .create_instance("ledger", serde_json::json!({"code": "a", "amount": 12}))
+        .await
+        .expect("Should create instance");
+
+    let index_exists = |name: String| {
+        let pool = store.pool().clone();
+        async move {
+            let (exists,): (bool,) =
+                sqlx::query_as("SELECT EXISTS(SELECT 1 FROM pg_indexes WHERE indexname = $1)")
+                    .bind(name)
+                    .fetch_one(&pool)
+                    .await
+                    .expect("Should query pg_indexes");
+            exists
+        }
+    };
+    assert!(index_exists(format!("{}_by_code", table)).await);
+
+    // Add a column, drop the index and change a type in one migration
+    let desired = CreateSchemaRequest::new(
+        "ledger",
+        &table,
+        vec![
+            ColumnDefinition::new("code", ColumnType::String),
+            ColumnDefinition::new("amount", ColumnType::decimal(12, 2)),
+            ColumnDefinition::new("note", ColumnType::String),
+        ],
+    );
+    let migrated = store
+        .apply_migration(desired)
+        .await
+        .expect("Should apply migration");
+    assert_eq!(migrated.columns.len(), 3);
+    assert!(migrated.indexes.is_none());
+    assert!(!index_exists(format!("{}_by_code", table)).await);
+
+    let (instances, _) = store
+        .query_instances(SimpleFilter::new("ledger"))
+        .await
+        .expect("Should query");
+    assert_eq!(instances[0].properties["amount"].as_f64(), Some(12.0));
+
+    // A failing step rolls back the whole migration: text has no automatic
+    // cast to bigint, so changing `code` to Integer fails after `extra` is added
+    let failing = CreateSchemaRequest::new(
+        "ledger",
+        &table,
+        vec![
+            ColumnDefinition::new("code", ColumnType::Integer),
+            ColumnDefinition::new("amount", ColumnType::decimal(12, 2)),
+            ColumnDefinition::new("note", ColumnType::String),
+            ColumnDefinition::new("extra", ColumnType::String),
+        ],
+    );
+    assert!(store.apply_migration(failing).await.is_err());
+
+    let schema = store
+        .get_schema("ledger")
+        .await
+        .expect("Should get schema")
+        .expect("Schema should exist");
+    assert_eq!(schema.columns, migrated.columns);
+    let (extra_exists,): (bool,) = sqlx::query_as(
+        "SELECT EXISTS(SELECT 1 FROM information_schema.columns \
+         WHERE table_name = $1 AND column_name = 'extra')",
+    )
+    .bind(&table)
+    .fetch_one(store.pool())
+    .await
+    .expect("Should query information_schema");
+    assert!(!extra_exists);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+// ==================== Instance Tests ====================
+
+#[tokio::test]
+async fn test_create_and_get_instance() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    // Create schema
+    let request = CreateSchemaRequest {
+        name: "products".to_string(),
+        description: None,
+        table_name: format!("{}_products", prefix),
+        columns: vec![
+            ColumnDefinition::new("name", ColumnType::String).not_null(),
+            ColumnDefinition::new("price", ColumnType::decimal(10, 2)),
+            ColumnDefinition::new("in_stock", ColumnType::Boolean),
+        ],
+        indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
+    };
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    // Create instance
+    let id = store
+        .create_instance(
+            "products",
+            serde_json::json!({
+                "name": "Widget",
+                "price": 19.99,
+                "in_stock": true
+            }),
+        )
+        .await
+        .expect("Should create instance");
+
+    // Get instance
+    let instance = store
+        .get_instance("products", &id)
+        .await
+        .expect("Should not error")
+        .expect("Instance should exist");
+
+    assert_eq!(instance.id, id);
+    assert_eq!(instance.properties["name"], "Widget");
+    assert_eq!(instance.properties["price"], 19.99);
+    assert_eq!(instance.properties["in_stock"], true);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_require_instance() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "gadgets",
+            format!("{}_gadgets", prefix),
+            vec![ColumnDefinition::new("name", ColumnType::String)],
+        ))
+        .await
+        .expect("Should create schema");
+
+    let id = store
+        .create_instance("gadgets", serde_json::json!({"name": "Sprocket"}))
+        .await
+        .expect("Should create instance");
+
+    // Present
+    let instance = store
+        .require_instance("gadgets", &id)
+        .await
+        .expect("Instance should exist");
+    assert_eq!(instance.properties["name"], "Sprocket");
+
+    // Instance missing
+    let result = store.require_instance("gadgets", "missing-id").await;
+    assert!(matches!(result, Err(ObjectStoreError::InstanceNotFound(id)) if id == "missing-id"));
+
+    // Schema missing
+    let result = store.require_instance("no_such_schema", &id).await;
+    assert!(matches!(result, Err(ObjectStoreError::SchemaNotFound(_))));
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_create_instance_with_client_id() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let request = CreateSchemaRequest::new(
+        "idempotent",
+        format!("{}_idempotent", prefix),
+        vec![ColumnDefinition::new("name", ColumnType::String)],
+    );
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    let id = store
+        .create_instance_with_id(
+            "idempotent",
+            "order-2024-0001",
+            serde_json::json!({"name": "first"}),
+        )
+        .await
+        .expect("Should create instance with client id");
+    assert_eq!(id, "order-2024-0001");
+
+    let instance = store
+        .get_instance("idempotent", "order-2024-0001")
+        .await
+        .expect("Should get instance")
+        .expect("Instance should exist");
+    assert_eq!(instance.properties["name"], "first");
+
+    // Re-inserting the same id is a typed conflict
+    let result = store
+        .create_instance_with_id(
+            "idempotent",
+            "order-2024-0001",
+            serde_json::json!({"name": "second"}),
+        )
+        .await;
+    assert!(matches!(result, Err(ObjectStoreError::Conflict(_))));
+
+    // Illegal ids are rejected up front
+    let result = store
+        .create_instance_with_id("idempotent", "", serde_json::json!({"name": "x"}))
+        .await;
+    assert!(matches!(result, Err(ObjectStoreError::Validation(_))));
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_update_instance() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    // Create schema
+    let request = CreateSchemaRequest {
+        name: "items".to_string(),
+        description: None,
+        table_name: format!("{}_items", prefix),
+        columns: vec![
+            ColumnDefinition::new("name", ColumnType::String).not_null(),
+            ColumnDefinition::new("count", ColumnType::Integer),
+        ],
+        indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
+    };
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    // Create instance
+    let id = store
+        .create_instance(
+            "items",
+            serde_json::json!({
+                "name": "Original",
+                "count": 10
+            }),
+        )
+        .await
+        .expect("Should create instance");
+
+    // Update instance
+    store
+        .update_instance(
+            "items",
+            &id,
+            serde_json::json!({
+                "name": "Updated",
+                "count": 20
+            }),
+        )
+        .await
+        .expect("Should update instance");
+
+    // Verify update
+    let instance = store
+        .get_instance("items", &id)
+        .await
+        .expect("Should not error")
+        .expect("Instance should exist");
+
+    assert_eq!(instance.properties["name"], "Updated");
+    assert_eq!(instance.properties["count"], 20);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_update_instance_diff_reports_changed_columns() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "tracked",
+            format!("{}_tracked", prefix),
+            vec![
+                ColumnDefinition::new("name", ColumnType::String),
+                ColumnDefinition::new("price", ColumnType::decimal(10, 2)),
+            ],
+        ))
+        .await
+        .expect("Should create schema");
+
+    let id = store
+        .create_instance(
+            "tracked",
+            serde_json::json!({"name": "Widget", "price": 10}),
+        )
+        .await
+        .expect("Should create instance");
+
+    let changed = store
+        .update_instance_diff(
+            "tracked",
+            &id,
+            serde_json::json!({"name": "Gadget", "price": 10.0}),
+        )
+        .await
+        .expect("Should update instance");
+    assert_eq!(changed, vec!["name".to_string()]);
+
+    let instance = store
+        .require_instance("tracked", &id)
+        .await
+        .expect("Should read instance");
+    assert_eq!(instance.properties["name"], "Gadget");
+
+    let unchanged = store
+        .update_instance_diff("tracked", &id, serde_json::json!({"name": "Gadget"}))
+        .await
+        .expect("Should accept a no-op update");
+    assert!(unchanged.is_empty());
+
+    let result = store
+        .update_instance_diff("tracked", "missing", serde_json::json!({"name": "x"}))
+        .await;
+    assert!(matches!(result, Err(ObjectStoreError::InstanceNotFound(_))));
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_update_instance_if_match() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "versioned",
+            format!("{}_versioned", prefix),
+            vec![ColumnDefinition::new("count", ColumnType::Integer)],
+        ))
+        .await
+        .expect("Should create schema");
+
+    let id = store
+        .create_instance("versioned", serde_json::json!({"count": 1}))
+        .await
+        .expect("Should create instance");
+
+    let etag = store
+        .require_instance("versioned", &id)
+        .await
+        .expect("Instance should exist")
+        .etag();
+
+    // Matching etag: the update goes through
+    store
+        .update_instance_if_match("versioned", &id, serde_json::json!({"count": 2}), &etag)
+        .await
+        .expect("Should update with current etag");
+
+    let instance = store
+        .require_instance("versioned", &id)
+        .await
+        .expect("Instance should exist");
+    assert_eq!(instance.properties["count"], 2);
+    assert_ne!(instance.etag(), etag);
+
+    // Stale etag: rejected and the row is left alone
+    let result = store
+        .update_instance_if_match("versioned", &id, serde_json::json!({"count": 3}), &etag)
+        .await;
+    assert!(matches!(result, Err(ObjectStoreError::Conflict(_))));
+
+    let instance = store
+        .require_instance("versioned", &id)
+        .await
+        .expect("Instance should exist");
+    assert_eq!(instance.properties["count"], 2);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_create_instance_in_caller_transaction() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "ledger",
+            format!("{}_ledger", prefix),
+            vec![ColumnDefinition::new("amount", ColumnType::Integer)],
+        ))
+        .await
+        .expect("Should create schema");
+
+    let audit_table = format!("{}_audit", prefix);
+    sqlx::query(&format!("CREATE TABLE \"{}\" (note TEXT)", audit_table))
+        .execute(store.pool())
+        .await
+        .expect("Should create audit table");
+
+    let mut tx = store.pool().begin().await.expect("Should begin");
+    sqlx::query(&format!(
+        "INSERT INTO \"{}\" (note) VALUES ('x')",
+        audit_table
+    ))
+    .execute(&mut *tx)
+    .await
+    .expect("Should insert audit row");
+    let id = store
+        .create_instance_tx(&mut tx, "ledger", serde_json::json!({"amount": 5}))
+        .await
+        .expect("Should create instance in transaction");
+    tx.rollback().await.expect("Should roll back");
+
+    // Both writes were undone together
+    assert!(store.get_instance("ledger", &id).await.unwrap().is_none());
+    let (audit_rows,): (i64,) =
+        sqlx::query_as(&format!("SELECT COUNT(*) FROM \"{}\"", audit_table))
+            .fetch_one(store.pool())
+            .await
+            .expect("Should count audit rows");
+    assert_eq!(audit_rows, 0);
+
+    let _ = sqlx::query(&format!("DROP TABLE IF EXISTS \"{}\"", audit_table))
+        .execute(store.pool())
+        .await;
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_update_missing_vs_deleted_instance() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "notes",
+            format!("{}_notes", prefix),
+            vec![ColumnDefinition::new("body", ColumnType::String)],
+        ))
+        .await
+        .expect("Should create schema");
+
+    let id = store
+        .create_instance("notes", serde_json::json!({"body": "draft"}))
+        .await
+        .expect("Should create instance");
+    store
+        .delete_instance("notes", &id)
+        .await
+        .expect("Should delete instance");
+
+    let result = store
+        .update_instance("notes", &id, serde_json::json!({"body": "edited"}))
+        .await;
+    assert!(
+        matches!(result, Err(ObjectStoreError::InstanceDeleted(ref deleted)) if *deleted == id)
+    );
+
+    let result = store
+        .update_instance("notes", "missing-id", serde_json::json!({"body": "edited"}))
+        .await;
+    assert!(matches!(result, Err(ObjectStoreError::InstanceNotFound(_))));
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_delete_instance() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    // Create schema
+    let request = CreateSchemaRequest {
+        name: "temp".to_string(),
+        description: None,
+        table_name: format!("{}_temp", prefix),
+        columns: vec![ColumnDefinition::new("value", ColumnType::String)],
+        indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
+    };
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    // Create instance
+    let id = store
+        .create_instance("temp", serde_json::json!({"value": "test"}))
+        .await
+        .expect("Should create instance");
+
+    // Delete instance
+    store
+        .delete_instance("temp", &id)
+        .await
+        .expect("Should delete instance");
+
+    // Should not be found
+    let found = store
+        .get_instance("temp", &id)
+        .await
+        .expect("Should not error");
+
+    assert!(found.is_none());
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_query_instances_simple() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    // Create schema
+    let request = CreateSchemaRequest {
+        name: "products".to_string(),
+        description: None,
+        table_name: format!("{}_products", prefix),
+        columns: vec![
+            ColumnDefinition::new("name", ColumnType::String).not_null(),
+            ColumnDefinition::new("category", ColumnType::String),
+            ColumnDefinition::new("price", ColumnType::decimal(10, 2)),
+        ],
+        indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
+    };
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    // Create multiple instances
+    for i in 1..=5 {
+        store
+            .create_instance(
+                "products",
+                serde_json::json!({
+                    "name": format!("Product {}", i),
+                    "category": if i % 2 == 0 { "even" } else { "odd" },
+                    "price": i as f64 * 10.0
+                }),
+            )
+            .await
+            .expect("Should create instance");
+    }
+
+    // Query all
+    let filter = SimpleFilter::new("products".to_string());
+    let (instances, count) = store
+        .query_instances(filter)
+        .await
+        .expect("Should query instances");
+
+    assert_eq!(count, 5);
+    assert_eq!(instances.len(), 5);
+
+    // Query with limit
+    let filter = SimpleFilter::new("products".to_string()).with_limit(2);
+    let (instances, count) = store
+        .query_instances(filter)
+        .await
+        .expect("Should query instances");
+
+    assert_eq!(count, 5); // Total count still 5
+    assert_eq!(instances.len(), 2); // But only 2 returned
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_filter_instances_with_condition() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    // Create schema
+    let request = CreateSchemaRequest {
+        name: "users".to_string(),
+        description: None,
+        table_name: format!("{}_users", prefix),
+        columns: vec![
+            ColumnDefinition::new("name", ColumnType::String).not_null(),
+            ColumnDefinition::new("age", ColumnType::Integer),
+            ColumnDefinition::new("active", ColumnType::Boolean),
+        ],
+        indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
+    };
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    // Create users
+    let users = vec![
+        ("Alice", 25, true),
+        ("Bob", 30, true),
+        ("Charlie", 35, false),
+        ("Diana", 28, true),
+    ];
+
+    for (name, age, active) in users {
+        store
+            .create_instance(
+                "users",
+                serde_json::json!({
+                    "name": name,
+                    "age": age,
+                    "active": active
+                }),
+            )
+            .await
+            .expect("Should create instance");
+    }
+
+    // Filter by active = true
+    let condition = Condition {
+        op: "EQ".to_string(),
+        arguments: Some(vec![serde_json::json!("active"), serde_json::json!(true)]),
+    };
+
+    let filter = FilterRequest {
+        condition: Some(condition),
+        sort_by: None,
+        sort_order: None,
+        limit: 100,
+        offset: 0,
+        computed: Vec::new(),
+        unique_sort: false,
+    };
+
+    let (instances, count) = store
+        .filter_instances("users", filter)
+        .await
+        .expect("Should filter instances");
+
+    assert_eq!(count, 3); // Alice, Bob, Diana
+    assert_eq!(instances.len(), 3);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_replace_instances() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "rates",
+            format!("{}_rates", prefix),
+            vec![
+                ColumnDefinition::new("currency", ColumnType::String).not_null(),
+                ColumnDefinition::new("rate", ColumnType::decimal(12, 6)),
+            ],
+        ))
+        .await
+        .expect("Should create schema");
+
+    store
+        .create_instances(
+            "rates",
+            vec![
+                serde_json::json!({"currency": "EUR", "rate": 0.9}),
+                serde_json::json!({"currency": "GBP", "rate": 0.8}),
+                serde_json::json!({"currency": "JPY", "rate": 150.0}),
+            ],
+        )
+        .await
+        .expect("Should create instances");
+    let (old, _) = store
+        .filter_instances("rates", FilterRequest::default())
+        .await
+        .expect("Should filter");
+    store
+        .delete_instance("rates", &old[0].id)
+        .await
+        .expect("Should soft-delete instance");
+
+    // An invalid row leaves the existing data in place
+    let err = store
+        .replace_instances(
+            "rates",
+            vec![
+                serde_json::json!({"currency": "EUR", "rate": 0.95}),
+                serde_json::json!({"rate": 1.0}),
+            ],
+        )
+        .await
+        .expect_err("Should reject invalid row");
+    assert!(matches!(
+        err,
+        ObjectStoreError::Validation(_) | ObjectStoreError::InstanceValidation { .. }
+    ));
+    let (_, remaining) = store
+        .filter_instances("rates", FilterRequest::default())
+        .await
+        .expect("Should filter");
+    assert_eq!(remaining, 2);
+
+    let inserted = store
+        .replace_instances(
+            "rates",
+            vec![
+                serde_json::json!({"currency": "EUR", "rate": 0.95}),
+                serde_json::json!({"currency": "CHF", "rate": 0.88}),
+            ],
+        )
+        .await
+        .expect("Should replace instances");
+    assert_eq!(inserted, 2);
+
+    let (rows, total) = store
+        .filter_instances("rates", FilterRequest::default())
+        .await
+        .expect("Should filter");
+    assert_eq!(total, 2);
+    let mut currencies: Vec<&str> = rows
+        .iter()
+        .map(|i| i.properties["currency"].as_str().unwrap())
+        .collect();
+    currencies.sort();
+    assert_eq!(currencies, vec!["CHF", "EUR"]);
+
+    // Soft-deleted rows are gone too
+    let table = format!("{}_rates", prefix);
+    let (physical,): (i64,) = sqlx::query_as(&format!("SELECT COUNT(*) FROM \"{}\"", table))
+        .fetch_one(store.pool())
+        .await
+        .expect("Should count rows");
+    assert_eq!(physical, 2);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_pagination_stable_with_tied_sort_field() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "tied",
+            format!("{}_tied", prefix),
+            vec![ColumnDefinition::new("bucket", ColumnType::Integer)],
+        ))
+        .await
+        .expect("Should create schema");
+
+    // Every row shares one of two sort values
+    let rows = (0..40)
+        .map(|n| serde_json::json!({"bucket": n % 2}))
+        .collect();
+    store
+        .create_instances("tied", rows)
+        .await
+        .expect("Should create instances");
+
+    let mut seen = std::collections::HashSet::new();
+    for page in 0..8 {
+        let filter = FilterRequest {
+            sort_by: Some(vec!["bucket".to_string()]),
+            ..Default::default()
+        }
+        .with_pagination(page * 5, 5);
+        let (instances, _) = store
+            .filter_instances("tied", filter)
+            .await
+            .expect("Should filter page");
+        assert_eq!(instances.len(), 5);
+        for instance in instances {
+            assert!(seen.insert(instance.id), "Row repeated across pages");
+        }
+    }
+    assert_eq!(seen.len(), 40);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_first_page_metadata() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "tickets",
+            format!("{}_tickets", prefix),
+            vec![
+                ColumnDefinition::new("number", ColumnType::Integer),
+                ColumnDefinition::new("queue", ColumnType::String),
+            ],
+        ))
+        .await
+        .expect("Should create schema");
+
+    let rows = (0..7)
+        .map(|n| {
+            let queue = if n < 2 { "vip" } else { "general" };
+            serde_json::json!({"number": n, "queue": queue})
+        })
+        .collect();
+    store
+        .create_instances("tickets", rows)
+        .await
+        .expect("Should create instances");
+
+    // Dataset larger than the page
+    let page = store
+        .first_page("tickets", None, &[("number", "desc")], 3)
+        .await
+        .expect("Should fetch first page");
+    assert_eq!(page.total, 7);
+    assert_eq!(page.page_size, 3);
+    assert_eq!(page.page_count, 3);
+    assert!(page.has_more);
+    let numbers: Vec<i64> = page
+        .items
+        .iter()
+        .map(|i| i.properties["number"].as_i64().unwrap())
+        .collect();
+    assert_eq!(numbers, vec![6, 5, 4]);
+
+    // Dataset smaller than the page
+    let page = store
+        .first_page("tickets", Some(Condition::eq("queue", "vip")), &[], 3)
+        .await
+        .expect("Should fetch first page");
+    assert_eq!(page.total, 2);
+    assert_eq!(page.items.len(), 2);
+    assert_eq!(page.page_count, 1);
+    assert!(!page.has_more);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_query_instances_after_pages_without_gaps() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "keyset",
+            format!("{}_keyset", prefix),
+            vec![ColumnDefinition::new("number", ColumnType::Integer)],
+        ))
+        .await
+        .expect("Should create schema");
+
+    // Each batch shares one created_at, so most ordering comes from the id
+    for batch in 0..2 {
+        let rows = (batch * 500..(batch + 1) * 500)
+            .map(|n| serde_json::json!({ "number": n }))
+            .collect();
+        store
+            .create_instances("keyset", rows)
+            .await
+            .expect("Should create instances");
+    }
+
+    let page_through = |condition: Option<Condition>| {
+        let store = &store;
+        async move {
+            let mut seen = Vec::new();
+            let mut cursor: Option<Cursor> = None;
+            let mut pages = 0;
+            loop {
+                let filter = FilterRequest {
+                    condition: condition.clone(),
+                    limit: 64,
+                    ..Default::default()
+                };
+                let (instances, next) = store
+                    .query_instances_after("keyset", filter, cursor)
+                    .await
+                    .expect("Should fetch page");
+                assert!(instances.len() <= 64);
+                pages += 1;
+                seen.extend(
+                    instances
+                        .iter()
+                        .map(|i| i.properties["number"].as_i64().unwrap()),
+                );
+                match next {
+                    // Cursors survive a round trip through their string form
+                    Some(next) => cursor = Some(Cursor::decode(&next.encode()).unwrap()),
+                    None => break,
+                }
+            }
+            (seen, pages)
+        }
+    };
+
+    let (mut seen, pages) = page_through(None).await;
+    assert_eq!(pages, 16);
+    assert_eq!(seen.len(), 1000);
+    seen.sort_unstable();
+    seen.dedup();
+    assert_eq!(seen, (0..1000).collect::<Vec<i64>>());
+
+    let (mut seen, _) = page_through(Some(Condition::lt("number", 130))).await;
+    assert_eq!(seen.len(), 130);
+    seen.sort_unstable();
+    assert_eq!(seen, (0..130).collect::<Vec<i64>>());
+
+    // An exactly full last page reports no further cursor
+    let filter = FilterRequest {
+        condition: Some(Condition::lt("number", 64)),
+        limit: 64,
+        ..Default::default()
+    };
+    let (instances, next) = store
+        .query_instances_after("keyset", filter, None)
+        .await
+        .expect("Should fetch page");
+    assert_eq!(instances.len(), 64);
+    assert!(next.is_none());
+
+    let result = store
+        .query_instances_after("keyset", FilterRequest::new().with_pagination(0, 0), None)
+        .await;
+    assert!(matches!(result, Err(ObjectStoreError::Validation(_))));
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_filter_instances_zero_limit_counts_only() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "tallies",
+            format!("{}_tallies", prefix),
+            vec![ColumnDefinition::new("score", ColumnType::Integer)],
+        ))
+        .await
+        .expect("Should create schema");
+
+    for score in [10, 20, 30] {
+        store
+            .create_instance("tallies", serde_json::json!({"score": score}))
+            .await
+            .expect("Should create instance");
+    }
+
+    let filter = FilterRequest {
+        condition: Some(Condition::gt("score", 15)),
+        limit: 0,
+        ..Default::default()
+    };
+
+    let (instances, count) = store
+        .filter_instances("tallies", filter)
+        .await
+        .expect("Should count instances");
+
+    assert_eq!(count, 2);
+    assert!(instances.is_empty());
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_filter_and_sort_by_id() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "keyed",
+            format!("{}_keyed", prefix),
+            vec![ColumnDefinition::new("label", ColumnType::String)],
+        ))
+        .await
+        .expect("Should create schema");
+
+    for id in ["key-c", "key-a", "key-b", "key-d"] {
+        store
+            .create_instance_with_id("keyed", id, serde_json::json!({"label": id}))
+            .await
+            .expect("Should create instance");
+    }
+
+    let filter = FilterRequest {
+        condition: Some(Condition::id_in(["key-b", "key-c", "key-a", "missing"])),
+        sort_by: Some(vec!["id".to_string()]),
+        sort_order: Some(vec!["desc".to_string()]),
+        ..Default::default()
+    };
+
+    let (instances, count) = store
+        .filter_instances("keyed", filter)
+        .await
+        .expect("Should filter by ids");
+
+    assert_eq!(count, 3);
+    let ids: Vec<_> = instances.iter().map(|i| i.id.as_str()).collect();
+    assert_eq!(ids, vec!["key-c", "key-b", "key-a"]);
+
+    let filter = FilterRequest {
+        condition: Some(Condition::gt("id", "key-b")),
+        sort_by: Some(vec!["id".to_string()]),
+        ..Default::default()
+    };
+
+    let (instances, _) = store
+        .filter_instances("keyed", filter)
+        .await
+        .expect("Should filter by id range");
+
+    let ids: Vec<_> = instances.iter().map(|i| i.id.as_str()).collect();
+    assert_eq!(ids, vec!["key-c", "key-d"]);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_large_in_list() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "tickets",
+            format!("{}_tickets", prefix),
+            vec![
+                ColumnDefinition::new("seq", ColumnType::Integer),
+                ColumnDefinition::new("code", ColumnType::String),
+            ],
+        ))
+        .await
+        .expect("Should create schema");
+
+    let rows: Vec<_> = (0..100)
+        .map(|i| serde_json::json!({"seq": i, "code": format!("c\"{}", i)}))
+        .collect();
+    store
+        .create_instances("tickets", rows)
+        .await
+        .expect("Should create instances");
+
+    // Even seq values only, padded out to 10k entries that match nothing
+    let seqs: Vec<serde_json::Value> = (0..100)
+        .step_by(2)
+        .chain(10_000..19_950)
+        .map(serde_json::Value::from)
+        .collect();
+    assert_eq!(seqs.len(), 10_000);
+
+    let filter = FilterRequest {
+        condition: Some(Condition::r#in("seq", seqs.clone())),
+        ..Default::default()
+    };
+    let (_, count) = store
+        .filter_instances("tickets", filter)
+        .await
+        .expect("Should filter by large IN list");
+    assert_eq!(count, 50);
+
+    let filter = FilterRequest {
+        condition: Some(Condition::not_in("seq", seqs)),
+        ..Default::default()
+    };
+    let (_, count) = store
+        .filter_instances("tickets", filter)
+        .await
+        .expect("Should filter by large NOT IN list");
+    assert_eq!(count, 50);
+
+    let filter = FilterRequest {
+        condition: Some(Condition::r#in(
+            "code",
+            vec!["c\"1".into(), "c\"2".into(), "missing".into()],
+        )),
+        ..Default::default()
+    };
+    let (_, count) = store
+        .filter_instances("tickets", filter)
+        .await
+        .expect("Should filter by quoted strings");
+    assert_eq!(count, 2);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_create_instance_returning_defaults() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "tickets_returning",
+            format!("{}_tickets_returning", prefix),
+            vec![
+                ColumnDefinition::new("title", ColumnType::String).not_null(),
+                ColumnDefinition::new("status", ColumnType::String)
+                    .not_null()
+                    .default("'open'"),
+                ColumnDefinition::new("priority", ColumnType::Integer).default("3"),
+                ColumnDefinition::new("opened_at", ColumnType::timestamp()).default("NOW()"),
+            ],
+        ))
+        .await
+        .expect("Should create schema");
+
+    let before = chrono::Utc::now() - chrono::Duration::seconds(5);
+    let instance = store
+        .create_instance_returning("tickets_returning", serde_json::json!({"title": "Broken"}))
+        .await
+        .expect("Should create instance");
+
+    assert!(!instance.id.is_empty());
+    assert_eq!(instance.properties["title"], "Broken");
+    assert_eq!(instance.properties["status"], "open");
+    assert_eq!(instance.properties["priority"], 3);
+
+    // Server-side defaults are materialized in the returned row
+    let opened_at = chrono::DateTime::parse_from_rfc3339(
+        instance.properties["opened_at"]
+            .as_str()
+            .expect("opened_at should be set"),
+    )
+    .expect("opened_at should be RFC 3339");
+    assert!(opened_at > before);
+    assert!(instance.created_at_utc().is_some());
+
+    let stored = store
+        .require_instance("tickets_returning", &instance.id)
+        .await
+        .expect("Should get instance");
+    assert_eq!(stored.properties, instance.properties);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_get_field() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "jobs",
+            format!("{}_jobs", prefix),
+            vec![
+                ColumnDefinition::new("status", ColumnType::String),
+                ColumnDefinition::new("attempts", ColumnType::Integer),
+                ColumnDefinition::new("payload", ColumnType::Json),
+            ],
+        ))
+        .await
+        .expect("Should create schema");
+
+    let id = store
+        .create_instance(
+            "jobs",
+            serde_json::json!({"status": "running", "payload": {"large": "blob"}}),
+        )
+        .await
+        .expect("Should create instance");
+
+    let status = store
+        .get_field("jobs", &id, "status")
+        .await
+        .expect("Should get field");
+    assert_eq!(status, Some(serde_json::json!("running")));
+
+    // An existing instance with a NULL value is distinguishable from a missing one
+    let attempts = store
+        .get_field("jobs", &id, "attempts")
+        .await
+        .expect("Should get field");
+    assert_eq!(attempts, Some(serde_json::Value::Null));
+    let missing = store
+        .get_field("jobs", "missing-id", "status")
+        .await
+        .expect("Should get field");
+    assert!(missing.is_none());
+
+    let err = store
+        .get_field("jobs", &id, "unknown")
+        .await
+        .expect_err("Should reject unknown column");
+    assert!(err.to_string().contains("unknown"));
+
+    // Soft-deleted instances are not visible
+    store
+        .delete_instance("jobs", &id)
+        .await
+        .expect("Should delete instance");
+    let deleted = store
+        .get_field("jobs", &id, "status")
+        .await
+        .expect("Should get field");
+    assert!(deleted.is_none());
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_filter_instances_computed_field() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "priced",
+            format!("{}_priced", prefix),
+            vec![
+                ColumnDefinition::new("name", ColumnType::String),
+                ColumnDefinition::new("price", ColumnType::decimal(10, 2)),
+                ColumnDefinition::new("quantity", ColumnType::Integer),
+            ],
+        ))
+        .await
+        .expect("Should create schema");
+
+    store
+        .create_instance(
+            "priced",
+            serde_json::json!({"name": "Widget", "price": 10.0, "quantity": 3}),
+        )
+        .await
+        .expect("Should create instance");
+
+    let filter = FilterRequest::default()
+        .with_computed("gross", "price * 1.2")
+        .with_computed("total", "price * quantity");
+    let (instances, _) = store
+        .filter_instances("priced", filter)
+        .await
+        .expect("Should filter with computed fields");
+
+    assert_eq!(instances.len(), 1);
+    let props = &instances[0].properties;
+    let gross = props["gross"].as_f64().expect("gross should be numeric");
+    assert!((gross - 12.0).abs() < 1e-9);
+    let total = props["total"].as_f64().expect("total should be numeric");
+    assert!((total - 30.0).abs() < 1e-9);
+
+    // Expressions are validated before anything reaches the database
+    let err = store
+        .filter_instances(
+            "priced",
+            FilterRequest::default().with_computed("bad", "price; DROP TABLE x"),
+        )
+        .await
+        .expect_err("Should reject unsafe expression");
+    assert!(matches!(err, ObjectStoreError::Validation(_)));
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_instance_exists() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    // Create schema
+    let request = CreateSchemaRequest {
+        name: "flags".to_string(),
+        description: None,
+        table_name: format!("{}_flags", prefix),
+        columns: vec![
+            ColumnDefinition::new("key", ColumnType::String)
+                .unique()
+                .not_null(),
+            ColumnDefinition::new("enabled", ColumnType::Boolean),
+        ],
+        indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
+    };
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    store
+        .create_instance(
+            "flags",
+            serde_json::json!({
+                "key": "feature_x",
+                "enabled": true
+            }),
+        )
+        .await
+        .expect("Should create instance");
+
+    // Check exists
+    let filter = SimpleFilter::new("flags".to_string());
+    let exists = store
+        .instance_exists(filter)
+        .await
+        .expect("Should check existence");
+
+    assert!(exists.is_some());
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_join_query_includes_related_columns() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let customers_table = format!("{}_customers", prefix);
+    store
+        .create_schema(CreateSchemaRequest {
+            name: "customers".to_string(),
+            description: None,
+            table_name: customers_table.clone(),
+            columns: vec![ColumnDefinition::new("name", ColumnType::String).not_null()],
+            indexes: None,
+            default_index_predicate: None,
+            create_default_index: None,
+            storage_params: None,
+            tablespace: None,
+        })
+        .await
+        .expect("Should create customers schema");
+
+    store
+        .create_schema(CreateSchemaRequest {
+            name: "orders".to_string(),
+            description: None,
+            table_name: format!("{}_orders", prefix),
+            columns: vec![
+                ColumnDefinition::new("customer_id", ColumnType::String)
+                    .not_null()
+                    .references(&customers_table),
+                ColumnDefinition::new("total", ColumnType::Integer),
+            ],
+            indexes: None,
+            default_index_predicate: None,
+            create_default_index: None,
+            storage_params: None,
+            tablespace: None,
+        })
+        .await
+        .expect("Should create orders schema");
+
+    let alice = store
+        .create_instance("customers", serde_json::json!({"name": "Alice"}))
+        .await
+        .expect("Should create customer");
+    let bob = store
+        .create_instance("customers", serde_json::json!({"name": "Bob"}))
+        .await
+        .expect("Should create customer");
+
+    for (customer, total) in [(&alice, 10), (&bob, 20), (&alice, 30)] {
+        store
+            .create_instance(
+                "orders",
+                serde_json::json!({"customer_id": customer, "total": total}),
+            )
+            .await
+            .expect("Should create order");
+    }
+
+    let results = store
+        .join_query("orders", "customer_id", "customers", &["name".to_string()])
+        .await
+        .expect("Should join");
+
+    assert_eq!(results.len(), 3);
+    for order in &results {
+        let expected = if order.properties["customer_id"] == alice.as_str() {
+            "Alice"
+        } else {
+            "Bob"
+        };
+        assert_eq!(order.properties["customers"]["name"], expected);
+    }
+
+    // A column without a matching foreign key is rejected
+    let result = store
+        .join_query("orders", "total", "customers", &["name".to_string()])
+        .await;
+    assert!(result.is_err());
+
+    // A base column named after the related schema would be overwritten by the
+    // nested object, so the join is rejected instead
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "invoices",
+            format!("{}_invoices", prefix),
+            vec![
+                ColumnDefinition::new("customers", ColumnType::String).references(&customers_table),
+            ],
+        ))
+        .await
+        .expect("Should create invoices schema");
+    store
+        .create_instance("invoices", serde_json::json!({"customers": alice}))
+        .await
+        .expect("Should create invoice");
+    let result = store
+        .join_query("invoices", "customers", "customers", &["name".to_string()])
+        .await;
+    assert!(matches!(result, Err(ObjectStoreError::Validation(_))));
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_delete_instance_cascade() {
+    let Some(db_url) = get_database_url() else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let prefix = test_prefix();
+    let metadata_table = format!("{}__schema", prefix);
+    let config = StoreConfig::builder(&db_url)
+        .metadata_table(&metadata_table)
+        .soft_delete(false)
+        .build();
+    let store = ObjectStore::new(config).await.expect("Should create store");
+
+    let customers_table = format!("{}_customers", prefix);
+    let orders_table = format!("{}_orders", prefix);
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "customers",
+            &customers_table,
+            vec![ColumnDefinition::new("name", ColumnType::String).not_null()],
+        ))
+        .await
+        .expect("Should create customers schema");
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "orders",
+            &orders_table,
+            vec![
+                ColumnDefinition::new("customer_id", ColumnType::String)
+                    .references(&customers_table),
+            ],
+        ))
+        .await
+        .expect("Should create orders schema");
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "order_lines",
+            format!("{}_order_lines", prefix),
+            vec![ColumnDefinition::new("order_id", ColumnType::String).references(&orders_table)],
+        ))
+        .await
+        .expect("Should create order lines schema");
+
+    let alice = store
+        .create_instance("customers", serde_json::json!({"name": "Alice"}))
+        .await
+        .expect("Should create customer");
+    let bob = store
+        .create_instance("customers", serde_json::json!({"name": "Bob"}))
+        .await
+        .expect("Should create customer");
+
+    for customer in [&alice, &alice, &bob] {
+        let order = store
+            .create_instance("orders", serde_json::json!({"customer_id": customer}))
+            .await
+            .expect("Should create order");
+        for _ in 0..2 {
+            store
+                .create_instance("order_lines", serde_json::json!({"order_id": order}))
+                .await
+                .expect("Should create order line");
+        }
+    }
+
+    let affected = store
+        .delete_instance_cascade("customers", &alice)
+        .await
+        .expect("Should cascade delete");
+    assert_eq!(affected.get("customers"), Some(&1));
+    assert_eq!(affected.get("orders"), Some(&2));
+    assert_eq!(affected.get("order_lines"), Some(&4));
+
+    // Bob's rows are untouched
+    assert!(
+        store
+            .get_instance("customers", &bob)
+            .await
+            .unwrap()
+            .is_some()
+    );
+    let (orders, _) = store
+        .filter_instances("orders", FilterRequest::default())
+        .await
+        .expect("Should filter orders");
+    assert_eq!(orders.len(), 1);
+    let (lines, _) = store
+        .filter_instances("order_lines", FilterRequest::default())
+        .await
+        .expect("Should filter order lines");
+    assert_eq!(lines.len(), 2);
+
+    let err = store
+        .delete_instance_cascade("customers", &alice)
+        .await
+        .expect_err("Should report missing instance");
+    assert!(matches!(err, ObjectStoreError::InstanceNotFound(_)));
+
+    cleanup_test(&store, &prefix).await;
+}
+
+// ==================== Validation Tests ====================
+
+#[tokio::test]
+async fn test_type_validation() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    // Create schema with strict types
+    let request = CreateSchemaRequest {
+        name: "typed".to_string(),
+        description: None,
+        table_name: format!("{}_typed", prefix),
+        columns: vec![
+            ColumnDefinition::new("count", ColumnType::Integer).not_null(),
+            ColumnDefinition::new("price", ColumnType::decimal(10, 2)),
+        ],
+        indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
+    };
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    // Valid types
+    let result = store
+        .create_instance(
+            "typed",
+            serde_json::json!({
+                "count": 42,
+                "price": 19.99
+            }),
+        )
+        .await;
+
+    assert!(result.is_ok());
+
+    // Invalid types - string for integer (should fail validation)
+    let result = store
+        .create_instance(
+            "typed",
+            serde_json::json!({
+                "count": "not a number",
+                "price": 9.99
+            }),
+        )
+        .await;
+
+    assert!(result.is_err());
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_required_column_validation() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    // Create schema with required column
+    let request = CreateSchemaRequest {
+        name: "required".to_string(),
+        description: None,
+        table_name: format!("{}_required", prefix),
+        columns: vec![
+            ColumnDefinition::new("name", ColumnType::String).not_null(),
+            ColumnDefinition::new("optional", ColumnType::String),
+        ],
+        indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
+    };
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    // Missing required column
+    let result = store
+        .create_instance(
+            "required",
+            serde_json::json!({
+                "optional": "value"
+            }),
+        )
+        .await;
+
+    assert!(result.is_err());
+
+    cleanup_test(&store, &prefix).await;
+}
+
+// ==================== Configuration Tests ====================
+
+#[tokio::test]
+async fn test_store_without_soft_delete() {
+    let Some(db_url) = get_database_url() else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let prefix = test_prefix();
+    let metadata_table = format!("{}__schema", prefix);
+
+    let config = StoreConfig::builder(&db_url)
+        .metadata_table(&metadata_table)
         .soft_delete(false) // Hard delete
         .build();
 
     let store = ObjectStore::new(config).await.expect("Should create store");
 
-    // Create and delete a schema
+    // Create and delete a schema
+    let request = CreateSchemaRequest {
+        name: "hard_delete_test".to_string(),
+        description: None,
+        table_name: format!("{}_hard", prefix),
+        columns: vec![ColumnDefinition::new("x", ColumnType::String)],
+        indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
+    };
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    // Delete (hard delete)
+    store
+        .delete_schema("hard_delete_test")
+        .await
+        .expect("Should hard delete");
+
+    // Table should be dropped - verify by trying to query the metadata directly
+    let count: (i64,) = sqlx::query_as(&format!(
+        "SELECT COUNT(*) FROM \"{}__schema\" WHERE name = 'hard_delete_test'",
+        prefix
+    ))
+    .fetch_one(store.pool())
+    .await
+    .expect("Should query");
+
+    assert_eq!(count.0, 0); // Row should be gone, not just soft-deleted
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_delete_schema_cascade_to_instances() {
+    let Some(db_url) = get_database_url() else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let prefix = test_prefix();
+    let metadata_table = format!("{}__schema", prefix);
+
+    let store = ObjectStore::new(
+        StoreConfig::builder(&db_url)
+            .metadata_table(&metadata_table)
+            .build(),
+    )
+    .await
+    .expect("Should create store");
+    let cascading_store = ObjectStore::new(
+        StoreConfig::builder(&db_url)
+            .metadata_table(&metadata_table)
+            .cascade_schema_delete(true)
+            .build(),
+    )
+    .await
+    .expect("Should create cascading store");
+
+    let kept_table = format!("{}_kept", prefix);
+    let cascaded_table = format!("{}_cascaded", prefix);
+    for (name, table) in [("kept", &kept_table), ("cascaded", &cascaded_table)] {
+        store
+            .create_schema(CreateSchemaRequest::new(
+                name,
+                table,
+                vec![ColumnDefinition::new("x", ColumnType::String)],
+            ))
+            .await
+            .expect("Should create schema");
+        store
+            .create_instance(name, serde_json::json!({"x": "row"}))
+            .await
+            .expect("Should create instance");
+    }
+
+    store
+        .delete_schema("kept")
+        .await
+        .expect("Should delete schema");
+    cascading_store
+        .delete_schema("cascaded")
+        .await
+        .expect("Should delete schema");
+
+    // Instances of a deleted schema are unreachable through the store either way
+    for name in ["kept", "cascaded"] {
+        let result = store.filter_instances(name, FilterRequest::default()).await;
+        assert!(matches!(result, Err(ObjectStoreError::SchemaDeleted(_))));
+    }
+
+    // Rows are only soft-deleted when the store cascades schema deletes
+    for (table, expected) in [(&kept_table, 1), (&cascaded_table, 0)] {
+        let (count,): (i64,) = sqlx::query_as(&format!(
+            "SELECT COUNT(*) FROM \"{}\" WHERE deleted = FALSE",
+            table
+        ))
+        .fetch_one(store.pool())
+        .await
+        .expect("Should count rows");
+        assert_eq!(count, expected);
+    }
+
+    // Deleted schemas are not listed, so drop their tables here
+    for table in [&kept_table, &cascaded_table] {
+        let drop_table = format!("DROP TABLE IF EXISTS \"{}\" CASCADE", table);
+        let _ = sqlx::query(&drop_table).execute(store.pool()).await;
+    }
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_metadata_soft_delete_independent_of_instances() {
+    let Some(db_url) = get_database_url() else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    for (instance_soft, schema_soft) in [(true, true), (true, false), (false, true), (false, false)]
+    {
+        let prefix = test_prefix();
+        let metadata_table = format!("{}__schema", prefix);
+        let table = format!("{}_items", prefix);
+
+        let store = ObjectStore::new(
+            StoreConfig::builder(&db_url)
+                .metadata_table(&metadata_table)
+                .soft_delete(instance_soft)
+                .metadata_soft_delete(schema_soft)
+                .build(),
+        )
+        .await
+        .expect("Should create store");
+
+        store
+            .create_schema(CreateSchemaRequest::new(
+                "items",
+                &table,
+                vec![ColumnDefinition::new("x", ColumnType::String)],
+            ))
+            .await
+            .expect("Should create schema");
+        let id = store
+            .create_instance("items", serde_json::json!({"x": "row"}))
+            .await
+            .expect("Should create instance");
+        store
+            .delete_instance("items", &id)
+            .await
+            .expect("Should delete instance");
+
+        // Instance deletes follow soft_delete only
+        let (rows,): (i64,) = sqlx::query_as(&format!("SELECT COUNT(*) FROM \"{}\"", table))
+            .fetch_one(store.pool())
+            .await
+            .expect("Should count rows");
+        assert_eq!(rows, i64::from(instance_soft));
+
+        store
+            .delete_schema("items")
+            .await
+            .expect("Should delete schema");
+
+        // Schema deletes follow metadata_soft_delete only
+        let (metadata_rows,): (i64,) =
+            sqlx::query_as(&format!("SELECT COUNT(*) FROM \"{}\"", metadata_table))
+                .fetch_one(store.pool())
+                .await
+                .expect("Should count metadata rows");
+        assert_eq!(metadata_rows, i64::from(schema_soft));
+
+        let (table_exists,): (bool,) = sqlx::query_as("SELECT to_regclass($1) IS NOT NULL")
+            .bind(&table)
+            .fetch_one(store.pool())
+            .await
+            .expect("Should check table");
+        assert_eq!(table_exists, schema_soft);
+
+        let result = store
+            .get_schema("items")
+            .await
+            .expect("Should query schema");
+        assert!(result.is_none());
+
+        let drop_table = format!("DROP TABLE IF EXISTS \"{}\" CASCADE", table);
+        let _ = sqlx::query(&drop_table).execute(store.pool()).await;
+        cleanup_test(&store, &prefix).await;
+    }
+}
+
+#[tokio::test]
+async fn test_unique_as_index_toggle() {
+    let Some(db_url) = get_database_url() else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let prefix = test_prefix();
+    let metadata_table = format!("{}__schema", prefix);
+    let store = ObjectStore::new(
+        StoreConfig::builder(&db_url)
+            .metadata_table(&metadata_table)
+            .unique_as_index(true)
+            .build(),
+    )
+    .await
+    .expect("Should create store");
+
+    let table = format!("{}_skus", prefix);
+    let index_name = format!("uq_{}_sku", table);
+    let index_exists = |name: String| {
+        let pool = store.pool().clone();
+        async move {
+            let (exists,): (bool,) =
+                sqlx::query_as("SELECT EXISTS(SELECT 1 FROM pg_indexes WHERE indexname = $1)")
+                    .bind(name)
+                    .fetch_one(&pool)
+                    .await
+                    .expect("Should query pg_indexes");
+            exists
+        }
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "skus",
+            &table,
+            vec![ColumnDefinition::new("sku", ColumnType::String)],
+        ))
+        .await
+        .expect("Should create schema");
+    assert!(!index_exists(index_name.clone()).await);
+
+    // Turning unique on creates the named index
+    store
+        .update_schema(
+            "skus",
+            UpdateSchemaRequest::new().with_columns(vec![
+                ColumnDefinition::new("sku", ColumnType::String).unique(),
+            ]),
+        )
+        .await
+        .expect("Should enable unique");
+    assert!(index_exists(index_name.clone()).await);
+
+    store
+        .create_instance("skus", serde_json::json!({"sku": "A"}))
+        .await
+        .expect("Should create instance");
+    let duplicate = store
+        .create_instance("skus", serde_json::json!({"sku": "A"}))
+        .await;
+    assert!(duplicate.is_err());
+
+    // Turning it off drops the index by name
+    store
+        .update_schema(
+            "skus",
+            UpdateSchemaRequest::new()
+                .with_columns(vec![ColumnDefinition::new("sku", ColumnType::String)]),
+        )
+        .await
+        .expect("Should disable unique");
+    assert!(!index_exists(index_name.clone()).await);
+
+    store
+        .create_instance("skus", serde_json::json!({"sku": "A"}))
+        .await
+        .expect("Duplicates are allowed again");
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_sql_error_includes_constraint_detail() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let table_name = format!("{}_members", prefix);
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "members",
+            &table_name,
+            vec![ColumnDefinition::new("email", ColumnType::String).unique()],
+        ))
+        .await
+        .expect("Should create schema");
+
+    store
+        .create_instance("members", serde_json::json!({"email": "ada@example.com"}))
+        .await
+        .expect("Should create instance");
+    let err = store
+        .create_instance("members", serde_json::json!({"email": "ada@example.com"}))
+        .await
+        .expect_err("Duplicate should violate the unique constraint");
+
+    let constraint = format!("uq_{}_email", table_name);
+    assert_eq!(err.constraint(), Some(constraint.as_str()));
+
+    let message = err.to_string();
+    assert!(message.contains(&format!("constraint: {}", constraint)));
+    assert!(message.contains("detail: Key (email)=(redacted) already exists."));
+    assert!(!message.contains("ada@example.com"));
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_unknown_enum_values_on_read() {
+    let Some(db_url) = get_database_url() else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let prefix = test_prefix();
+    let metadata_table = format!("{}__schema", prefix);
+    let open_store = |mode: UnknownEnumValues| {
+        ObjectStore::new(
+            StoreConfig::builder(&db_url)
+                .metadata_table(&metadata_table)
+                .unknown_enum_values(mode)
+                .build(),
+        )
+    };
+    let store = open_store(UnknownEnumValues::PassThrough)
+        .await
+        .expect("Should create store");
+
+    let table = format!("{}_tasks", prefix);
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "tasks",
+            &table,
+            vec![ColumnDefinition::new(
+                "priority",
+                ColumnType::Enum {
+                    values: vec!["low".to_string(), "high".to_string()],
+                },
+            )],
+        ))
+        .await
+        .expect("Should create schema");
+
+    // A row left over from when "urgent" was still an allowed value
+    sqlx::query(&format!(
+        "ALTER TABLE \"{0}\" DROP CONSTRAINT \"ck_{0}_priority\"",
+        table
+    ))
+    .execute(store.pool())
+    .await
+    .expect("Should drop enum check");
+    let (id,): (String,) = sqlx::query_as(&format!(
+        "INSERT INTO \"{}\" (priority) VALUES ('urgent') RETURNING id",
+        table
+    ))
+    .fetch_one(store.pool())
+    .await
+    .expect("Should insert stale enum value");
+
+    let instance = store
+        .require_instance("tasks", &id)
+        .await
+        .expect("Pass-through should read the row");
+    assert_eq!(instance.properties["priority"], "urgent");
+
+    let nulling = open_store(UnknownEnumValues::Null)
+        .await
+        .expect("Should create store");
+    let instance = nulling
+        .require_instance("tasks", &id)
+        .await
+        .expect("Null mode should read the row");
+    assert!(instance.properties.get("priority").is_none());
+
+    let strict = open_store(UnknownEnumValues::Error)
+        .await
+        .expect("Should create store");
+    let err = strict
+        .get_instance("tasks", &id)
+        .await
+        .expect_err("Error mode should reject the row");
+    assert!(err.to_string().contains("urgent"));
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_reopen_metadata_table_with_soft_delete() {
+    let Some(db_url) = get_database_url() else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let prefix = test_prefix();
+    let metadata_table = format!("{}__schema", prefix);
+
+    // First open without soft delete: the metadata table has no `deleted` column
+    let config = StoreConfig::builder(&db_url)
+        .metadata_table(&metadata_table)
+        .soft_delete(false)
+        .build();
+    let store = ObjectStore::new(config).await.expect("Should create store");
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "legacy",
+            format!("{}_legacy", prefix),
+            vec![ColumnDefinition::new("x", ColumnType::String)],
+        ))
+        .await
+        .expect("Should create schema");
+
+    // Re-opening with soft delete migrates the table instead of failing later
+    let config = StoreConfig::builder(&db_url)
+        .metadata_table(&metadata_table)
+        .soft_delete(true)
+        .build();
+    let store = ObjectStore::new(config)
+        .await
+        .expect("Should re-open store with soft delete");
+
+    let schemas = store.list_schemas().await.expect("Should list schemas");
+    assert_eq!(schemas.len(), 1);
+    assert_eq!(schemas[0].name, "legacy");
+
+    // Incompatible tables are reported clearly
+    let broken_table = format!("{}_broken_meta", prefix);
+    sqlx::query(&format!(
+        "CREATE TABLE \"{}\" (id TEXT PRIMARY KEY)",
+        broken_table
+    ))
+    .execute(store.pool())
+    .await
+    .expect("Should create broken table");
+    let config = StoreConfig::builder(&db_url)
+        .metadata_table(&broken_table)
+        .build();
+    match ObjectStore::new(config).await {
+        Err(ObjectStoreError::Database(msg)) => assert!(msg.contains("missing columns")),
+        Err(other) => panic!("Expected Database error, got {:?}", other),
+        Ok(_) => panic!("Expected incompatible metadata table to be rejected"),
+    }
+    let _ = sqlx::query(&format!("DROP TABLE IF EXISTS \"{}\"", broken_table))
+        .execute(store.pool())
+        .await;
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_custom_metadata_table() {
+    let Some(db_url) = get_database_url() else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let prefix = test_prefix();
+    let custom_metadata = format!("{}_custom_meta", prefix);
+
+    let config = StoreConfig::builder(&db_url)
+        .metadata_table(&custom_metadata)
+        .build();
+
+    let store = ObjectStore::new(config).await.expect("Should create store");
+
+    // Verify the custom metadata table exists
+    let exists: (bool,) = sqlx::query_as(&format!(
+        "SELECT EXISTS (SELECT 1 FROM information_schema.tables WHERE table_name = '{}')",
+        custom_metadata
+    ))
+    .fetch_one(store.pool())
+    .await
+    .expect("Should query");
+
+    assert!(exists.0);
+
+    // Clean up
+    let _ = sqlx::query(&format!(
+        "DROP TABLE IF EXISTS \"{}\" CASCADE",
+        custom_metadata
+    ))
+    .execute(store.pool())
+    .await;
+}
+
+// ==================== Column Type Tests ====================
+
+#[tokio::test]
+async fn test_all_column_types() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    // Create schema with all column types
+    let request = CreateSchemaRequest {
+        name: "all_types".to_string(),
+        description: None,
+        table_name: format!("{}_all_types", prefix),
+        columns: vec![
+            ColumnDefinition::new("string_col", ColumnType::String),
+            ColumnDefinition::new("int_col", ColumnType::Integer),
+            ColumnDefinition::new("float_col", ColumnType::decimal(10, 2)),
+            ColumnDefinition::new("bool_col", ColumnType::Boolean),
+            ColumnDefinition::new("json_col", ColumnType::Json),
+            ColumnDefinition::new("decimal_col", ColumnType::decimal(10, 2)),
+            ColumnDefinition::new("timestamp_col", ColumnType::timestamp()),
+        ],
+        indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
+    };
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    // Create instance with all types
+    let id = store
+        .create_instance(
+            "all_types",
+            serde_json::json!({
+                "string_col": "hello",
+                "int_col": 42,
+                "float_col": 9.87,
+                "bool_col": true,
+                "json_col": {"nested": "value", "arr": [1, 2, 3]},
+                "decimal_col": 123.45,
+                "timestamp_col": "2024-01-15T10:30:00Z"
+            }),
+        )
+        .await
+        .expect("Should create instance");
+
+    // Retrieve and verify
+    let instance = store
+        .get_instance("all_types", &id)
+        .await
+        .expect("Should not error")
+        .expect("Instance should exist");
+
+    assert_eq!(instance.properties["string_col"], "hello");
+    assert_eq!(instance.properties["int_col"], 42);
+    assert!((instance.properties["float_col"].as_f64().unwrap() - 9.87).abs() < 0.01);
+    assert_eq!(instance.properties["bool_col"], true);
+    assert_eq!(instance.properties["json_col"]["nested"], "value");
+    assert!((instance.properties["decimal_col"].as_f64().unwrap() - 123.45).abs() < 0.01);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_between_timestamp_range() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let request = CreateSchemaRequest::new(
+        "events",
+        format!("{}_events", prefix),
+        vec![
+            ColumnDefinition::new("label", ColumnType::String),
+            ColumnDefinition::new("happened_at", ColumnType::timestamp()),
+        ],
+    );
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    store
+        .create_instances(
+            "events",
+            vec![
+                serde_json::json!({"label": "before", "happened_at": "2023-12-31T23:59:59Z"}),
+                serde_json::json!({"label": "lower", "happened_at": "2024-01-01T00:00:00Z"}),
+                serde_json::json!({"label": "inside", "happened_at": "2024-01-15T12:00:00Z"}),
+                serde_json::json!({"label": "upper", "happened_at": "2024-02-01T00:00:00Z"}),
+                serde_json::json!({"label": "after", "happened_at": "2024-02-01T00:00:01Z"}),
+            ],
+        )
+        .await
+        .expect("Should create instances");
+
+    let filter = FilterRequest {
+        condition: Some(Condition::between(
+            "happened_at",
+            "2024-01-01T00:00:00Z",
+            "2024-02-01T00:00:00Z",
+        )),
+        ..Default::default()
+    };
+
+    let (results, total) = store
+        .filter_instances("events", filter)
+        .await
+        .expect("Should filter by range");
+
+    // Bounds are inclusive and compared as timestamps, not text
+    assert_eq!(total, 3);
+    let mut labels: Vec<&str> = results
+        .iter()
+        .map(|i| i.properties["label"].as_str().unwrap())
+        .collect();
+    labels.sort();
+    assert_eq!(labels, vec!["inside", "lower", "upper"]);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_naive_timestamp_round_trip() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let table_name = format!("{}_legacy_events", prefix);
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "legacy_events",
+            &table_name,
+            vec![ColumnDefinition::new(
+                "logged_at",
+                ColumnType::naive_timestamp(),
+            )],
+        ))
+        .await
+        .expect("Should create schema");
+
+    let data_type: String = sqlx::query_scalar(
+        "SELECT data_type FROM information_schema.columns \
+         WHERE table_name = $1 AND column_name = 'logged_at'",
+    )
+    .bind(&table_name)
+    .fetch_one(store.pool())
+    .await
+    .expect("Should read column type");
+    assert_eq!(data_type, "timestamp without time zone");
+
+    let id = store
+        .create_instance(
+            "legacy_events",
+            serde_json::json!({"logged_at": "2024-03-10T08:15:30.5"}),
+        )
+        .await
+        .expect("Should create instance");
+
+    let instance = store
+        .get_instance("legacy_events", &id)
+        .await
+        .expect("Should get instance")
+        .expect("Instance should exist");
+    assert_eq!(
+        instance.properties["logged_at"],
+        serde_json::json!("2024-03-10T08:15:30.500")
+    );
+
+    let (matches, _) = store
+        .filter_instances(
+            "legacy_events",
+            FilterRequest::new().with_condition(Condition::gt("logged_at", "2024-03-10T08:00:00")),
+        )
+        .await
+        .expect("Should filter by naive timestamp");
+    assert_eq!(matches.len(), 1);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_json_eq_nested_key() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "profiles",
+            format!("{}_profiles", prefix),
+            vec![
+                ColumnDefinition::new("label", ColumnType::String),
+                ColumnDefinition::new("metadata", ColumnType::Json),
+            ],
+        ))
+        .await
+        .expect("Should create schema");
+
+    store
+        .create_instances(
+            "profiles",
+            vec![
+                serde_json::json!({"label": "berlin", "metadata": {"address": {"city": "Berlin", "zip": 10115}}}),
+                serde_json::json!({"label": "paris", "metadata": {"address": {"city": "Paris", "zip": 75001}}}),
+                serde_json::json!({"label": "none", "metadata": {"tags": ["x"]}}),
+            ],
+        )
+        .await
+        .expect("Should create instances");
+
+    let query = |path: serde_json::Value, value: serde_json::Value| FilterRequest {
+        condition: Some(Condition::new(
+            "JSON_EQ",
+            vec![serde_json::json!("metadata"), path, value],
+        )),
+        ..Default::default()
+    };
+
+    let (results, total) = store
+        .filter_instances(
+            "profiles",
+            query(
+                serde_json::json!("address.city"),
+                serde_json::json!("Berlin"),
+            ),
+        )
+        .await
+        .expect("Should filter by nested key");
+    assert_eq!(total, 1);
+    assert_eq!(results[0].properties["label"], "berlin");
+
+    // Numbers are matched by their text form
+    let (results, _) = store
+        .filter_instances(
+            "profiles",
+            query(
+                serde_json::json!(["address", "zip"]),
+                serde_json::json!(75001),
+            ),
+        )
+        .await
+        .expect("Should filter by nested number");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].properties["label"], "paris");
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[cfg(feature = "postgis")]
+#[tokio::test]
+async fn test_point_column_within_radius() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    if sqlx::query("CREATE EXTENSION IF NOT EXISTS postgis")
+        .execute(store.pool())
+        .await
+        .is_err()
+    {
+        eprintln!("Skipping test: PostGIS is not available");
+        cleanup_test(&store, &prefix).await;
+        return;
+    }
+
+    let request = CreateSchemaRequest::new(
+        "places",
+        format!("{}_places", prefix),
+        vec![
+            ColumnDefinition::new("name", ColumnType::String),
+            ColumnDefinition::new("location", ColumnType::Point),
+        ],
+    );
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    store
+        .create_instances(
+            "places",
+            vec![
+                // Brandenburg Gate and Berlin TV Tower (~2 km apart)
+                serde_json::json!({"name": "gate", "location": {"lat": 52.5163, "lng": 13.3777}}),
+                serde_json::json!({
+                    "name": "tower",
+                    "location": {"type": "Point", "coordinates": [13.4094, 52.5208]}
+                }),
+                // Munich, far away
+                serde_json::json!({"name": "munich", "location": {"lat": 48.1374, "lng": 11.5755}}),
+            ],
+        )
+        .await
+        .expect("Should create instances");
+
+    let filter = FilterRequest {
+        condition: Some(Condition {
+            op: "WITHIN_RADIUS".to_string(),
+            arguments: Some(vec![
+                serde_json::json!("location"),
+                serde_json::json!({"lat": 52.5163, "lng": 13.3777}),
+                serde_json::json!(5000),
+            ]),
+        }),
+        ..Default::default()
+    };
+
+    let (results, total) = store
+        .filter_instances("places", filter)
+        .await
+        .expect("Should filter by radius");
+
+    assert_eq!(total, 2);
+    let mut names: Vec<&str> = results
+        .iter()
+        .map(|i| i.properties["name"].as_str().unwrap())
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["gate", "tower"]);
+
+    // Points are read back as GeoJSON
+    let gate = results
+        .iter()
+        .find(|i| i.properties["name"] == "gate")
+        .unwrap();
+    assert_eq!(gate.properties["location"]["type"], "Point");
+
+    cleanup_test(&store, &prefix).await;
+}
+
+// ==================== Sorting Tests ====================
+
+#[tokio::test]
+async fn test_sorting() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    // Create schema
+    let request = CreateSchemaRequest {
+        name: "sortable".to_string(),
+        description: None,
+        table_name: format!("{}_sortable", prefix),
+        columns: vec![
+            ColumnDefinition::new("name", ColumnType::String).not_null(),
+            ColumnDefinition::new("rank", ColumnType::Integer),
+        ],
+        indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
+    };
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    // Create instances
+    for (name, rank) in [("Charlie", 3), ("Alice", 1), ("Bob", 2)] {
+        store
+            .create_instance(
+                "sortable",
+                serde_json::json!({
+                    "name": name,
+                    "rank": rank
+                }),
+            )
+            .await
+            .expect("Should create instance");
+    }
+
+    // Sort by name ascending
+    let filter = FilterRequest {
+        condition: None,
+        sort_by: Some(vec!["name".to_string()]),
+        sort_order: Some(vec!["asc".to_string()]),
+        limit: 100,
+        offset: 0,
+        computed: Vec::new(),
+        unique_sort: false,
+    };
+
+    let (instances, _) = store
+        .filter_instances("sortable", filter)
+        .await
+        .expect("Should filter");
+
+    assert_eq!(instances[0].properties["name"], "Alice");
+    assert_eq!(instances[1].properties["name"], "Bob");
+    assert_eq!(instances[2].properties["name"], "Charlie");
+
+    // Sort by rank descending
+    let filter = FilterRequest {
+        condition: None,
+        sort_by: Some(vec!["rank".to_string()]),
+        sort_order: Some(vec!["desc".to_string()]),
+        limit: 100,
+        offset: 0,
+        computed: Vec::new(),
+        unique_sort: false,
+    };
+
+    let (instances, _) = store
+        .filter_instances("sortable", filter)
+        .await
+        .expect("Should filter");
+
+    assert_eq!(instances[0].properties["rank"], 3);
+    assert_eq!(instances[1].properties["rank"], 2);
+    assert_eq!(instances[2].properties["rank"], 1);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+// ==================== Pagination Tests ====================
+
+#[tokio::test]
+async fn test_pagination() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    // Create schema
+    let request = CreateSchemaRequest {
+        name: "paginated".to_string(),
+        description: None,
+        table_name: format!("{}_paginated", prefix),
+        columns: vec![ColumnDefinition::new("index", ColumnType::Integer).not_null()],
+        indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
+    };
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    // Create 10 instances
+    for i in 1..=10 {
+        store
+            .create_instance("paginated", serde_json::json!({"index": i}))
+            .await
+            .expect("Should create instance");
+    }
+
+    // Page 1 (offset 0, limit 3)
+    let filter = FilterRequest {
+        condition: None,
+        sort_by: Some(vec!["index".to_string()]),
+        sort_order: Some(vec!["asc".to_string()]),
+        limit: 3,
+        offset: 0,
+        computed: Vec::new(),
+        unique_sort: false,
+    };
+
+    let (instances, total) = store
+        .filter_instances("paginated", filter)
+        .await
+        .expect("Should filter");
+
+    assert_eq!(total, 10);
+    assert_eq!(instances.len(), 3);
+
+    // Page 2 (offset 3, limit 3)
+    let filter = FilterRequest {
+        condition: None,
+        sort_by: Some(vec!["index".to_string()]),
+        sort_order: Some(vec!["asc".to_string()]),
+        limit: 3,
+        offset: 3,
+        computed: Vec::new(),
+        unique_sort: false,
+    };
+
+    let (instances, _) = store
+        .filter_instances("paginated", filter)
+        .await
+        .expect("Should filter");
+
+    assert_eq!(instances.len(), 3);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+// ==================== Bulk Operations Tests ====================
+
+#[tokio::test]
+async fn test_update_instances_with_condition() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let table_name = format!("{}_bulk_update", prefix);
+    let request = CreateSchemaRequest {
+        name: "bulk_update".to_string(),
+        description: None,
+        table_name: table_name.clone(),
+        columns: vec![
+            ColumnDefinition::new("category", ColumnType::String).not_null(),
+            ColumnDefinition::new("status", ColumnType::String).not_null(),
+            ColumnDefinition::new("count", ColumnType::Integer),
+        ],
+        indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
+    };
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    // Create test instances
+    for i in 0..5 {
+        let category = if i < 3 { "electronics" } else { "clothing" };
+        store
+            .create_instance(
+                "bulk_update",
+                serde_json::json!({
+                    "category": category,
+                    "status": "active",
+                    "count": i
+                }),
+            )
+            .await
+            .expect("Should create instance");
+    }
+
+    // Update all electronics to archived
+    let affected = store
+        .update_instances(
+            "bulk_update",
+            serde_json::json!({"status": "archived"}),
+            Condition::eq("category", "electronics"),
+        )
+        .await
+        .expect("Should update instances");
+
+    assert_eq!(affected, 3);
+
+    // Verify the update
+    let (instances, _) = store
+        .filter_instances(
+            "bulk_update",
+            FilterRequest::new().with_condition(Condition::eq("status", "archived")),
+        )
+        .await
+        .expect("Should filter");
+
+    assert_eq!(instances.len(), 3);
+
+    // Verify clothing is still active
+    let (instances, _) = store
+        .filter_instances(
+            "bulk_update",
+            FilterRequest::new().with_condition(Condition::eq("status", "active")),
+        )
+        .await
+        .expect("Should filter");
+
+    assert_eq!(instances.len(), 2);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_set_field_where() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "orders",
+            format!("{}_orders", prefix),
+            vec![
+                ColumnDefinition::new("status", ColumnType::String).not_null(),
+                ColumnDefinition::new("placed_at", ColumnType::timestamp()),
+            ],
+        ))
+        .await
+        .expect("Should create schema");
+
+    for placed_at in [
+        "2020-01-01T00:00:00Z",
+        "2021-06-01T00:00:00Z",
+        "2025-01-01T00:00:00Z",
+    ] {
+        store
+            .create_instance(
+                "orders",
+                serde_json::json!({"status": "open", "placed_at": placed_at}),
+            )
+            .await
+            .expect("Should create instance");
+    }
+
+    let affected = store
+        .set_field_where(
+            "orders",
+            "status",
+            serde_json::json!("archived"),
+            Condition::lt("placed_at", "2024-01-01T00:00:00Z"),
+        )
+        .await
+        .expect("Should set field");
+    assert_eq!(affected, 2);
+
+    let (open, _) = store
+        .filter_instances(
+            "orders",
+            FilterRequest::new().with_condition(Condition::eq("status", "open")),
+        )
+        .await
+        .expect("Should filter");
+    assert_eq!(open.len(), 1);
+    assert!(
+        open[0].properties["placed_at"]
+            .as_str()
+            .unwrap()
+            .starts_with("2025-01-01")
+    );
+
+    let err = store
+        .set_field_where(
+            "orders",
+            "missing",
+            serde_json::json!("archived"),
+            Condition::eq("status", "open"),
+        )
+        .await
+        .expect_err("Should reject unknown column");
+    assert!(matches!(err, ObjectStoreError::Validation(_)));
+
+    let err = store
+        .set_field_where(
+            "orders",
+            "placed_at",
+            serde_json::json!("not a timestamp"),
+            Condition::eq("status", "open"),
+        )
+        .await
+        .expect_err("Should reject invalid value");
+    assert!(matches!(err, ObjectStoreError::Validation(_)));
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_update_instances_no_matches() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let table_name = format!("{}_bulk_update_empty", prefix);
+    let request = CreateSchemaRequest {
+        name: "bulk_update_empty".to_string(),
+        description: None,
+        table_name: table_name.clone(),
+        columns: vec![
+            ColumnDefinition::new("name", ColumnType::String).not_null(),
+            ColumnDefinition::new("status", ColumnType::String),
+        ],
+        indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
+    };
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    store
+        .create_instance(
+            "bulk_update_empty",
+            serde_json::json!({"name": "test", "status": "active"}),
+        )
+        .await
+        .expect("Should create");
+
+    // Update with condition that matches nothing
+    let affected = store
+        .update_instances(
+            "bulk_update_empty",
+            serde_json::json!({"status": "archived"}),
+            Condition::eq("name", "nonexistent"),
+        )
+        .await
+        .expect("Should succeed with 0 affected");
+
+    assert_eq!(affected, 0);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_update_instances_rejects_null_for_not_null_column() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let request = CreateSchemaRequest::new(
+        "bulk_update_null",
+        format!("{}_bulk_update_null", prefix),
+        vec![
+            ColumnDefinition::new("name", ColumnType::String).not_null(),
+            ColumnDefinition::new("status", ColumnType::String),
+        ],
+    );
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    store
+        .create_instance(
+            "bulk_update_null",
+            serde_json::json!({"name": "test", "status": "active"}),
+        )
+        .await
+        .expect("Should create");
+
+    let result = store
+        .update_instances(
+            "bulk_update_null",
+            serde_json::json!({"name": null}),
+            Condition::eq("status", "active"),
+        )
+        .await;
+    match result {
+        Err(ObjectStoreError::Validation(msg)) => assert!(msg.contains("'name'")),
+        other => panic!("Expected validation error, got {:?}", other),
+    }
+
+    // Nullable columns can still be cleared
+    let affected = store
+        .update_instances(
+            "bulk_update_null",
+            serde_json::json!({"status": null}),
+            Condition::eq("name", "test"),
+        )
+        .await
+        .expect("Should clear nullable column");
+    assert_eq!(affected, 1);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_delete_instances_soft_delete() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let table_name = format!("{}_bulk_delete_soft", prefix);
+    let request = CreateSchemaRequest {
+        name: "bulk_delete_soft".to_string(),
+        description: None,
+        table_name: table_name.clone(),
+        columns: vec![
+            ColumnDefinition::new("category", ColumnType::String).not_null(),
+            ColumnDefinition::new("value", ColumnType::Integer),
+        ],
+        indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
+    };
+
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    // Create test instances
+    for i in 0..5 {
+        let category = if i < 3 { "to_delete" } else { "keep" };
+        store
+            .create_instance(
+                "bulk_delete_soft",
+                serde_json::json!({
+                    "category": category,
+                    "value": i
+                }),
+            )
+            .await
+            .expect("Should create instance");
+    }
+
+    // Delete all "to_delete" category
+    let affected = store
+        .delete_instances("bulk_delete_soft", Condition::eq("category", "to_delete"))
+        .await
+        .expect("Should delete instances");
+
+    assert_eq!(affected, 3);
+
+    // Verify only "keep" remains visible
+    let (instances, total) = store
+        .query_instances(SimpleFilter::new("bulk_delete_soft"))
+        .await
+        .expect("Should query");
+
+    assert_eq!(total, 2);
+    assert_eq!(instances.len(), 2);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_delete_instances_preview() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "preview_delete",
+            format!("{}_preview_delete", prefix),
+            vec![ColumnDefinition::new("value", ColumnType::Integer)],
+        ))
+        .await
+        .expect("Should create schema");
+
+    for i in 0..6 {
+        store
+            .create_instance("preview_delete", serde_json::json!({"value": i}))
+            .await
+            .expect("Should create instance");
+    }
+
+    let condition = Condition::gte("value", 2);
+    let preview = store
+        .delete_instances_preview("preview_delete", condition.clone())
+        .await
+        .expect("Should preview delete");
+    assert_eq!(preview, 4);
+
+    // The preview does not delete anything
+    let (_, total) = store
+        .query_instances(SimpleFilter::new("preview_delete"))
+        .await
+        .expect("Should query");
+    assert_eq!(total, 6);
+
+    let affected = store
+        .delete_instances("preview_delete", condition.clone())
+        .await
+        .expect("Should delete instances");
+    assert_eq!(affected, preview);
+
+    // Already-deleted rows no longer count
+    let preview = store
+        .delete_instances_preview("preview_delete", condition)
+        .await
+        .expect("Should preview delete");
+    assert_eq!(preview, 0);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_count_by_deleted() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "counted",
+            format!("{}_counted", prefix),
+            vec![ColumnDefinition::new("value", ColumnType::Integer)],
+        ))
+        .await
+        .expect("Should create schema");
+
+    for i in 0..5 {
+        store
+            .create_instance("counted", serde_json::json!({"value": i}))
+            .await
+            .expect("Should create instance");
+    }
+    store
+        .delete_instances("counted", Condition::lt("value", 2))
+        .await
+        .expect("Should delete instances");
+
+    let (active, deleted) = store
+        .count_by_deleted("counted")
+        .await
+        .expect("Should count");
+    assert_eq!(active, 3);
+    assert_eq!(deleted, 2);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_delete_instances_hard_delete() {
+    let db_url = match get_database_url() {
+        Some(url) => url,
+        None => {
+            eprintln!("Skipping test: TEST_DATABASE_URL not set");
+            return;
+        }
+    };
+
+    let prefix = test_prefix();
+    let metadata_table = format!("{}__schema", prefix);
+
+    // Create store with soft_delete disabled
+    let config = StoreConfig::builder(&db_url)
+        .metadata_table(&metadata_table)
+        .soft_delete(false)
+        .build();
+
+    let store = ObjectStore::new(config).await.expect("Should create store");
+
+    let table_name = format!("{}_bulk_delete_hard", prefix);
     let request = CreateSchemaRequest {
-        name: "hard_delete_test".to_string(),
+        name: "bulk_delete_hard".to_string(),
         description: None,
-        table_name: format!("{}_hard", prefix),
-        columns: vec![ColumnDefinition::new("x", ColumnType::String)],
+        table_name: table_name.clone(),
+        columns: vec![
+            ColumnDefinition::new("tag", ColumnType::String).not_null(),
+            ColumnDefinition::new("value", ColumnType::Integer),
+        ],
         indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
     };
 
     store
@@ -746,86 +4653,340 @@ async fn test_store_without_soft_delete() {
         .await
         .expect("Should create schema");
 
-    // Delete (hard delete)
+    // Create test instances
+    for i in 0..4 {
+        let tag = if i < 2 { "remove" } else { "stay" };
+        store
+            .create_instance(
+                "bulk_delete_hard",
+                serde_json::json!({
+                    "tag": tag,
+                    "value": i
+                }),
+            )
+            .await
+            .expect("Should create instance");
+    }
+
+    // Hard delete "remove" tag
+    let affected = store
+        .delete_instances("bulk_delete_hard", Condition::eq("tag", "remove"))
+        .await
+        .expect("Should delete instances");
+
+    assert_eq!(affected, 2);
+
+    // Verify only "stay" remains
+    let (instances, total) = store
+        .query_instances(SimpleFilter::new("bulk_delete_hard"))
+        .await
+        .expect("Should query");
+
+    assert_eq!(total, 2);
+    assert_eq!(instances.len(), 2);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_create_instances_batch() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    let table_name = format!("{}_bulk_create", prefix);
+    let request = CreateSchemaRequest {
+        name: "bulk_create".to_string(),
+        description: None,
+        table_name: table_name.clone(),
+        columns: vec![
+            ColumnDefinition::new("name", ColumnType::String).not_null(),
+            ColumnDefinition::new("index", ColumnType::Integer).not_null(),
+            ColumnDefinition::new("active", ColumnType::Boolean),
+        ],
+        indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
+    };
+
     store
-        .delete_schema("hard_delete_test")
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
+
+    // Create multiple instances at once
+    let instances: Vec<serde_json::Value> = (0..10)
+        .map(|i| {
+            serde_json::json!({
+                "name": format!("Item {}", i),
+                "index": i,
+                "active": i % 2 == 0
+            })
+        })
+        .collect();
+
+    let affected = store
+        .create_instances("bulk_create", instances)
+        .await
+        .expect("Should create instances");
+
+    assert_eq!(affected, 10);
+
+    // Verify all were created
+    let (results, total) = store
+        .query_instances(SimpleFilter::new("bulk_create"))
+        .await
+        .expect("Should query");
+
+    assert_eq!(total, 10);
+    assert_eq!(results.len(), 10);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_create_instances_best_effort() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "ingest",
+            format!("{}_ingest", prefix),
+            vec![
+                ColumnDefinition::new("sku", ColumnType::String)
+                    .unique()
+                    .not_null(),
+                ColumnDefinition::new("qty", ColumnType::Integer),
+            ],
+        ))
+        .await
+        .expect("Should create schema");
+
+    let instances = vec![
+        serde_json::json!({"sku": "A", "qty": 1}),
+        serde_json::json!({"sku": "B", "qty": "lots"}), // invalid integer
+        serde_json::json!({"sku": "C", "qty": 3}),
+        serde_json::json!({"qty": 4}), // missing required column
+        serde_json::json!({"sku": "A", "qty": 5}), // unique violation
+        serde_json::json!({"sku": "F", "qty": 6}),
+    ];
+
+    let result = store
+        .create_instances_best_effort("ingest", instances)
+        .await
+        .expect("Should run best-effort insert");
+
+    assert_eq!(result.inserted.len(), 3);
+    let failed: Vec<usize> = result.failed.iter().map(|(idx, _)| *idx).collect();
+    assert_eq!(failed, vec![1, 3, 4]);
+    assert!(result.failed[0].1.contains("qty"));
+
+    let mut skus = Vec::new();
+    for id in &result.inserted {
+        let instance = store
+            .get_instance("ingest", id)
+            .await
+            .expect("Should get instance")
+            .expect("Inserted instance should exist");
+        skus.push(instance.properties["sku"].as_str().unwrap().to_string());
+    }
+    assert_eq!(skus, vec!["A", "C", "F"]);
+
+    let (_, total) = store
+        .query_instances(SimpleFilter::new("ingest"))
+        .await
+        .expect("Should query");
+    assert_eq!(total, 3);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_filter_by_created_at_range() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "audit",
+            format!("{}_audit", prefix),
+            vec![ColumnDefinition::new("name", ColumnType::String)],
+        ))
+        .await
+        .expect("Should create schema");
+
+    store
+        .create_instances(
+            "audit",
+            vec![
+                serde_json::json!({"name": "old", "createdAt": "2020-01-15T10:30:00Z"}),
+                serde_json::json!({"name": "mid", "createdAt": "2024-06-01T00:00:00+02:00"}),
+                serde_json::json!({"name": "recent"}),
+            ],
+        )
+        .await
+        .expect("Should create instances");
+
+    let names = |instances: Vec<runtara_object_store::Instance>| {
+        let mut names: Vec<String> = instances
+            .into_iter()
+            .map(|i| i.properties["name"].as_str().unwrap().to_string())
+            .collect();
+        names.sort();
+        names
+    };
+
+    // camelCase and snake_case both resolve to the system column
+    let (in_2024, _) = store
+        .filter_instances(
+            "audit",
+            FilterRequest::new().with_condition(Condition::between(
+                "createdAt",
+                "2024-01-01T00:00:00Z",
+                "2024-12-31T23:59:59Z",
+            )),
+        )
         .await
-        .expect("Should hard delete");
+        .expect("Should filter by createdAt range");
+    assert_eq!(names(in_2024), vec!["mid"]);
 
-    // Table should be dropped - verify by trying to query the metadata directly
-    let count: (i64,) = sqlx::query_as(&format!(
-        "SELECT COUNT(*) FROM \"{}__schema\" WHERE name = 'hard_delete_test'",
-        prefix
-    ))
-    .fetch_one(store.pool())
-    .await
-    .expect("Should query");
+    // "Created in the last 7 days" compares instants, not text
+    let week_ago = (chrono::Utc::now() - chrono::Duration::days(7)).to_rfc3339();
+    let (last_week, _) = store
+        .filter_instances(
+            "audit",
+            FilterRequest::new().with_condition(Condition::gte("created_at", week_ago)),
+        )
+        .await
+        .expect("Should filter by created_at");
+    assert_eq!(names(last_week), vec!["recent"]);
 
-    assert_eq!(count.0, 0); // Row should be gone, not just soft-deleted
+    let (before_2024, _) = store
+        .filter_instances(
+            "audit",
+            FilterRequest::new().with_condition(Condition::lt("updatedAt", "2024-01-01T00:00:00Z")),
+        )
+        .await
+        .expect("Should filter by updatedAt");
+    assert_eq!(names(before_2024), vec!["old"]);
 
     cleanup_test(&store, &prefix).await;
 }
 
 #[tokio::test]
-async fn test_custom_metadata_table() {
-    let Some(db_url) = get_database_url() else {
+async fn test_create_instances_preserves_explicit_timestamps() {
+    let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    let prefix = test_prefix();
-    let custom_metadata = format!("{}_custom_meta", prefix);
+    let request = CreateSchemaRequest::new(
+        "bulk_import",
+        format!("{}_bulk_import", prefix),
+        vec![ColumnDefinition::new("name", ColumnType::String).not_null()],
+    );
 
-    let config = StoreConfig::builder(&db_url)
-        .metadata_table(&custom_metadata)
-        .build();
+    store
+        .create_schema(request)
+        .await
+        .expect("Should create schema");
 
-    let store = ObjectStore::new(config).await.expect("Should create store");
+    let instances = vec![
+        serde_json::json!({
+            "name": "historical",
+            "createdAt": "2020-01-15T10:30:00Z",
+            "updatedAt": "2021-06-01T08:00:00Z"
+        }),
+        serde_json::json!({
+            "name": "created_only",
+            "createdAt": "2019-03-02T12:00:00+00:00"
+        }),
+        serde_json::json!({ "name": "fresh" }),
+    ];
 
-    // Verify the custom metadata table exists
-    let exists: (bool,) = sqlx::query_as(&format!(
-        "SELECT EXISTS (SELECT 1 FROM information_schema.tables WHERE table_name = '{}')",
-        custom_metadata
-    ))
-    .fetch_one(store.pool())
-    .await
-    .expect("Should query");
+    let affected = store
+        .create_instances("bulk_import", instances)
+        .await
+        .expect("Should create instances");
+    assert_eq!(affected, 3);
 
-    assert!(exists.0);
+    let (results, _) = store
+        .query_instances(SimpleFilter::new("bulk_import"))
+        .await
+        .expect("Should query");
 
-    // Clean up
-    let _ = sqlx::query(&format!(
-        "DROP TABLE IF EXISTS \"{}\" CASCADE",
-        custom_metadata
-    ))
-    .execute(store.pool())
-    .await;
-}
+    let by_name = |name: &str| {
+        results
+            .iter()
+            .find(|i| i.properties["name"] == name)
+            .expect("Instance should exist")
+    };
 
-// ==================== Column Type Tests ====================
+    let historical = by_name("historical");
+    assert!(historical.created_at.starts_with("2020-01-15T10:30:00"));
+    assert!(historical.updated_at.starts_with("2021-06-01T08:00:00"));
+
+    // updatedAt falls back to the explicit createdAt
+    let created_only = by_name("created_only");
+    assert!(created_only.created_at.starts_with("2019-03-02T12:00:00"));
+    assert!(created_only.updated_at.starts_with("2019-03-02T12:00:00"));
+
+    // Rows without explicit timestamps still get NOW()
+    let fresh = by_name("fresh");
+    assert!(!fresh.created_at.starts_with("2019"));
+    assert!(!fresh.created_at.starts_with("2020"));
+
+    // Malformed timestamps are rejected with the offending index and key
+    let result = store
+        .create_instances(
+            "bulk_import",
+            vec![serde_json::json!({ "name": "bad", "createdAt": "yesterday" })],
+        )
+        .await;
+    match result {
+        Err(ObjectStoreError::InstanceValidation {
+            instance_index,
+            column,
+            ..
+        }) => {
+            assert_eq!(instance_index, Some(0));
+            assert_eq!(column.as_deref(), Some("createdAt"));
+        }
+        other => panic!("Expected InstanceValidation error, got {:?}", other),
+    }
+
+    cleanup_test(&store, &prefix).await;
+}
 
 #[tokio::test]
-async fn test_all_column_types() {
+async fn test_create_instances_validation_rollback() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    // Create schema with all column types
+    let table_name = format!("{}_bulk_create_fail", prefix);
     let request = CreateSchemaRequest {
-        name: "all_types".to_string(),
+        name: "bulk_create_fail".to_string(),
         description: None,
-        table_name: format!("{}_all_types", prefix),
+        table_name: table_name.clone(),
         columns: vec![
-            ColumnDefinition::new("string_col", ColumnType::String),
-            ColumnDefinition::new("int_col", ColumnType::Integer),
-            ColumnDefinition::new("float_col", ColumnType::decimal(10, 2)),
-            ColumnDefinition::new("bool_col", ColumnType::Boolean),
-            ColumnDefinition::new("json_col", ColumnType::Json),
-            ColumnDefinition::new("decimal_col", ColumnType::decimal(10, 2)),
-            ColumnDefinition::new("timestamp_col", ColumnType::Timestamp),
+            ColumnDefinition::new("name", ColumnType::String).not_null(),
+            ColumnDefinition::new("count", ColumnType::Integer).not_null(),
         ],
         indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
     };
 
     store
@@ -833,59 +4994,52 @@ async fn test_all_column_types() {
         .await
         .expect("Should create schema");
 
-    // Create instance with all types
-    let id = store
-        .create_instance(
-            "all_types",
-            serde_json::json!({
-                "string_col": "hello",
-                "int_col": 42,
-                "float_col": 9.87,
-                "bool_col": true,
-                "json_col": {"nested": "value", "arr": [1, 2, 3]},
-                "decimal_col": 123.45,
-                "timestamp_col": "2024-01-15T10:30:00Z"
-            }),
-        )
-        .await
-        .expect("Should create instance");
+    // Try to create instances with one invalid (missing required field)
+    let instances = vec![
+        serde_json::json!({"name": "Valid 1", "count": 1}),
+        serde_json::json!({"name": "Valid 2", "count": 2}),
+        serde_json::json!({"name": "Invalid"}), // Missing required "count"
+        serde_json::json!({"name": "Valid 3", "count": 3}),
+    ];
 
-    // Retrieve and verify
-    let instance = store
-        .get_instance("all_types", &id)
+    let result = store.create_instances("bulk_create_fail", instances).await;
+
+    // Should fail due to validation
+    assert!(result.is_err());
+
+    // Verify no instances were created (pre-validation should prevent any insertion)
+    let (results, total) = store
+        .query_instances(SimpleFilter::new("bulk_create_fail"))
         .await
-        .expect("Should not error")
-        .expect("Instance should exist");
+        .expect("Should query");
 
-    assert_eq!(instance.properties["string_col"], "hello");
-    assert_eq!(instance.properties["int_col"], 42);
-    assert!((instance.properties["float_col"].as_f64().unwrap() - 9.87).abs() < 0.01);
-    assert_eq!(instance.properties["bool_col"], true);
-    assert_eq!(instance.properties["json_col"]["nested"], "value");
-    assert!((instance.properties["decimal_col"].as_f64().unwrap() - 123.45).abs() < 0.01);
+    assert_eq!(total, 0);
+    assert_eq!(results.len(), 0);
 
     cleanup_test(&store, &prefix).await;
 }
 
-// ==================== Sorting Tests ====================
-
 #[tokio::test]
-async fn test_sorting() {
+async fn test_bulk_validation_error_reports_index_and_column() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    // Create schema
+    let table_name = format!("{}_bulk_structured", prefix);
     let request = CreateSchemaRequest {
-        name: "sortable".to_string(),
+        name: "bulk_structured".to_string(),
         description: None,
-        table_name: format!("{}_sortable", prefix),
+        table_name: table_name.clone(),
         columns: vec![
-            ColumnDefinition::new("name", ColumnType::String).not_null(),
-            ColumnDefinition::new("rank", ColumnType::Integer),
+            ColumnDefinition::new("sku", ColumnType::String).not_null(),
+            ColumnDefinition::new("count", ColumnType::Integer),
         ],
         indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
     };
 
     store
@@ -893,75 +5047,105 @@ async fn test_sorting() {
         .await
         .expect("Should create schema");
 
-    // Create instances
-    for (name, rank) in [("Charlie", 3), ("Alice", 1), ("Bob", 2)] {
-        store
-            .create_instance(
-                "sortable",
-                serde_json::json!({
-                    "name": name,
-                    "rank": rank
-                }),
-            )
-            .await
-            .expect("Should create instance");
-    }
+    let instances = vec![
+        serde_json::json!({"sku": "A", "count": 1}),
+        serde_json::json!({"sku": "B", "count": "not a number"}),
+    ];
 
-    // Sort by name ascending
-    let filter = FilterRequest {
-        condition: None,
-        sort_by: Some(vec!["name".to_string()]),
-        sort_order: Some(vec!["asc".to_string()]),
-        limit: 100,
-        offset: 0,
-    };
+    let err = store
+        .create_instances("bulk_structured", instances.clone())
+        .await
+        .expect_err("Should fail validation");
+
+    match err {
+        ObjectStoreError::InstanceValidation {
+            instance_index,
+            column,
+            ..
+        } => {
+            assert_eq!(instance_index, Some(1));
+            assert_eq!(column.as_deref(), Some("count"));
+        }
+        other => panic!("Expected InstanceValidation, got {:?}", other),
+    }
 
-    let (instances, _) = store
-        .filter_instances("sortable", filter)
+    // Upsert surfaces the same structured information
+    let err = store
+        .upsert_instances("bulk_structured", instances, vec!["sku".to_string()])
         .await
-        .expect("Should filter");
+        .expect_err("Should fail validation");
 
-    assert_eq!(instances[0].properties["name"], "Alice");
-    assert_eq!(instances[1].properties["name"], "Bob");
-    assert_eq!(instances[2].properties["name"], "Charlie");
+    assert!(matches!(
+        err,
+        ObjectStoreError::InstanceValidation {
+            instance_index: Some(1),
+            ..
+        }
+    ));
 
-    // Sort by rank descending
-    let filter = FilterRequest {
-        condition: None,
-        sort_by: Some(vec!["rank".to_string()]),
-        sort_order: Some(vec!["desc".to_string()]),
-        limit: 100,
-        offset: 0,
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_create_instances_empty() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
     };
 
-    let (instances, _) = store
-        .filter_instances("sortable", filter)
+    let table_name = format!("{}_bulk_create_empty", prefix);
+    let request = CreateSchemaRequest {
+        name: "bulk_create_empty".to_string(),
+        description: None,
+        table_name: table_name.clone(),
+        columns: vec![ColumnDefinition::new("name", ColumnType::String)],
+        indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
+    };
+
+    store
+        .create_schema(request)
         .await
-        .expect("Should filter");
+        .expect("Should create schema");
 
-    assert_eq!(instances[0].properties["rank"], 3);
-    assert_eq!(instances[1].properties["rank"], 2);
-    assert_eq!(instances[2].properties["rank"], 1);
+    // Create with empty array
+    let affected = store
+        .create_instances("bulk_create_empty", vec![])
+        .await
+        .expect("Should succeed");
+
+    assert_eq!(affected, 0);
 
     cleanup_test(&store, &prefix).await;
 }
 
-// ==================== Pagination Tests ====================
-
 #[tokio::test]
-async fn test_pagination() {
+async fn test_upsert_instances_insert_only() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    // Create schema
+    let table_name = format!("{}_upsert_insert", prefix);
     let request = CreateSchemaRequest {
-        name: "paginated".to_string(),
+        name: "upsert_insert".to_string(),
         description: None,
-        table_name: format!("{}_paginated", prefix),
-        columns: vec![ColumnDefinition::new("index", ColumnType::Integer).not_null()],
+        table_name: table_name.clone(),
+        columns: vec![
+            ColumnDefinition::new("sku", ColumnType::String)
+                .unique()
+                .not_null(),
+            ColumnDefinition::new("name", ColumnType::String).not_null(),
+            ColumnDefinition::new("price", ColumnType::decimal(10, 2)),
+        ],
         indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
     };
 
     store
@@ -969,70 +5153,55 @@ async fn test_pagination() {
         .await
         .expect("Should create schema");
 
-    // Create 10 instances
-    for i in 1..=10 {
-        store
-            .create_instance("paginated", serde_json::json!({"index": i}))
-            .await
-            .expect("Should create instance");
-    }
-
-    // Page 1 (offset 0, limit 3)
-    let filter = FilterRequest {
-        condition: None,
-        sort_by: Some(vec!["index".to_string()]),
-        sort_order: Some(vec!["asc".to_string()]),
-        limit: 3,
-        offset: 0,
-    };
+    // Upsert new instances
+    let instances = vec![
+        serde_json::json!({"sku": "SKU001", "name": "Product 1", "price": 10.00}),
+        serde_json::json!({"sku": "SKU002", "name": "Product 2", "price": 20.00}),
+        serde_json::json!({"sku": "SKU003", "name": "Product 3", "price": 30.00}),
+    ];
 
-    let (instances, total) = store
-        .filter_instances("paginated", filter)
+    let affected = store
+        .upsert_instances("upsert_insert", instances, vec!["sku".to_string()])
         .await
-        .expect("Should filter");
-
-    assert_eq!(total, 10);
-    assert_eq!(instances.len(), 3);
+        .expect("Should upsert");
 
-    // Page 2 (offset 3, limit 3)
-    let filter = FilterRequest {
-        condition: None,
-        sort_by: Some(vec!["index".to_string()]),
-        sort_order: Some(vec!["asc".to_string()]),
-        limit: 3,
-        offset: 3,
-    };
+    assert_eq!(affected, 3);
 
-    let (instances, _) = store
-        .filter_instances("paginated", filter)
+    // Verify all were created
+    let (results, total) = store
+        .query_instances(SimpleFilter::new("upsert_insert"))
         .await
-        .expect("Should filter");
+        .expect("Should query");
 
-    assert_eq!(instances.len(), 3);
+    assert_eq!(total, 3);
+    assert_eq!(results.len(), 3);
 
     cleanup_test(&store, &prefix).await;
 }
 
-// ==================== Bulk Operations Tests ====================
-
 #[tokio::test]
-async fn test_update_instances_with_condition() {
+async fn test_upsert_instances_update_only() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    let table_name = format!("{}_bulk_update", prefix);
+    let table_name = format!("{}_upsert_update", prefix);
     let request = CreateSchemaRequest {
-        name: "bulk_update".to_string(),
+        name: "upsert_update".to_string(),
         description: None,
         table_name: table_name.clone(),
         columns: vec![
-            ColumnDefinition::new("category", ColumnType::String).not_null(),
-            ColumnDefinition::new("status", ColumnType::String).not_null(),
-            ColumnDefinition::new("count", ColumnType::Integer),
+            ColumnDefinition::new("code", ColumnType::String)
+                .unique()
+                .not_null(),
+            ColumnDefinition::new("value", ColumnType::Integer).not_null(),
         ],
         indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
     };
 
     store
@@ -1040,76 +5209,74 @@ async fn test_update_instances_with_condition() {
         .await
         .expect("Should create schema");
 
-    // Create test instances
-    for i in 0..5 {
-        let category = if i < 3 { "electronics" } else { "clothing" };
+    // Create initial instances
+    for code in ["A", "B", "C"] {
         store
             .create_instance(
-                "bulk_update",
-                serde_json::json!({
-                    "category": category,
-                    "status": "active",
-                    "count": i
-                }),
+                "upsert_update",
+                serde_json::json!({"code": code, "value": 1}),
             )
             .await
-            .expect("Should create instance");
+            .expect("Should create");
     }
 
-    // Update all electronics to archived
+    // Upsert with updated values
+    let instances = vec![
+        serde_json::json!({"code": "A", "value": 100}),
+        serde_json::json!({"code": "B", "value": 200}),
+        serde_json::json!({"code": "C", "value": 300}),
+    ];
+
     let affected = store
-        .update_instances(
-            "bulk_update",
-            serde_json::json!({"status": "archived"}),
-            Condition::eq("category", "electronics"),
-        )
+        .upsert_instances("upsert_update", instances, vec!["code".to_string()])
         .await
-        .expect("Should update instances");
+        .expect("Should upsert");
 
     assert_eq!(affected, 3);
 
-    // Verify the update
-    let (instances, _) = store
-        .filter_instances(
-            "bulk_update",
-            FilterRequest::new().with_condition(Condition::eq("status", "archived")),
-        )
+    // Verify values were updated
+    let (_results, total) = store
+        .query_instances(SimpleFilter::new("upsert_update"))
         .await
-        .expect("Should filter");
+        .expect("Should query");
 
-    assert_eq!(instances.len(), 3);
+    assert_eq!(total, 3); // Still only 3 (no new rows)
 
-    // Verify clothing is still active
-    let (instances, _) = store
-        .filter_instances(
-            "bulk_update",
-            FilterRequest::new().with_condition(Condition::eq("status", "active")),
-        )
+    // Check one of the updated values
+    let instance = store
+        .instance_exists(SimpleFilter::new("upsert_update").filter("code", "A"))
         .await
-        .expect("Should filter");
+        .expect("Should find")
+        .expect("Should exist");
 
-    assert_eq!(instances.len(), 2);
+    assert_eq!(instance.properties["value"], 100);
 
     cleanup_test(&store, &prefix).await;
 }
 
 #[tokio::test]
-async fn test_update_instances_no_matches() {
+async fn test_upsert_instances_mixed() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    let table_name = format!("{}_bulk_update_empty", prefix);
+    let table_name = format!("{}_upsert_mixed", prefix);
     let request = CreateSchemaRequest {
-        name: "bulk_update_empty".to_string(),
+        name: "upsert_mixed".to_string(),
         description: None,
         table_name: table_name.clone(),
         columns: vec![
-            ColumnDefinition::new("name", ColumnType::String).not_null(),
-            ColumnDefinition::new("status", ColumnType::String),
+            ColumnDefinition::new("key", ColumnType::String)
+                .unique()
+                .not_null(),
+            ColumnDefinition::new("data", ColumnType::String).not_null(),
         ],
         indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
     };
 
     store
@@ -1117,46 +5284,85 @@ async fn test_update_instances_no_matches() {
         .await
         .expect("Should create schema");
 
+    // Create some initial instances
     store
         .create_instance(
-            "bulk_update_empty",
-            serde_json::json!({"name": "test", "status": "active"}),
+            "upsert_mixed",
+            serde_json::json!({"key": "existing1", "data": "old1"}),
+        )
+        .await
+        .expect("Should create");
+    store
+        .create_instance(
+            "upsert_mixed",
+            serde_json::json!({"key": "existing2", "data": "old2"}),
         )
         .await
         .expect("Should create");
 
-    // Update with condition that matches nothing
+    // Upsert: 2 updates + 2 inserts
+    let instances = vec![
+        serde_json::json!({"key": "existing1", "data": "updated1"}), // Update
+        serde_json::json!({"key": "new1", "data": "new1"}),          // Insert
+        serde_json::json!({"key": "existing2", "data": "updated2"}), // Update
+        serde_json::json!({"key": "new2", "data": "new2"}),          // Insert
+    ];
+
     let affected = store
-        .update_instances(
-            "bulk_update_empty",
-            serde_json::json!({"status": "archived"}),
-            Condition::eq("name", "nonexistent"),
-        )
+        .upsert_instances("upsert_mixed", instances, vec!["key".to_string()])
         .await
-        .expect("Should succeed with 0 affected");
+        .expect("Should upsert");
 
-    assert_eq!(affected, 0);
+    assert_eq!(affected, 4);
+
+    // Verify total count
+    let (_, total) = store
+        .query_instances(SimpleFilter::new("upsert_mixed"))
+        .await
+        .expect("Should query");
+
+    assert_eq!(total, 4); // 2 existing + 2 new
+
+    // Verify updates happened
+    let instance = store
+        .instance_exists(SimpleFilter::new("upsert_mixed").filter("key", "existing1"))
+        .await
+        .expect("Should find")
+        .expect("Should exist");
+
+    assert_eq!(instance.properties["data"], "updated1");
 
     cleanup_test(&store, &prefix).await;
 }
 
 #[tokio::test]
-async fn test_delete_instances_soft_delete() {
+async fn test_upsert_instances_multi_column_conflict() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    let table_name = format!("{}_bulk_delete_soft", prefix);
+    let table_name = format!("{}_upsert_multi", prefix);
     let request = CreateSchemaRequest {
-        name: "bulk_delete_soft".to_string(),
+        name: "upsert_multi".to_string(),
         description: None,
         table_name: table_name.clone(),
         columns: vec![
-            ColumnDefinition::new("category", ColumnType::String).not_null(),
-            ColumnDefinition::new("value", ColumnType::Integer),
+            ColumnDefinition::new("tenant", ColumnType::String).not_null(),
+            ColumnDefinition::new("code", ColumnType::String).not_null(),
+            ColumnDefinition::new("value", ColumnType::Integer).not_null(),
         ],
-        indexes: None,
+        indexes: Some(vec![
+            IndexDefinition::new(
+                "tenant_code_unique",
+                vec!["tenant".to_string(), "code".to_string()],
+            )
+            .unique(),
+        ]),
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
     };
 
     store
@@ -1164,186 +5370,313 @@ async fn test_delete_instances_soft_delete() {
         .await
         .expect("Should create schema");
 
-    // Create test instances
-    for i in 0..5 {
-        let category = if i < 3 { "to_delete" } else { "keep" };
-        store
-            .create_instance(
-                "bulk_delete_soft",
-                serde_json::json!({
-                    "category": category,
-                    "value": i
-                }),
-            )
-            .await
-            .expect("Should create instance");
-    }
+    // Create initial instances
+    store
+        .create_instance(
+            "upsert_multi",
+            serde_json::json!({"tenant": "A", "code": "X", "value": 1}),
+        )
+        .await
+        .expect("Should create");
+    store
+        .create_instance(
+            "upsert_multi",
+            serde_json::json!({"tenant": "A", "code": "Y", "value": 2}),
+        )
+        .await
+        .expect("Should create");
+
+    // Upsert with multi-column conflict
+    let instances = vec![
+        serde_json::json!({"tenant": "A", "code": "X", "value": 100}), // Update
+        serde_json::json!({"tenant": "A", "code": "Z", "value": 3}),   // Insert
+        serde_json::json!({"tenant": "B", "code": "X", "value": 4}),   // Insert (different tenant)
+    ];
 
-    // Delete all "to_delete" category
     let affected = store
-        .delete_instances("bulk_delete_soft", Condition::eq("category", "to_delete"))
+        .upsert_instances(
+            "upsert_multi",
+            instances,
+            vec!["tenant".to_string(), "code".to_string()],
+        )
+        .await
+        .expect("Should upsert");
+
+    assert_eq!(affected, 3);
+
+    // Verify total count: 2 original + 2 new = 4
+    let (_, total) = store
+        .query_instances(SimpleFilter::new("upsert_multi"))
+        .await
+        .expect("Should query");
+
+    assert_eq!(total, 4);
+
+    // Verify the update happened
+    let (instances, _) = store
+        .filter_instances(
+            "upsert_multi",
+            FilterRequest::new().with_condition(Condition::and(vec![
+                Condition::eq("tenant", "A"),
+                Condition::eq("code", "X"),
+            ])),
+        )
+        .await
+        .expect("Should filter");
+
+    assert_eq!(instances.len(), 1);
+    assert_eq!(instances[0].properties["value"], 100);
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_upsert_single_instance() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "upsert_single",
+            format!("{}_upsert_single", prefix),
+            vec![
+                ColumnDefinition::new("sku", ColumnType::String)
+                    .unique()
+                    .not_null(),
+                ColumnDefinition::new("name", ColumnType::String),
+            ],
+        ))
         .await
-        .expect("Should delete instances");
+        .expect("Should create schema");
 
-    assert_eq!(affected, 3);
+    let inserted = store
+        .upsert_instance(
+            "upsert_single",
+            serde_json::json!({"sku": "SKU001", "name": "First"}),
+            vec!["sku".to_string()],
+        )
+        .await
+        .expect("Should insert");
+    assert_eq!(inserted.properties["name"], "First");
+
+    let updated = store
+        .upsert_instance(
+            "upsert_single",
+            serde_json::json!({"sku": "SKU001", "name": "Second"}),
+            vec!["sku".to_string()],
+        )
+        .await
+        .expect("Should update");
+    assert_eq!(updated.id, inserted.id);
+    assert_eq!(updated.properties["name"], "Second");
 
-    // Verify only "keep" remains visible
-    let (instances, total) = store
-        .query_instances(SimpleFilter::new("bulk_delete_soft"))
+    let (_, total) = store
+        .query_instances(SimpleFilter::new("upsert_single"))
         .await
         .expect("Should query");
-
-    assert_eq!(total, 2);
-    assert_eq!(instances.len(), 2);
+    assert_eq!(total, 1);
 
     cleanup_test(&store, &prefix).await;
 }
 
 #[tokio::test]
-async fn test_delete_instances_hard_delete() {
-    let db_url = match get_database_url() {
-        Some(url) => url,
-        None => {
-            eprintln!("Skipping test: TEST_DATABASE_URL not set");
-            return;
-        }
+async fn test_upsert_stream() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
     };
 
-    let prefix = test_prefix();
-    let metadata_table = format!("{}__schema", prefix);
-
-    // Create store with soft_delete disabled
-    let config = StoreConfig::builder(&db_url)
-        .metadata_table(&metadata_table)
-        .soft_delete(false)
-        .build();
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "upsert_stream",
+            format!("{}_upsert_stream", prefix),
+            vec![
+                ColumnDefinition::new("sku", ColumnType::String)
+                    .unique()
+                    .not_null(),
+                ColumnDefinition::new("name", ColumnType::String),
+            ],
+        ))
+        .await
+        .expect("Should create schema");
 
-    let store = ObjectStore::new(config).await.expect("Should create store");
+    let mut stream = store
+        .upsert_stream("upsert_stream", vec!["sku".to_string()])
+        .await
+        .expect("Should start stream")
+        .flush_size(2);
+
+    stream
+        .push(vec![
+            serde_json::json!({"sku": "A", "name": "A1"}),
+            serde_json::json!({"sku": "B", "name": "B1"}),
+            serde_json::json!({"sku": "C", "name": "C1"}),
+        ])
+        .await
+        .expect("Should push first batch");
 
-    let table_name = format!("{}_bulk_delete_hard", prefix);
-    let request = CreateSchemaRequest {
-        name: "bulk_delete_hard".to_string(),
-        description: None,
-        table_name: table_name.clone(),
-        columns: vec![
-            ColumnDefinition::new("tag", ColumnType::String).not_null(),
-            ColumnDefinition::new("value", ColumnType::Integer),
-        ],
-        indexes: None,
-    };
+    // Flushed rows stay in the stream's transaction until finish
+    let (_, total) = store
+        .query_instances(SimpleFilter::new("upsert_stream"))
+        .await
+        .expect("Should query");
+    assert_eq!(total, 0);
 
-    store
-        .create_schema(request)
+    stream
+        .push(vec![
+            serde_json::json!({"sku": "A", "name": "A2"}),
+            serde_json::json!({"sku": "D", "name": "D1"}),
+        ])
         .await
-        .expect("Should create schema");
+        .expect("Should push second batch");
 
-    // Create test instances
-    for i in 0..4 {
-        let tag = if i < 2 { "remove" } else { "stay" };
-        store
-            .create_instance(
-                "bulk_delete_hard",
-                serde_json::json!({
-                    "tag": tag,
-                    "value": i
-                }),
-            )
-            .await
-            .expect("Should create instance");
-    }
+    // Invalid batches are rejected without ending the stream
+    let err = stream
+        .push(vec![serde_json::json!({"name": "no sku"})])
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ObjectStoreError::InstanceValidation { .. }));
 
-    // Hard delete "remove" tag
-    let affected = store
-        .delete_instances("bulk_delete_hard", Condition::eq("tag", "remove"))
+    stream
+        .push(vec![serde_json::json!({"sku": "B", "name": "B2"})])
         .await
-        .expect("Should delete instances");
+        .expect("Should push third batch");
 
-    assert_eq!(affected, 2);
+    let affected = stream.finish().await.expect("Should finish");
+    assert_eq!(affected, 6);
 
-    // Verify only "stay" remains
     let (instances, total) = store
-        .query_instances(SimpleFilter::new("bulk_delete_hard"))
+        .query_instances(SimpleFilter::new("upsert_stream"))
+        .await
+        .expect("Should query");
+    assert_eq!(total, 4);
+    let names: std::collections::HashMap<_, _> = instances
+        .iter()
+        .map(|i| {
+            (
+                i.properties["sku"].as_str().unwrap().to_string(),
+                i.properties["name"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    assert_eq!(names["A"], "A2");
+    assert_eq!(names["B"], "B2");
+    assert_eq!(names["C"], "C1");
+    assert_eq!(names["D"], "D1");
+
+    // A stream dropped without finish commits nothing
+    let mut stream = store
+        .upsert_stream("upsert_stream", vec!["sku".to_string()])
+        .await
+        .expect("Should start stream")
+        .flush_size(1);
+    stream
+        .push(vec![serde_json::json!({"sku": "E", "name": "E1"})])
+        .await
+        .expect("Should push");
+    drop(stream);
+
+    let (_, total) = store
+        .query_instances(SimpleFilter::new("upsert_stream"))
         .await
         .expect("Should query");
+    assert_eq!(total, 4);
 
-    assert_eq!(total, 2);
-    assert_eq!(instances.len(), 2);
+    let err = store
+        .upsert_stream("upsert_stream", vec!["missing".to_string()])
+        .await
+        .err()
+        .expect("Unknown conflict column should fail");
+    assert!(matches!(err, ObjectStoreError::Validation(_)));
 
     cleanup_test(&store, &prefix).await;
 }
 
 #[tokio::test]
-async fn test_create_instances_batch() {
+async fn test_upsert_instances_conflict_column_values() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    let table_name = format!("{}_bulk_create", prefix);
-    let request = CreateSchemaRequest {
-        name: "bulk_create".to_string(),
-        description: None,
-        table_name: table_name.clone(),
-        columns: vec![
-            ColumnDefinition::new("name", ColumnType::String).not_null(),
-            ColumnDefinition::new("index", ColumnType::Integer).not_null(),
-            ColumnDefinition::new("active", ColumnType::Boolean),
-        ],
-        indexes: None,
-    };
-
     store
-        .create_schema(request)
+        .create_schema(CreateSchemaRequest::new(
+            "upsert_keys",
+            format!("{}_upsert_keys", prefix),
+            vec![
+                ColumnDefinition::new("sku", ColumnType::String).unique(),
+                ColumnDefinition::new("name", ColumnType::String),
+            ],
+        ))
         .await
         .expect("Should create schema");
 
-    // Create multiple instances at once
-    let instances: Vec<serde_json::Value> = (0..10)
-        .map(|i| {
-            serde_json::json!({
-                "name": format!("Item {}", i),
-                "index": i,
-                "active": i % 2 == 0
-            })
-        })
-        .collect();
+    // A missing conflict value can never match, so it is rejected
+    let result = store
+        .upsert_instances(
+            "upsert_keys",
+            vec![serde_json::json!({"name": "No key"})],
+            vec!["sku".to_string()],
+        )
+        .await;
+    match result {
+        Err(ObjectStoreError::InstanceValidation {
+            instance_index,
+            column,
+            message,
+        }) => {
+            assert_eq!(instance_index, Some(0));
+            assert_eq!(column.as_deref(), Some("sku"));
+            assert!(message.contains("never updated"));
+        }
+        other => panic!("Expected InstanceValidation, got {:?}", other),
+    }
 
-    let affected = store
-        .create_instances("bulk_create", instances)
+    // Conflicting on id uses the supplied id
+    let id = store
+        .create_instance_with_id("upsert_keys", "item-1", serde_json::json!({"name": "Old"}))
         .await
-        .expect("Should create instances");
-
-    assert_eq!(affected, 10);
+        .expect("Should create instance");
 
-    // Verify all were created
-    let (results, total) = store
-        .query_instances(SimpleFilter::new("bulk_create"))
+    let affected = store
+        .upsert_instances(
+            "upsert_keys",
+            vec![serde_json::json!({"id": id, "name": "New"})],
+            vec!["id".to_string()],
+        )
         .await
-        .expect("Should query");
+        .expect("Should upsert by id");
+    assert_eq!(affected, 1);
 
-    assert_eq!(total, 10);
-    assert_eq!(results.len(), 10);
+    let instance = store
+        .require_instance("upsert_keys", "item-1")
+        .await
+        .expect("Should read instance");
+    assert_eq!(instance.properties["name"], "New");
 
     cleanup_test(&store, &prefix).await;
 }
 
 #[tokio::test]
-async fn test_create_instances_validation_rollback() {
+async fn test_upsert_instances_empty_conflict_columns() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    let table_name = format!("{}_bulk_create_fail", prefix);
+    let table_name = format!("{}_upsert_empty_conflict", prefix);
     let request = CreateSchemaRequest {
-        name: "bulk_create_fail".to_string(),
+        name: "upsert_empty_conflict".to_string(),
         description: None,
         table_name: table_name.clone(),
-        columns: vec![
-            ColumnDefinition::new("name", ColumnType::String).not_null(),
-            ColumnDefinition::new("count", ColumnType::Integer).not_null(),
-        ],
+        columns: vec![ColumnDefinition::new("name", ColumnType::String)],
         indexes: None,
+        default_index_predicate: None,
+        create_default_index: None,
+        storage_params: None,
+        tablespace: None,
     };
 
     store
@@ -1351,384 +5684,592 @@ async fn test_create_instances_validation_rollback() {
         .await
         .expect("Should create schema");
 
-    // Try to create instances with one invalid (missing required field)
-    let instances = vec![
-        serde_json::json!({"name": "Valid 1", "count": 1}),
-        serde_json::json!({"name": "Valid 2", "count": 2}),
-        serde_json::json!({"name": "Invalid"}), // Missing required "count"
-        serde_json::json!({"name": "Valid 3", "count": 3}),
-    ];
+    let instances = vec![serde_json::json!({"name": "test"})];
 
-    let result = store.create_instances("bulk_create_fail", instances).await;
+    // Should fail with empty conflict columns
+    let result = store
+        .upsert_instances("upsert_empty_conflict", instances, vec![])
+        .await;
 
-    // Should fail due to validation
     assert!(result.is_err());
 
-    // Verify no instances were created (pre-validation should prevent any insertion)
-    let (results, total) = store
-        .query_instances(SimpleFilter::new("bulk_create_fail"))
-        .await
-        .expect("Should query");
-
-    assert_eq!(total, 0);
-    assert_eq!(results.len(), 0);
-
     cleanup_test(&store, &prefix).await;
 }
 
 #[tokio::test]
-async fn test_create_instances_empty() {
+async fn test_contains_any() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    let table_name = format!("{}_bulk_create_empty", prefix);
-    let request = CreateSchemaRequest {
-        name: "bulk_create_empty".to_string(),
-        description: None,
-        table_name: table_name.clone(),
-        columns: vec![ColumnDefinition::new("name", ColumnType::String)],
-        indexes: None,
-    };
-
     store
-        .create_schema(request)
+        .create_schema(CreateSchemaRequest::new(
+            "contains_any",
+            format!("{}_contains_any", prefix),
+            vec![ColumnDefinition::new("title", ColumnType::String)],
+        ))
         .await
         .expect("Should create schema");
 
-    // Create with empty array
-    let affected = store
-        .create_instances("bulk_create_empty", vec![])
+    for title in [
+        "Red Shirt",
+        "blue jeans",
+        "GREEN hat",
+        "Yellow scarf",
+        "50% off",
+        "500 off",
+    ] {
+        store
+            .create_instance("contains_any", serde_json::json!({ "title": title }))
+            .await
+            .expect("Should create instance");
+    }
+
+    let filter = FilterRequest::new()
+        .with_condition(Condition::contains_any("title", ["red", "Blue", "hat"]));
+    let (instances, total) = store
+        .filter_instances("contains_any", filter)
         .await
-        .expect("Should succeed");
+        .expect("Should filter");
+    assert_eq!(total, 3);
+    let mut titles: Vec<_> = instances
+        .iter()
+        .map(|i| i.properties["title"].as_str().unwrap())
+        .collect();
+    titles.sort_unstable();
+    assert_eq!(titles, ["GREEN hat", "Red Shirt", "blue jeans"]);
 
-    assert_eq!(affected, 0);
+    // `%` matches literally
+    let filter = FilterRequest::new().with_condition(Condition::contains_any("title", ["0%"]));
+    let (instances, _) = store
+        .filter_instances("contains_any", filter)
+        .await
+        .expect("Should filter");
+    assert_eq!(instances.len(), 1);
+    assert_eq!(instances[0].properties["title"], "50% off");
 
     cleanup_test(&store, &prefix).await;
 }
 
 #[tokio::test]
-async fn test_upsert_instances_insert_only() {
+async fn test_typed_condition_params() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    let table_name = format!("{}_upsert_insert", prefix);
-    let request = CreateSchemaRequest {
-        name: "upsert_insert".to_string(),
-        description: None,
-        table_name: table_name.clone(),
-        columns: vec![
-            ColumnDefinition::new("sku", ColumnType::String)
-                .unique()
-                .not_null(),
-            ColumnDefinition::new("name", ColumnType::String).not_null(),
-            ColumnDefinition::new("price", ColumnType::decimal(10, 2)),
-        ],
-        indexes: None,
-    };
-
     store
-        .create_schema(request)
+        .create_schema(CreateSchemaRequest::new(
+            "stock",
+            format!("{}_stock", prefix),
+            vec![
+                ColumnDefinition::new("sku", ColumnType::String),
+                ColumnDefinition::new("qty", ColumnType::Integer),
+                ColumnDefinition::new("price", ColumnType::decimal(10, 2)),
+                ColumnDefinition::new("active", ColumnType::Boolean),
+                ColumnDefinition::new("due", ColumnType::timestamp()),
+            ],
+        ))
         .await
         .expect("Should create schema");
 
-    // Upsert new instances
-    let instances = vec![
-        serde_json::json!({"sku": "SKU001", "name": "Product 1", "price": 10.00}),
-        serde_json::json!({"sku": "SKU002", "name": "Product 2", "price": 20.00}),
-        serde_json::json!({"sku": "SKU003", "name": "Product 3", "price": 30.00}),
-    ];
+    store
+        .create_instances(
+            "stock",
+            vec![
+                serde_json::json!({"sku": "a", "qty": 9, "price": 9.5, "active": true,
+                    "due": "2024-03-01T09:00:00+01:00"}),
+                serde_json::json!({"sku": "b", "qty": 10, "price": 10.25, "active": false,
+                    "due": "2024-03-01T08:45:00Z"}),
+                serde_json::json!({"sku": "c", "qty": 100, "price": 100, "active": true,
+                    "due": "2024-02-29T23:00:00-05:00"}),
+            ],
+        )
+        .await
+        .expect("Should create instances");
 
-    let affected = store
-        .upsert_instances("upsert_insert", instances, vec!["sku".to_string()])
+    let skus = |instances: Vec<runtara_object_store::Instance>| {
+        let mut skus: Vec<String> = instances
+            .into_iter()
+            .map(|i| i.properties["sku"].as_str().unwrap().to_string())
+            .collect();
+        skus.sort();
+        skus
+    };
+    let filter = |condition: Condition| {
+        FilterRequest::new()
+            .with_condition(condition)
+            .with_pagination(0, 100)
+    };
+
+    // As text "9" > "10" and "9" > "100"; as integers neither holds
+    let (found, _) = store
+        .filter_instances("stock", filter(Condition::gt("qty", 9)))
         .await
-        .expect("Should upsert");
+        .expect("Should filter integers");
+    assert_eq!(skus(found), vec!["b", "c"]);
 
-    assert_eq!(affected, 3);
+    let (found, _) = store
+        .filter_instances("stock", filter(Condition::between("qty", 10, 100)))
+        .await
+        .expect("Should filter integer range");
+    assert_eq!(skus(found), vec!["b", "c"]);
 
-    // Verify all were created
-    let (results, total) = store
-        .query_instances(SimpleFilter::new("upsert_insert"))
+    // Fractional bounds keep their exact decimal value
+    let (found, _) = store
+        .filter_instances("stock", filter(Condition::lte("price", 10.25)))
+        .await
+        .expect("Should filter decimals");
+    assert_eq!(skus(found), vec!["a", "b"]);
+
+    let (found, _) = store
+        .filter_instances("stock", filter(Condition::eq("active", true)))
+        .await
+        .expect("Should filter booleans");
+    assert_eq!(skus(found), vec!["a", "c"]);
+
+    // Instants compare across offsets: a is 08:00Z, b 08:45Z, c 04:00Z (all March 1)
+    let (found, _) = store
+        .filter_instances(
+            "stock",
+            filter(Condition::gt("due", "2024-03-01T09:30:00+01:00")),
+        )
+        .await
+        .expect("Should filter timestamps");
+    assert_eq!(skus(found), vec!["b"]);
+
+    // Bulk update and delete bind the same typed parameters
+    let updated = store
+        .update_instances(
+            "stock",
+            serde_json::json!({"active": false}),
+            Condition::gte("qty", 100),
+        )
+        .await
+        .expect("Should update by integer condition");
+    assert_eq!(updated, 1);
+
+    let deleted = store
+        .delete_instances("stock", Condition::lt("qty", 10))
         .await
-        .expect("Should query");
+        .expect("Should delete by integer condition");
+    assert_eq!(deleted, 1);
 
-    assert_eq!(total, 3);
-    assert_eq!(results.len(), 3);
+    let (found, _) = store
+        .filter_instances("stock", filter(Condition::eq("active", false)))
+        .await
+        .expect("Should filter after update");
+    assert_eq!(skus(found), vec!["b", "c"]);
 
     cleanup_test(&store, &prefix).await;
 }
 
 #[tokio::test]
-async fn test_upsert_instances_update_only() {
+async fn test_filter_instances_flat() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    let table_name = format!("{}_upsert_update", prefix);
-    let request = CreateSchemaRequest {
-        name: "upsert_update".to_string(),
-        description: None,
-        table_name: table_name.clone(),
-        columns: vec![
-            ColumnDefinition::new("code", ColumnType::String)
-                .unique()
-                .not_null(),
-            ColumnDefinition::new("value", ColumnType::Integer).not_null(),
-        ],
-        indexes: None,
-    };
-
     store
-        .create_schema(request)
+        .create_schema(CreateSchemaRequest::new(
+            "flat",
+            format!("{}_flat", prefix),
+            vec![ColumnDefinition::new("name", ColumnType::String)],
+        ))
         .await
         .expect("Should create schema");
 
-    // Create initial instances
-    for code in ["A", "B", "C"] {
+    let id = store
+        .create_instance("flat", serde_json::json!({"name": "Widget"}))
+        .await
+        .expect("Should create instance");
+
+    let (rows, total) = store
+        .filter_instances_flat("flat", FilterRequest::new())
+        .await
+        .expect("Should filter flat");
+    assert_eq!(total, 1);
+    let row = rows[0].as_object().expect("Row should be an object");
+    assert_eq!(row["id"], id.as_str());
+    assert_eq!(row["name"], "Widget");
+    assert!(row["createdAt"].is_string());
+    assert!(row["updatedAt"].is_string());
+    assert!(!row.contains_key("properties"));
+
+    cleanup_test(&store, &prefix).await;
+}
+
+#[tokio::test]
+async fn test_delete_across_schemas() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    for name in ["notes", "files"] {
         store
-            .create_instance(
-                "upsert_update",
-                serde_json::json!({"code": code, "value": 1}),
+            .create_schema(CreateSchemaRequest::new(
+                name,
+                format!("{}_{}", prefix, name),
+                vec![ColumnDefinition::new("account", ColumnType::String)],
+            ))
+            .await
+            .expect("Should create schema");
+        store
+            .create_instances(
+                name,
+                vec![
+                    serde_json::json!({"account": "gone"}),
+                    serde_json::json!({"account": "gone"}),
+                    serde_json::json!({"account": "kept"}),
+                ],
             )
             .await
-            .expect("Should create");
+            .expect("Should create instances");
     }
 
-    // Upsert with updated values
-    let instances = vec![
-        serde_json::json!({"code": "A", "value": 100}),
-        serde_json::json!({"code": "B", "value": 200}),
-        serde_json::json!({"code": "C", "value": 300}),
-    ];
-
-    let affected = store
-        .upsert_instances("upsert_update", instances, vec!["code".to_string()])
-        .await
-        .expect("Should upsert");
-
-    assert_eq!(affected, 3);
+    let count = |name: &'static str| {
+        let store = &store;
+        async move {
+            let (_, total) = store
+                .filter_instances(name, FilterRequest::new())
+                .await
+                .expect("Should count instances");
+            total
+        }
+    };
 
-    // Verify values were updated
-    let (_results, total) = store
-        .query_instances(SimpleFilter::new("upsert_update"))
+    // A failing entry rolls back the deletes that ran before it
+    let err = store
+        .delete_across(vec![
+            ("notes".to_string(), Condition::eq("account", "gone")),
+            ("files".to_string(), Condition::new("BOGUS", vec![])),
+        ])
         .await
-        .expect("Should query");
-
-    assert_eq!(total, 3); // Still only 3 (no new rows)
+        .expect_err("Should reject invalid condition");
+    assert!(matches!(err, ObjectStoreError::InvalidCondition(_)));
+    assert_eq!(count("notes").await, 3);
 
-    // Check one of the updated values
-    let instance = store
-        .instance_exists(SimpleFilter::new("upsert_update").filter("code", "A"))
+    let affected = store
+        .delete_across(vec![
+            ("notes".to_string(), Condition::eq("account", "gone")),
+            ("files".to_string(), Condition::eq("account", "gone")),
+        ])
         .await
-        .expect("Should find")
-        .expect("Should exist");
-
-    assert_eq!(instance.properties["value"], 100);
+        .expect("Should delete across schemas");
+    assert_eq!(affected.get("notes"), Some(&2));
+    assert_eq!(affected.get("files"), Some(&2));
+    assert_eq!(count("notes").await, 1);
+    assert_eq!(count("files").await, 1);
 
     cleanup_test(&store, &prefix).await;
 }
 
 #[tokio::test]
-async fn test_upsert_instances_mixed() {
+async fn test_create_instance_idempotent() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    let table_name = format!("{}_upsert_mixed", prefix);
-    let request = CreateSchemaRequest {
-        name: "upsert_mixed".to_string(),
-        description: None,
-        table_name: table_name.clone(),
-        columns: vec![
-            ColumnDefinition::new("key", ColumnType::String)
-                .unique()
-                .not_null(),
-            ColumnDefinition::new("data", ColumnType::String).not_null(),
-        ],
-        indexes: None,
-    };
-
     store
-        .create_schema(request)
+        .create_schema(CreateSchemaRequest::new(
+            "payments",
+            format!("{}_payments", prefix),
+            vec![
+                ColumnDefinition::new("external_id", ColumnType::String).unique(),
+                ColumnDefinition::new("amount", ColumnType::Integer),
+            ],
+        ))
         .await
         .expect("Should create schema");
 
-    // Create some initial instances
-    store
-        .create_instance(
-            "upsert_mixed",
-            serde_json::json!({"key": "existing1", "data": "old1"}),
+    let (first, created) = store
+        .create_instance_idempotent(
+            "payments",
+            serde_json::json!({"external_id": "pay-1", "amount": 100}),
+            &["external_id"],
         )
         .await
-        .expect("Should create");
-    store
-        .create_instance(
-            "upsert_mixed",
-            serde_json::json!({"key": "existing2", "data": "old2"}),
+        .expect("Should create instance");
+    assert!(created);
+    assert_eq!(first.properties["amount"], 100);
+
+    // A retry returns the stored row untouched, even with different data
+    let (retry, created) = store
+        .create_instance_idempotent(
+            "payments",
+            serde_json::json!({"external_id": "pay-1", "amount": 999}),
+            &["external_id"],
         )
         .await
-        .expect("Should create");
-
-    // Upsert: 2 updates + 2 inserts
-    let instances = vec![
-        serde_json::json!({"key": "existing1", "data": "updated1"}), // Update
-        serde_json::json!({"key": "new1", "data": "new1"}),          // Insert
-        serde_json::json!({"key": "existing2", "data": "updated2"}), // Update
-        serde_json::json!({"key": "new2", "data": "new2"}),          // Insert
-    ];
-
-    let affected = store
-        .upsert_instances("upsert_mixed", instances, vec!["key".to_string()])
-        .await
-        .expect("Should upsert");
-
-    assert_eq!(affected, 4);
+        .expect("Should return existing instance");
+    assert!(!created);
+    assert_eq!(retry.id, first.id);
+    assert_eq!(retry.properties["amount"], 100);
 
-    // Verify total count
     let (_, total) = store
-        .query_instances(SimpleFilter::new("upsert_mixed"))
+        .filter_instances("payments", FilterRequest::new())
         .await
-        .expect("Should query");
+        .expect("Should count instances");
+    assert_eq!(total, 1);
+
+    let err = store
+        .create_instance_idempotent(
+            "payments",
+            serde_json::json!({"external_id": "pay-2"}),
+            &["missing"],
+        )
+        .await
+        .expect_err("Should reject unknown key column");
+    assert!(matches!(err, ObjectStoreError::Validation(_)));
 
-    assert_eq!(total, 4); // 2 existing + 2 new
+    cleanup_test(&store, &prefix).await;
+}
 
-    // Verify updates happened
-    let instance = store
-        .instance_exists(SimpleFilter::new("upsert_mixed").filter("key", "existing1"))
+#[tokio::test]
+async fn test_create_instance_idempotent_normalized_key() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "accounts",
+            format!("{}_accounts", prefix),
+            vec![
+                ColumnDefinition::new("email", ColumnType::String)
+                    .unique()
+                    .normalize(Normalization::Lowercase),
+                ColumnDefinition::new("plan", ColumnType::String),
+            ],
+        ))
         .await
-        .expect("Should find")
-        .expect("Should exist");
+        .expect("Should create schema");
 
-    assert_eq!(instance.properties["data"], "updated1");
+    let (first, created) = store
+        .create_instance_idempotent(
+            "accounts",
+            serde_json::json!({"email": "ABC@example.com", "plan": "free"}),
+            &["email"],
+        )
+        .await
+        .expect("Should create instance");
+    assert!(created);
+    assert_eq!(first.properties["email"], "abc@example.com");
+
+    // A retry with different letter case finds the normalized row
+    let (retry, created) = store
+        .create_instance_idempotent(
+            "accounts",
+            serde_json::json!({"email": "Abc@Example.com", "plan": "pro"}),
+            &["email"],
+        )
+        .await
+        .expect("Should return existing instance");
+    assert!(!created);
+    assert_eq!(retry.id, first.id);
+    assert_eq!(retry.properties["plan"], "free");
 
     cleanup_test(&store, &prefix).await;
 }
 
 #[tokio::test]
-async fn test_upsert_instances_multi_column_conflict() {
+async fn test_immutable_column() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    let table_name = format!("{}_upsert_multi", prefix);
-    let request = CreateSchemaRequest {
-        name: "upsert_multi".to_string(),
-        description: None,
-        table_name: table_name.clone(),
-        columns: vec![
-            ColumnDefinition::new("tenant", ColumnType::String).not_null(),
-            ColumnDefinition::new("code", ColumnType::String).not_null(),
-            ColumnDefinition::new("value", ColumnType::Integer).not_null(),
-        ],
-        indexes: Some(vec![
-            IndexDefinition::new(
-                "tenant_code_unique",
-                vec!["tenant".to_string(), "code".to_string()],
-            )
-            .unique(),
-        ]),
-    };
-
     store
-        .create_schema(request)
+        .create_schema(CreateSchemaRequest::new(
+            "members",
+            format!("{}_members", prefix),
+            vec![
+                ColumnDefinition::new("email", ColumnType::String).unique(),
+                ColumnDefinition::new("account_id", ColumnType::String).immutable(),
+                ColumnDefinition::new("role", ColumnType::String),
+            ],
+        ))
         .await
         .expect("Should create schema");
 
-    // Create initial instances
-    store
+    // Set freely at insert
+    let id = store
         .create_instance(
-            "upsert_multi",
-            serde_json::json!({"tenant": "A", "code": "X", "value": 1}),
+            "members",
+            serde_json::json!({"email": "a@x.io", "account_id": "acc-1", "role": "admin"}),
         )
         .await
-        .expect("Should create");
-    store
-        .create_instance(
-            "upsert_multi",
-            serde_json::json!({"tenant": "A", "code": "Y", "value": 2}),
+        .expect("Should insert immutable column");
+
+    let err = store
+        .update_instance("members", &id, serde_json::json!({"account_id": "acc-2"}))
+        .await
+        .expect_err("Should reject updating immutable column");
+    assert!(matches!(err, ObjectStoreError::Validation(_)));
+
+    let err = store
+        .update_instances(
+            "members",
+            serde_json::json!({"account_id": "acc-2"}),
+            Condition::eq("role", "admin"),
         )
         .await
-        .expect("Should create");
+        .expect_err("Should reject bulk update of immutable column");
+    assert!(matches!(err, ObjectStoreError::Validation(_)));
 
-    // Upsert with multi-column conflict
-    let instances = vec![
-        serde_json::json!({"tenant": "A", "code": "X", "value": 100}), // Update
-        serde_json::json!({"tenant": "A", "code": "Z", "value": 3}),   // Insert
-        serde_json::json!({"tenant": "B", "code": "X", "value": 4}),   // Insert (different tenant)
-    ];
+    store
+        .update_instance("members", &id, serde_json::json!({"role": "owner"}))
+        .await
+        .expect("Should update other columns");
 
-    let affected = store
+    // Upserts may resend the stored value but not change it
+    store
         .upsert_instances(
-            "upsert_multi",
-            instances,
-            vec!["tenant".to_string(), "code".to_string()],
+            "members",
+            vec![serde_json::json!({"email": "a@x.io", "account_id": "acc-1", "role": "viewer"})],
+            vec!["email".to_string()],
         )
         .await
-        .expect("Should upsert");
+        .expect("Should upsert with unchanged immutable value");
 
-    assert_eq!(affected, 3);
+    let err = store
+        .upsert_instances(
+            "members",
+            vec![serde_json::json!({"email": "a@x.io", "account_id": "acc-2", "role": "admin"})],
+            vec!["email".to_string()],
+        )
+        .await
+        .expect_err("Should reject upsert changing immutable column");
+    assert!(matches!(err, ObjectStoreError::Validation(_)));
 
-    // Verify total count: 2 original + 2 new = 4
-    let (_, total) = store
-        .query_instances(SimpleFilter::new("upsert_multi"))
+    let stored = store
+        .require_instance("members", &id)
         .await
-        .expect("Should query");
+        .expect("Should fetch instance");
+    assert_eq!(stored.properties["account_id"], "acc-1");
+    assert_eq!(stored.properties["role"], "viewer");
 
-    assert_eq!(total, 4);
+    cleanup_test(&store, &prefix).await;
+}
 
-    // Verify the update happened
-    let (instances, _) = store
-        .filter_instances(
-            "upsert_multi",
-            FilterRequest::new().with_condition(Condition::and(vec![
-                Condition::eq("tenant", "A"),
-                Condition::eq("code", "X"),
-            ])),
+#[tokio::test]
+async fn test_update_instances_returning() {
+    let Some((store, prefix)) = create_test_store().await else {
+        eprintln!("Skipping test: TEST_DATABASE_URL not set");
+        return;
+    };
+
+    store
+        .create_schema(CreateSchemaRequest::new(
+            "tasks",
+            format!("{}_tasks", prefix),
+            vec![
+                ColumnDefinition::new("title", ColumnType::String),
+                ColumnDefinition::new("status", ColumnType::String),
+            ],
+        ))
+        .await
+        .expect("Should create schema");
+
+    store
+        .create_instances(
+            "tasks",
+            vec![
+                serde_json::json!({"title": "a", "status": "open"}),
+                serde_json::json!({"title": "b", "status": "open"}),
+                serde_json::json!({"title": "c", "status": "done"}),
+            ],
         )
         .await
-        .expect("Should filter");
+        .expect("Should create instances");
 
-    assert_eq!(instances.len(), 1);
-    assert_eq!(instances[0].properties["value"], 100);
+    let mut updated = store
+        .update_instances_returning(
+            "tasks",
+            serde_json::json!({"status": "closed"}),
+            Condition::eq("status", "open"),
+        )
+        .await
+        .expect("Should update and return instances");
+    updated.sort_by(|a, b| {
+        a.properties["title"]
+            .as_str()
+            .cmp(&b.properties["title"].as_str())
+    });
+
+    assert_eq!(updated.len(), 2);
+    assert_eq!(updated[0].properties["title"], "a");
+    assert_eq!(updated[1].properties["title"], "b");
+    assert!(updated.iter().all(|i| i.properties["status"] == "closed"));
+
+    let stored = store
+        .require_instance("tasks", &updated[0].id)
+        .await
+        .expect("Should fetch instance");
+    assert_eq!(stored.properties["status"], "closed");
+    assert_eq!(stored.updated_at, updated[0].updated_at);
 
     cleanup_test(&store, &prefix).await;
 }
 
 #[tokio::test]
-async fn test_upsert_instances_empty_conflict_columns() {
+async fn test_numeric_gt_is_not_lexicographic() {
     let Some((store, prefix)) = create_test_store().await else {
         eprintln!("Skipping test: TEST_DATABASE_URL not set");
         return;
     };
 
-    let table_name = format!("{}_upsert_empty_conflict", prefix);
-    let request = CreateSchemaRequest {
-        name: "upsert_empty_conflict".to_string(),
-        description: None,
-        table_name: table_name.clone(),
-        columns: vec![ColumnDefinition::new("name", ColumnType::String)],
-        indexes: None,
-    };
-
     store
-        .create_schema(request)
+        .create_schema(CreateSchemaRequest::new(
+            "priced",
+            format!("{}_priced", prefix),
+            vec![
+                ColumnDefinition::new("price", ColumnType::decimal(10, 2)),
+                ColumnDefinition::new("rank", ColumnType::Integer),
+            ],
+        ))
         .await
         .expect("Should create schema");
 
-    let instances = vec![serde_json::json!({"name": "test"})];
-
-    // Should fail with empty conflict columns
-    let result = store
-        .upsert_instances("upsert_empty_conflict", instances, vec![])
-        .await;
+    store
+        .create_instances(
+            "priced",
+            vec![
+                serde_json::json!({"price": 2, "rank": 2}),
+                serde_json::json!({"price": 10, "rank": 10}),
+                serde_json::json!({"price": 100, "rank": 100}),
+            ],
+        )
+        .await
+        .expect("Should create instances");
 
-    assert!(result.is_err());
+    for field in ["price", "rank"] {
+        let (found, _) = store
+            .filter_instances(
+                "priced",
+                FilterRequest::new()
+                    .with_condition(Condition::gt(field, 9))
+                    .with_sort(vec![field.to_string()], vec!["asc".to_string()]),
+            )
+            .await
+            .expect("Should filter numerically");
+        let values: Vec<f64> = found
+            .iter()
+            .map(|i| {
+                let value = &i.properties[field];
+                value
+                    .as_f64()
+                    .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(values, vec![10.0, 100.0], "GT 9 on {}", field);
+    }
 
     cleanup_test(&store, &prefix).await;
 }